@@ -0,0 +1,46 @@
+#!/bin/sh
+# Check that the `ct` functions compile to code without data-dependent
+# branches.
+#
+# Builds `ci/ct-branches`, which exports each `micromath::ct` function under
+# an unmangled `ct_*` symbol, and scans the generated assembly of those
+# symbols for conditional branches and calls. Backward conditional branches
+# are allowed: they close loops with a fixed trip count (e.g. the 32 steps of
+# `ct::powi`), which don't depend on the inputs.
+#
+# Supports x86_64 and aarch64 targets, e.g.:
+#
+#     ci/ct-branches.sh --target aarch64-unknown-linux-gnu
+
+set -eu
+
+cd "$(dirname "$0")/ct-branches"
+rm -f target/*/release/deps/ct_branches-*.s target/release/deps/ct_branches-*.s
+cargo rustc --release "$@" -- --emit asm
+
+asm=$(ls target/*/release/deps/ct_branches-*.s target/release/deps/ct_branches-*.s 2>/dev/null | head -n 1)
+
+awk '
+    /^ct_[a-z]+:/ { function_name = substr($1, 1, length($1) - 1); split("", labels); next }
+    /^\.Lfunc_end/ { function_name = ""; next }
+    function_name == "" { next }
+    /^\.?L[A-Za-z0-9_]+:/ { labels[substr($1, 1, length($1) - 1)] = 1; next }
+    {
+        mnemonic = $1
+        target = $NF
+        conditional = (mnemonic ~ /^j[a-z]+$/ && mnemonic != "jmp") ||
+            mnemonic ~ /^b\.[a-z]+$/ || mnemonic ~ /^(cbz|cbnz|tbz|tbnz)$/
+        call = mnemonic ~ /^(call|callq|bl|blr)$/
+
+        if ((conditional && !(target in labels)) || call) {
+            printf "%s: %s\n", function_name, $0
+            failed = 1
+        }
+    }
+    END { exit failed }
+' "$asm" || {
+    echo "error: data-dependent branches or calls found in $asm" >&2
+    exit 1
+}
+
+echo "ok: no data-dependent branches in the ct functions"
@@ -0,0 +1,60 @@
+//! Unmangled, non-inlined wrappers around the `micromath::ct` functions.
+
+#![no_std]
+
+use micromath::{ct, F32};
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo<'_>) -> ! {
+    loop {}
+}
+
+#[no_mangle]
+pub extern "C" fn ct_select(choice: bool, a: f32, b: f32) -> f32 {
+    ct::select(choice, F32(a), F32(b)).0
+}
+
+#[no_mangle]
+pub extern "C" fn ct_abs(x: f32) -> f32 {
+    ct::abs(F32(x)).0
+}
+
+#[no_mangle]
+pub extern "C" fn ct_copysign(x: f32, sign: f32) -> f32 {
+    ct::copysign(F32(x), F32(sign)).0
+}
+
+#[no_mangle]
+pub extern "C" fn ct_min(x: f32, y: f32) -> f32 {
+    ct::min(F32(x), F32(y)).0
+}
+
+#[no_mangle]
+pub extern "C" fn ct_max(x: f32, y: f32) -> f32 {
+    ct::max(F32(x), F32(y)).0
+}
+
+#[no_mangle]
+pub extern "C" fn ct_invsqrt(x: f32) -> f32 {
+    ct::invsqrt(F32(x)).0
+}
+
+#[no_mangle]
+pub extern "C" fn ct_sqrt(x: f32) -> f32 {
+    ct::sqrt(F32(x)).0
+}
+
+#[no_mangle]
+pub extern "C" fn ct_exp(x: f32) -> f32 {
+    ct::exp(F32(x)).0
+}
+
+#[no_mangle]
+pub extern "C" fn ct_ln(x: f32) -> f32 {
+    ct::ln(F32(x)).0
+}
+
+#[no_mangle]
+pub extern "C" fn ct_powi(x: f32, n: i32) -> f32 {
+    ct::powi(F32(x), n).0
+}
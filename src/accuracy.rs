@@ -0,0 +1,498 @@
+//! Machine-readable accuracy of the [`F32`] approximations.
+//!
+//! Each constant in this module gives the maximum error of one function over
+//! an input domain, measured against a double precision reference at every
+//! `f32` in that domain:
+//!
+//! - absolute error: `|actual - expected|`
+//! - relative error: `|actual - expected| / |expected|`, which isn't checked
+//!   for subnormal results as they have fewer significant bits
+//! - ULP error: the number of representable `f32` values between the result
+//!   and the correctly rounded one
+//!
+//! Bounds which aren't guaranteed are [`f32::INFINITY`] or [`u32::MAX`]. This
+//! is the case for the absolute error of functions with large results, such
+//! as [`F32::exp`], and for the relative and ULP error of functions with a
+//! zero inside the domain, such as [`F32::sin`] or [`F32::ln`], as the error
+//! of the approximation doesn't shrink with the result.
+//!
+//! ```
+//! use micromath::{accuracy, F32};
+//!
+//! let accuracy = accuracy::SIN;
+//! assert!(accuracy.contains(1.0));
+//! assert!((F32(1.0).sin().0 - 0.841_471).abs() <= accuracy.max_abs_error);
+//! ```
+//!
+//! The bounds are verified at a sample of inputs by the regular tests, and at
+//! every input by an exhaustive test enabled with the `exhaustive-tests`
+//! feature, which should be run in release mode:
+//!
+//! ```text
+//! cargo test --release --features exhaustive-tests accuracy
+//! ```
+
+use crate::F32;
+
+#[cfg(feature = "trig-table")]
+use crate::trig_table::{TrigTable, DEFAULT_SIZE};
+
+/// Maximum error of a function over an input domain.
+#[derive(Copy, Clone, Debug)]
+pub struct Accuracy {
+    /// Name of the [`F32`] method, e.g. `"sin"`.
+    pub name: &'static str,
+
+    /// The function being characterized.
+    pub function: fn(F32) -> F32,
+
+    /// Smallest input the bounds hold for.
+    pub min: f32,
+
+    /// Largest input the bounds hold for.
+    pub max: f32,
+
+    /// Maximum absolute error.
+    pub max_abs_error: f32,
+
+    /// Maximum relative error.
+    pub max_rel_error: f32,
+
+    /// Maximum error in units in the last place.
+    pub max_ulps: u32,
+}
+
+impl Accuracy {
+    /// Is `x` within the domain the bounds hold for?
+    pub fn contains(&self, x: f32) -> bool {
+        self.min <= x && x <= self.max
+    }
+}
+
+/// Accuracy of [`F32::sqrt`] for positive normal inputs.
+pub const SQRT: Accuracy = Accuracy {
+    name: "sqrt",
+    function: F32::sqrt,
+    min: f32::MIN_POSITIVE,
+    max: f32::MAX,
+    max_abs_error: f32::INFINITY,
+    max_rel_error: 0.061,
+    max_ulps: 720_000,
+};
+
+/// Accuracy of [`F32::invsqrt`] for positive normal inputs.
+pub const INVSQRT: Accuracy = Accuracy {
+    name: "invsqrt",
+    function: F32::invsqrt,
+    min: f32::MIN_POSITIVE,
+    max: f32::MAX,
+    max_abs_error: f32::INFINITY,
+    max_rel_error: 0.035,
+    max_ulps: 570_000,
+};
+
+/// Accuracy of [`F32::cbrt`] for positive normal inputs.
+///
+/// The error is the same for negative inputs, since the sign is preserved.
+pub const CBRT: Accuracy = Accuracy {
+    name: "cbrt",
+    function: F32::cbrt,
+    min: f32::MIN_POSITIVE,
+    max: f32::MAX,
+    max_abs_error: f32::INFINITY,
+    max_rel_error: 0.033,
+    max_ulps: 396_000,
+};
+
+/// Accuracy of [`F32::exp`] for inputs with a finite, normal result.
+pub const EXP: Accuracy = Accuracy {
+    name: "exp",
+    function: F32::exp,
+    min: -87.0,
+    max: 88.0,
+    max_abs_error: f32::INFINITY,
+    max_rel_error: 0.0024,
+    max_ulps: 20_100,
+};
+
+/// Accuracy of [`F32::exp2`] for inputs with a finite, normal result.
+pub const EXP2: Accuracy = Accuracy {
+    name: "exp2",
+    function: F32::exp2,
+    min: -126.0,
+    max: 127.0,
+    max_abs_error: f32::INFINITY,
+    max_rel_error: 0.0024,
+    max_ulps: 20_100,
+};
+
+/// Accuracy of [`F32::exp_m1`] for inputs with a finite result.
+pub const EXP_M1: Accuracy = Accuracy {
+    name: "exp_m1",
+    function: F32::exp_m1,
+    min: -87.0,
+    max: 88.0,
+    max_abs_error: f32::INFINITY,
+    max_rel_error: 4.6e-6,
+    max_ulps: 80,
+};
+
+/// Accuracy of [`F32::ln`] for positive normal inputs.
+pub const LN: Accuracy = Accuracy {
+    name: "ln",
+    function: F32::ln,
+    min: f32::MIN_POSITIVE,
+    max: f32::MAX,
+    max_abs_error: 7e-5,
+    max_rel_error: f32::INFINITY,
+    max_ulps: u32::MAX,
+};
+
+/// Accuracy of [`F32::log2`] for positive normal inputs.
+pub const LOG2: Accuracy = Accuracy {
+    name: "log2",
+    function: F32::log2,
+    min: f32::MIN_POSITIVE,
+    max: f32::MAX,
+    max_abs_error: 1.04e-4,
+    max_rel_error: f32::INFINITY,
+    max_ulps: u32::MAX,
+};
+
+/// Accuracy of [`F32::log10`] for positive normal inputs.
+pub const LOG10: Accuracy = Accuracy {
+    name: "log10",
+    function: F32::log10,
+    min: f32::MIN_POSITIVE,
+    max: f32::MAX,
+    max_abs_error: 3.3e-5,
+    max_rel_error: f32::INFINITY,
+    max_ulps: u32::MAX,
+};
+
+/// Accuracy of [`F32::ln_1p`] for inputs greater than `-1`.
+pub const LN_1P: Accuracy = Accuracy {
+    name: "ln_1p",
+    function: F32::ln_1p,
+    min: -0.999_999_94,
+    max: f32::MAX,
+    max_abs_error: 7e-5,
+    max_rel_error: 8.8e-5,
+    max_ulps: 1_040,
+};
+
+/// Accuracy of [`F32::sin`] for inputs in the `[-1e5, 1e5]` range.
+#[cfg(not(feature = "trig-table"))]
+pub const SIN: Accuracy = Accuracy {
+    name: "sin",
+    function: F32::sin,
+    min: -1e5,
+    max: 1e5,
+    max_abs_error: 1.1e-3,
+    max_rel_error: f32::INFINITY,
+    max_ulps: u32::MAX,
+};
+
+/// Accuracy of [`F32::cos`] for inputs in the `[-1e5, 1e5]` range.
+#[cfg(not(feature = "trig-table"))]
+pub const COS: Accuracy = Accuracy {
+    name: "cos",
+    function: F32::cos,
+    min: -1e5,
+    max: 1e5,
+    max_abs_error: 1.1e-3,
+    max_rel_error: f32::INFINITY,
+    max_ulps: u32::MAX,
+};
+
+/// Accuracy of the table-driven [`F32::sin`] for inputs in the `[-1e5, 1e5]`
+/// range, with the absolute error depending on the table size.
+#[cfg(feature = "trig-table")]
+pub const SIN: Accuracy = Accuracy {
+    name: "sin",
+    function: F32::sin,
+    min: -1e5,
+    max: 1e5,
+    max_abs_error: TrigTable::<DEFAULT_SIZE>::MAX_ERROR,
+    max_rel_error: f32::INFINITY,
+    max_ulps: u32::MAX,
+};
+
+/// Accuracy of the table-driven [`F32::cos`] for inputs in the `[-1e5, 1e5]`
+/// range, with the absolute error depending on the table size.
+#[cfg(feature = "trig-table")]
+pub const COS: Accuracy = Accuracy {
+    name: "cos",
+    function: F32::cos,
+    min: -1e5,
+    max: 1e5,
+    max_abs_error: TrigTable::<DEFAULT_SIZE>::MAX_ERROR,
+    max_rel_error: f32::INFINITY,
+    max_ulps: u32::MAX,
+};
+
+/// Accuracy of [`F32::tan`] for inputs in the `[-1.4, 1.4]` range, away from
+/// the poles.
+pub const TAN: Accuracy = Accuracy {
+    name: "tan",
+    function: F32::tan,
+    min: -1.4,
+    max: 1.4,
+    max_abs_error: 0.038,
+    max_rel_error: f32::INFINITY,
+    max_ulps: u32::MAX,
+};
+
+/// Accuracy of [`F32::atan`] for inputs in the `[-1.8e19, 1.8e19]` range.
+///
+/// Larger inputs return NaN, as squaring them overflows.
+pub const ATAN: Accuracy = Accuracy {
+    name: "atan",
+    function: F32::atan,
+    min: -1.8e19,
+    max: 1.8e19,
+    max_abs_error: 0.0029,
+    max_rel_error: 0.064,
+    max_ulps: 1_070_000,
+};
+
+/// Accuracy of [`F32::asin`] over its whole `[-1, 1]` domain.
+pub const ASIN: Accuracy = Accuracy {
+    name: "asin",
+    function: F32::asin,
+    min: -1.0,
+    max: 1.0,
+    max_abs_error: 0.018,
+    max_rel_error: 0.096,
+    max_ulps: 1_600_000,
+};
+
+/// Accuracy of [`F32Ext::acos`](crate::F32Ext::acos) over its `[-1, 1]` domain,
+/// except for `1` where the result is zero.
+pub const ACOS: Accuracy = Accuracy {
+    name: "acos",
+    function: F32::acos,
+    min: -1.0,
+    max: 0.999_999_94,
+    max_abs_error: 0.029,
+    max_rel_error: 0.064,
+    max_ulps: 1_070_000,
+};
+
+/// Accuracy of [`F32::atan2`] for `y = 1` and `x` in the `[-1.8e19, 1.8e19]`
+/// range, which covers angles in `(0, π)`.
+///
+/// The error only depends on the angle as long as `x²` and `y²` don't
+/// overflow, and is the same for negative `y`.
+pub const ATAN2: Accuracy = Accuracy {
+    name: "atan2",
+    function: |x| F32::ONE.atan2(x),
+    min: -1.8e19,
+    max: 1.8e19,
+    max_abs_error: 0.0029,
+    max_rel_error: 0.064,
+    max_ulps: 1_070_000,
+};
+
+/// Accuracy of [`F32::sinh`] for inputs in the `[-88, 88]` range, which
+/// have a finite result.
+pub const SINH: Accuracy = Accuracy {
+    name: "sinh",
+    function: F32::sinh,
+    min: -88.0,
+    max: 88.0,
+    max_abs_error: f32::INFINITY,
+    max_rel_error: 0.0018,
+    max_ulps: 22_700,
+};
+
+/// Accuracy of [`F32::cosh`] for inputs in the `[-88, 88]` range, which
+/// have a finite result.
+pub const COSH: Accuracy = Accuracy {
+    name: "cosh",
+    function: F32::cosh,
+    min: -88.0,
+    max: 88.0,
+    max_abs_error: f32::INFINITY,
+    max_rel_error: 7.6e-4,
+    max_ulps: 12_700,
+};
+
+/// Accuracy of [`F32::tanh`] for all finite inputs.
+pub const TANH: Accuracy = Accuracy {
+    name: "tanh",
+    function: F32::tanh,
+    min: f32::MIN,
+    max: f32::MAX,
+    max_abs_error: 3.35e-4,
+    max_rel_error: f32::INFINITY,
+    max_ulps: u32::MAX,
+};
+
+/// Accuracy of [`F32::asinh`] for all finite inputs.
+pub const ASINH: Accuracy = Accuracy {
+    name: "asinh",
+    function: F32::asinh,
+    min: f32::MIN,
+    max: f32::MAX,
+    max_abs_error: 7e-5,
+    max_rel_error: 8.8e-5,
+    max_ulps: 1_040,
+};
+
+/// Accuracy of [`F32::acosh`] for all finite inputs in its `[1, ∞)` domain.
+pub const ACOSH: Accuracy = Accuracy {
+    name: "acosh",
+    function: F32::acosh,
+    min: 1.0,
+    max: f32::MAX,
+    max_abs_error: 7e-5,
+    max_rel_error: 8.8e-5,
+    max_ulps: 1_040,
+};
+
+/// Accuracy of [`F32::atanh`] over its `(-1, 1)` domain.
+pub const ATANH: Accuracy = Accuracy {
+    name: "atanh",
+    function: F32::atanh,
+    min: -0.999_999_94,
+    max: 0.999_999_94,
+    max_abs_error: 3.2e-5,
+    max_rel_error: 8.8e-5,
+    max_ulps: 1_040,
+};
+
+/// Accuracy of [`F32::erf`] for all finite inputs.
+pub const ERF: Accuracy = Accuracy {
+    name: "erf",
+    function: F32::erf,
+    min: f32::MIN,
+    max: f32::MAX,
+    max_abs_error: 8e-7,
+    max_rel_error: 1.3e-6,
+    max_ulps: 14,
+};
+
+/// Accuracy of [`F32::gamma`] for positive inputs with a finite result.
+pub const GAMMA: Accuracy = Accuracy {
+    name: "gamma",
+    function: F32::gamma,
+    min: f32::MIN_POSITIVE,
+    max: 35.0,
+    max_abs_error: f32::INFINITY,
+    max_rel_error: 2e-5,
+    max_ulps: 320,
+};
+
+/// Accuracy of every function characterized by this module.
+pub const ALL: &[Accuracy] = &[
+    SQRT, INVSQRT, CBRT, EXP, EXP2, EXP_M1, LN, LOG2, LOG10, LN_1P, SIN, COS, TAN, ATAN, ASIN,
+    ACOS, ATAN2, SINH, COSH, TANH, ASINH, ACOSH, ATANH, ERF, GAMMA,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::Accuracy;
+    use crate::approx::{ulps_distance, ulps_key};
+
+    /// Checks the bounds at every `step`th `f32` in the domain, against the
+    /// double precision `reference`.
+    fn check(accuracy: &Accuracy, reference: fn(f64) -> f64, step: usize) {
+        // Inverse of `ulps_key`
+        fn from_key(key: i64) -> f32 {
+            let magnitude = f32::from_bits(key.unsigned_abs() as u32);
+
+            if key < 0 {
+                -magnitude
+            } else {
+                magnitude
+            }
+        }
+
+        let max = ulps_key(accuracy.max);
+
+        for key in (ulps_key(accuracy.min)..=max).step_by(step).chain([max]) {
+            let x = from_key(key);
+            let expected = reference(f64::from(x));
+            let actual = (accuracy.function)(crate::F32(x)).0;
+
+            let abs_error = (f64::from(actual) - expected).abs();
+            let rel_error = if abs_error == 0.0 || expected.abs() < f64::from(f32::MIN_POSITIVE) {
+                0.0
+            } else {
+                abs_error / expected.abs()
+            };
+            let ulps = ulps_distance(actual, expected as f32);
+
+            assert!(
+                abs_error <= f64::from(accuracy.max_abs_error)
+                    && rel_error <= f64::from(accuracy.max_rel_error)
+                    && ulps <= u64::from(accuracy.max_ulps),
+                "{}({}) = {} vs {}: abs {}, rel {}, ulps {}",
+                accuracy.name,
+                x,
+                actual,
+                expected,
+                abs_error,
+                rel_error,
+                ulps
+            );
+        }
+    }
+
+    /// Generates a sampled and an exhaustive test for each function.
+    macro_rules! accuracy_tests {
+        ($($name:ident => $accuracy:expr, $reference:expr;)+) => {
+            $(
+                mod $name {
+                    use super::check;
+                    use crate::accuracy::*;
+
+                    #[test]
+                    fn sampled() {
+                        check(&$accuracy, $reference, 4099);
+                    }
+
+                    #[cfg(feature = "exhaustive-tests")]
+                    #[test]
+                    fn exhaustive() {
+                        check(&$accuracy, $reference, 1);
+                    }
+                }
+            )+
+
+            #[test]
+            fn all_covered() {
+                assert_eq!(super::ALL.len(), [$(stringify!($name)),+].len());
+            }
+        };
+    }
+
+    accuracy_tests! {
+        sqrt => SQRT, f64::sqrt;
+        invsqrt => INVSQRT, |x| 1.0 / x.sqrt();
+        cbrt => CBRT, f64::cbrt;
+        exp => EXP, f64::exp;
+        exp2 => EXP2, f64::exp2;
+        exp_m1 => EXP_M1, f64::exp_m1;
+        ln => LN, f64::ln;
+        log2 => LOG2, f64::log2;
+        log10 => LOG10, f64::log10;
+        ln_1p => LN_1P, f64::ln_1p;
+        sin => SIN, f64::sin;
+        cos => COS, f64::cos;
+        tan => TAN, f64::tan;
+        atan => ATAN, f64::atan;
+        asin => ASIN, f64::asin;
+        acos => ACOS, f64::acos;
+        atan2 => ATAN2, |x| 1.0f64.atan2(x);
+        sinh => SINH, f64::sinh;
+        cosh => COSH, f64::cosh;
+        tanh => TANH, f64::tanh;
+        asinh => ASINH, f64::asinh;
+        acosh => ACOSH, f64::acosh;
+        atanh => ATANH, f64::atanh;
+        erf => ERF, libm::erf;
+        gamma => GAMMA, libm::tgamma;
+    }
+}
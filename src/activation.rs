@@ -0,0 +1,311 @@
+//! Activation functions for small neural networks.
+//!
+//! The scalar functions are defined as methods on [`F32`]. Each has a
+//! counterpart in this module which applies it to a slice of `f32` in place,
+//! e.g. [`sigmoid_in_place`], along with [`softmax_in_place`] which
+//! normalizes a whole slice.
+//!
+//! ```
+//! use micromath::{activation, F32};
+//!
+//! assert_eq!(F32(-1.0).relu(), F32(0.0));
+//!
+//! let mut logits = [1.0, 2.0, 3.0];
+//! activation::softmax_in_place(&mut logits);
+//! assert!((logits[2] - 0.665).abs() < 0.005);
+//! ```
+
+use crate::F32;
+
+/// `2 * sqrt(2 / π)`, used by the `tanh` approximation of GELU.
+const GELU_SCALE: f32 = 1.595_769;
+
+/// Cubic coefficient of the `tanh` approximation of GELU.
+const GELU_CUBIC: f32 = 0.044_715;
+
+impl F32 {
+    /// Approximates the logistic sigmoid function `1 / (1 + e^-x)` with a
+    /// maximum error of `0.001`.
+    pub fn sigmoid(self) -> Self {
+        Self::ONE / (Self::ONE + (-self).exp())
+    }
+
+    /// Approximates the softplus function `ln(1 + e^x)`, a smooth version of
+    /// [`F32::relu`], with a maximum error of `0.001`.
+    pub fn softplus(self) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+
+        // `ln(1 + e^x) = max(x, 0) + ln(1 + e^-|x|)` doesn't overflow
+        let max = if self > 0.0 { self } else { Self::ZERO };
+        max + (-self.abs()).exp().ln_1p()
+    }
+
+    /// Rectified linear unit: returns `x` if it's positive, otherwise `0`.
+    ///
+    /// NaN is passed through unchanged.
+    #[inline]
+    pub fn relu(self) -> Self {
+        if self < 0.0 {
+            Self::ZERO
+        } else {
+            self
+        }
+    }
+
+    /// Leaky rectified linear unit: returns `x` if it's positive, otherwise
+    /// `x * slope`.
+    ///
+    /// NaN is passed through unchanged.
+    #[inline]
+    pub fn leaky_relu(self, slope: f32) -> Self {
+        if self < 0.0 {
+            self * slope
+        } else {
+            self
+        }
+    }
+
+    /// Approximates the Gaussian error linear unit `x * Φ(x)`, where `Φ` is the
+    /// standard normal CDF, with a maximum error of `0.001`.
+    ///
+    /// Uses the `tanh` approximation
+    /// `0.5 * x * (1 + tanh(sqrt(2 / π) * (x + 0.044715 * x^3)))`, computed
+    /// with [`F32::sigmoid`].
+    pub fn gelu(self) -> Self {
+        let x3 = self * self * self;
+        self * (GELU_SCALE * (self + GELU_CUBIC * x3)).sigmoid()
+    }
+}
+
+/// Apply [`F32::sigmoid`] to each value in the slice.
+pub fn sigmoid_in_place(values: &mut [f32]) {
+    map_in_place(values, F32::sigmoid);
+}
+
+/// Apply [`F32::tanh`] to each value in the slice.
+pub fn tanh_in_place(values: &mut [f32]) {
+    map_in_place(values, F32::tanh);
+}
+
+/// Apply [`F32::softplus`] to each value in the slice.
+pub fn softplus_in_place(values: &mut [f32]) {
+    map_in_place(values, F32::softplus);
+}
+
+/// Apply [`F32::relu`] to each value in the slice.
+pub fn relu_in_place(values: &mut [f32]) {
+    map_in_place(values, F32::relu);
+}
+
+/// Apply [`F32::leaky_relu`] with the given `slope` to each value in the
+/// slice.
+pub fn leaky_relu_in_place(values: &mut [f32], slope: f32) {
+    map_in_place(values, |x| x.leaky_relu(slope));
+}
+
+/// Apply [`F32::gelu`] to each value in the slice.
+pub fn gelu_in_place(values: &mut [f32]) {
+    map_in_place(values, F32::gelu);
+}
+
+/// Replace the values in the slice with their softmax `e^x_i / Σ e^x_j`, so
+/// they're all positive and sum to `1`.
+///
+/// The maximum value is subtracted from each value before exponentiating, so
+/// large inputs don't overflow.
+pub fn softmax_in_place(values: &mut [f32]) {
+    let max = values.iter().fold(f32::NEG_INFINITY, |max, &x| {
+        if x > max || x.is_nan() {
+            x
+        } else {
+            max
+        }
+    });
+
+    let mut sum = 0.0;
+
+    for value in values.iter_mut() {
+        *value = F32(*value - max).exp().0;
+        sum += *value;
+    }
+
+    let scale = 1.0 / sum;
+
+    for value in values.iter_mut() {
+        *value *= scale;
+    }
+}
+
+/// Replace each value in the slice with `f(value)`.
+#[inline]
+fn map_in_place(values: &mut [f32], f: impl Fn(F32) -> F32) {
+    for value in values.iter_mut() {
+        *value = f(F32(*value)).0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Maximum error
+    const MAX_ERROR: f32 = 0.001;
+
+    /// Inputs to evaluate each function at
+    fn inputs() -> impl Iterator<Item = f32> {
+        (-1_000..=1_000).map(|i| i as f32 * 0.01)
+    }
+
+    #[test]
+    fn sigmoid() {
+        for x in inputs() {
+            let expected = 1.0 / (1.0 + (-x).exp());
+            let actual = F32(x).sigmoid().0;
+            let delta = (actual - expected).abs();
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large: {} vs {}",
+                delta,
+                actual,
+                expected
+            );
+        }
+
+        assert_eq!(F32(-1000.0).sigmoid(), F32(0.0));
+        assert_eq!(F32(1000.0).sigmoid(), F32(1.0));
+    }
+
+    #[test]
+    fn softplus() {
+        for x in inputs() {
+            let expected = x.exp().ln_1p();
+            let actual = F32(x).softplus().0;
+            let delta = (actual - expected).abs();
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large: {} vs {}",
+                delta,
+                actual,
+                expected
+            );
+        }
+
+        assert_eq!(F32(1000.0).softplus(), F32(1000.0));
+        assert_eq!(F32(-1000.0).softplus(), F32(0.0));
+        assert!(F32::NAN.softplus().is_nan());
+    }
+
+    #[test]
+    fn relu() {
+        assert_eq!(F32(-2.0).relu(), F32(0.0));
+        assert_eq!(F32(0.0).relu(), F32(0.0));
+        assert_eq!(F32(2.0).relu(), F32(2.0));
+        assert!(F32::NAN.relu().is_nan());
+
+        assert_eq!(F32(-2.0).leaky_relu(0.01), F32(-0.02));
+        assert_eq!(F32(2.0).leaky_relu(0.01), F32(2.0));
+        assert!(F32::NAN.leaky_relu(0.01).is_nan());
+    }
+
+    #[test]
+    fn gelu() {
+        // Reference values of the exact `x * Φ(x)`
+        const TEST_VECTORS: &[(f32, f32)] = &[
+            (-5.0, -1.433_257e-6),
+            (-3.0, -0.004_049_694),
+            (-2.0, -0.045_500_26),
+            (-1.0, -0.158_655_25),
+            (-0.5, -0.154_268_77),
+            (0.0, 0.0),
+            (0.5, 0.345_731_23),
+            (1.0, 0.841_344_8),
+            (2.0, 1.954_499_7),
+            (3.0, 2.995_950_3),
+            (5.0, 4.999_998_6),
+        ];
+
+        for &(x, expected) in TEST_VECTORS {
+            let actual = F32(x).gelu().0;
+            let delta = (actual - expected).abs();
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large: {} vs {}",
+                delta,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn in_place() {
+        let values = [-2.0, -0.5, 0.0, 0.5, 2.0];
+
+        let mut relu = values;
+        relu_in_place(&mut relu);
+        assert_eq!(relu, [0.0, 0.0, 0.0, 0.5, 2.0]);
+
+        let mut leaky = values;
+        leaky_relu_in_place(&mut leaky, 0.1);
+        assert_eq!(leaky, [-0.2, -0.05, 0.0, 0.5, 2.0]);
+
+        let mut sigmoid = values;
+        sigmoid_in_place(&mut sigmoid);
+
+        let mut tanh = values;
+        tanh_in_place(&mut tanh);
+
+        let mut softplus = values;
+        softplus_in_place(&mut softplus);
+
+        let mut gelu = values;
+        gelu_in_place(&mut gelu);
+
+        for (i, &value) in values.iter().enumerate() {
+            let x = F32(value);
+            assert_eq!(sigmoid[i], x.sigmoid().0);
+            assert_eq!(tanh[i], x.tanh().0);
+            assert_eq!(softplus[i], x.softplus().0);
+            assert_eq!(gelu[i], x.gelu().0);
+        }
+    }
+
+    #[test]
+    fn softmax() {
+        let mut values = [1.0, 2.0, 3.0, 4.0];
+        softmax_in_place(&mut values);
+
+        let sum: f32 = [1.0f32, 2.0, 3.0, 4.0].iter().map(|x| x.exp()).sum();
+
+        for (i, &actual) in values.iter().enumerate() {
+            let expected = (i as f32 + 1.0).exp() / sum;
+            let delta = (actual - expected).abs();
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large: {} vs {}",
+                delta,
+                actual,
+                expected
+            );
+        }
+
+        let total: f32 = values.iter().sum();
+        assert!((total - 1.0).abs() <= f32::EPSILON * 4.0);
+    }
+
+    #[test]
+    fn softmax_large_inputs() {
+        // Would overflow `e^x` without subtracting the maximum first
+        let mut values = [1000.0, 1000.0, 999.0];
+        softmax_in_place(&mut values);
+
+        assert!(values.iter().all(|x| x.is_finite()));
+        assert_eq!(values[0], values[1]);
+        assert!((values[2] - 0.155_362_4).abs() <= MAX_ERROR);
+
+        let mut empty: [f32; 0] = [];
+        softmax_in_place(&mut empty);
+    }
+}
@@ -0,0 +1,314 @@
+//! Approximate equality comparisons.
+
+use crate::F32;
+
+/// Approximate equality comparisons, for checking results of floating point
+/// approximations against expected values.
+///
+/// Compound types such as vectors and quaternions are approximately equal if
+/// each of their components are.
+///
+/// The [`assert_approx_eq!`](crate::assert_approx_eq) and
+/// [`assert_approx_ne!`](crate::assert_approx_ne) macros assert on these
+/// comparisons, and can be used in `no_std` tests.
+///
+/// ```
+/// use micromath::{assert_approx_eq, ApproxEq, F32};
+///
+/// assert!(F32(2.0).sqrt().abs_diff_eq(&F32(1.414), 0.1));
+/// assert!(1000.0f32.relative_eq(&1000.1, 1e-4));
+/// assert!(1.0f32.ulps_eq(&(1.0 + f32::EPSILON), 1));
+///
+/// assert_approx_eq!(F32(9.0).sqrt(), F32(3.0), abs <= 0.2);
+/// assert_approx_eq!(1e6f32, 1.000_001e6, rel <= 1e-6);
+/// assert_approx_eq!(0.1f32 + 0.2, 0.3, ulps <= 1);
+/// ```
+pub trait ApproxEq<Rhs: ?Sized = Self> {
+    /// Are `self` and `other` equal, or is the absolute difference between
+    /// them at most `epsilon`?
+    fn abs_diff_eq(&self, other: &Rhs, epsilon: f32) -> bool;
+
+    /// Are `self` and `other` equal, or is the absolute difference between
+    /// them at most `max_relative` times the larger of their magnitudes?
+    ///
+    /// An infinity is only approximately equal to itself.
+    fn relative_eq(&self, other: &Rhs, max_relative: f32) -> bool;
+
+    /// Are `self` and `other` equal, or at most `max_ulps` representable
+    /// values apart?
+    ///
+    /// NaN is never approximately equal to anything.
+    fn ulps_eq(&self, other: &Rhs, max_ulps: u32) -> bool;
+}
+
+impl ApproxEq for f32 {
+    fn abs_diff_eq(&self, other: &f32, epsilon: f32) -> bool {
+        // Exact comparison first, so infinities compare equal
+        self == other || F32(self - other).abs() <= epsilon
+    }
+
+    fn relative_eq(&self, other: &f32, max_relative: f32) -> bool {
+        if self == other {
+            return true;
+        }
+
+        // Otherwise the infinite tolerance would accept any finite value
+        if !self.is_finite() || !other.is_finite() {
+            return false;
+        }
+
+        let largest = F32(*self).abs().max(F32(*other).abs());
+        F32(self - other).abs() <= largest * max_relative
+    }
+
+    fn ulps_eq(&self, other: &f32, max_ulps: u32) -> bool {
+        if self == other {
+            return true;
+        }
+
+        if self.is_nan() || other.is_nan() {
+            return false;
+        }
+
+        ulps_distance(*self, *other) <= u64::from(max_ulps)
+    }
+}
+
+impl ApproxEq for F32 {
+    fn abs_diff_eq(&self, other: &F32, epsilon: f32) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+
+    fn relative_eq(&self, other: &F32, max_relative: f32) -> bool {
+        self.0.relative_eq(&other.0, max_relative)
+    }
+
+    fn ulps_eq(&self, other: &F32, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, max_ulps)
+    }
+}
+
+impl<T, const N: usize> ApproxEq for [T; N]
+where
+    T: ApproxEq,
+{
+    fn abs_diff_eq(&self, other: &[T; N], epsilon: f32) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+
+    fn relative_eq(&self, other: &[T; N], max_relative: f32) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.relative_eq(b, max_relative))
+    }
+
+    fn ulps_eq(&self, other: &[T; N], max_ulps: u32) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.ulps_eq(b, max_ulps))
+    }
+}
+
+/// Number of representable values between two non-NaN floats.
+pub(crate) fn ulps_distance(a: f32, b: f32) -> u64 {
+    ulps_key(a).abs_diff(ulps_key(b))
+}
+
+/// Maps the bits of a non-NaN float onto an integer with the same ordering
+/// as the floats, with both zeros mapped to `0`.
+pub(crate) fn ulps_key(x: f32) -> i64 {
+    let x = F32(x);
+    let magnitude = i64::from(x.abs().to_bits());
+
+    if x.is_sign_negative() {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Asserts that two values are approximately equal using [`ApproxEq`].
+///
+/// The comparison is given after the values as `abs <= epsilon`,
+/// `rel <= max_relative` or `ulps <= max_ulps`, which calls
+/// [`ApproxEq::abs_diff_eq`], [`ApproxEq::relative_eq`] or
+/// [`ApproxEq::ulps_eq`] respectively. Both values must implement `Debug`.
+///
+/// ```
+/// use micromath::{assert_approx_eq, F32};
+///
+/// assert_approx_eq!(F32(0.5).exp(), F32(1.648_721), abs <= 0.003);
+/// assert_approx_eq!(F32(0.5).exp(), F32(1.648_721), rel <= 0.002);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr, $kind:ident <= $tolerance:expr $(,)?) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if !$crate::__approx_eq!(left, right, $kind, tolerance) {
+                    panic!(
+                        "assertion `left ≈ right` failed ({} <= {:?})\n  left: {:?}\n right: {:?}",
+                        stringify!($kind),
+                        tolerance,
+                        left,
+                        right
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two values are not approximately equal using [`ApproxEq`].
+///
+/// Takes the same comparisons as [`assert_approx_eq!`].
+///
+/// ```
+/// use micromath::{assert_approx_ne, F32};
+///
+/// assert_approx_ne!(F32(1.0), F32(1.1), abs <= 0.05);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($left:expr, $right:expr, $kind:ident <= $tolerance:expr $(,)?) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                if $crate::__approx_eq!(left, right, $kind, tolerance) {
+                    panic!(
+                        "assertion `left !≈ right` failed ({} <= {:?})\n  left: {:?}\n right: {:?}",
+                        stringify!($kind),
+                        tolerance,
+                        left,
+                        right
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Dispatches the comparisons of [`assert_approx_eq!`] to [`ApproxEq`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __approx_eq {
+    ($left:expr, $right:expr, abs, $tolerance:expr) => {
+        $crate::ApproxEq::abs_diff_eq($left, $right, $tolerance)
+    };
+    ($left:expr, $right:expr, rel, $tolerance:expr) => {
+        $crate::ApproxEq::relative_eq($left, $right, $tolerance)
+    };
+    ($left:expr, $right:expr, ulps, $tolerance:expr) => {
+        $crate::ApproxEq::ulps_eq($left, $right, $tolerance)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ApproxEq;
+    use crate::F32;
+
+    #[test]
+    fn abs_diff_eq() {
+        assert!(1.0f32.abs_diff_eq(&1.001, 0.002));
+        assert!(!1.0f32.abs_diff_eq(&1.003, 0.002));
+        assert!(f32::INFINITY.abs_diff_eq(&f32::INFINITY, 0.0));
+        assert!(!f32::INFINITY.abs_diff_eq(&f32::NEG_INFINITY, 1.0));
+        assert!(!f32::NAN.abs_diff_eq(&f32::NAN, 1.0));
+        assert!(F32(-2.0).abs_diff_eq(&F32(-2.1), 0.11));
+    }
+
+    #[test]
+    fn relative_eq() {
+        assert!(1000.0f32.relative_eq(&1001.0, 0.001));
+        assert!(!1000.0f32.relative_eq(&1002.0, 0.001));
+        assert!(0.0f32.relative_eq(&-0.0, 0.0));
+        assert!(!0.0f32.relative_eq(&f32::MIN_POSITIVE, 0.5));
+        assert!(f32::INFINITY.relative_eq(&f32::INFINITY, 0.0));
+        assert!(!f32::INFINITY.relative_eq(&1.0, 0.5));
+        assert!(!1.0f32.relative_eq(&f32::NEG_INFINITY, 0.5));
+        assert!(!f32::MAX.relative_eq(&f32::INFINITY, 1.0));
+        assert!(!f32::INFINITY.relative_eq(&f32::NEG_INFINITY, 1.0));
+        assert!(!f32::NAN.relative_eq(&f32::NAN, 1.0));
+        assert!(F32(-1e-20).relative_eq(&F32(-1.000_1e-20), 1e-3));
+    }
+
+    #[test]
+    fn ulps_eq() {
+        let one = 1.0f32;
+        let next = f32::from_bits(one.to_bits() + 3);
+
+        assert!(one.ulps_eq(&next, 3));
+        assert!(!one.ulps_eq(&next, 2));
+        assert!(0.0f32.ulps_eq(&-0.0, 0));
+
+        // Across zero
+        let tiny = f32::from_bits(1);
+        assert!(tiny.ulps_eq(&-tiny, 2));
+        assert!(!tiny.ulps_eq(&-tiny, 1));
+
+        assert!(f32::MAX.ulps_eq(&f32::INFINITY, 1));
+        assert!(!f32::MAX.ulps_eq(&f32::MIN, u32::MAX / 2));
+        assert!(!f32::NAN.ulps_eq(&f32::NAN, u32::MAX));
+        assert!(F32(one).ulps_eq(&F32(next), 3));
+    }
+
+    #[test]
+    fn arrays() {
+        assert!([1.0f32, 2.0].abs_diff_eq(&[1.01, 1.99], 0.02));
+        assert!(![1.0f32, 2.0].abs_diff_eq(&[1.01, 1.9], 0.02));
+    }
+
+    #[test]
+    fn macros() {
+        assert_approx_eq!(F32(2.0), F32(2.001), abs <= 0.01);
+        assert_approx_eq!(100.0f32, 100.5, rel <= 0.01);
+        assert_approx_eq!(1.0f32, 1.0 + f32::EPSILON, ulps <= 1);
+        assert_approx_ne!(1.0f32, 1.1, abs <= 0.01);
+    }
+
+    #[test]
+    #[should_panic(expected = "abs <= 0.01")]
+    fn macro_failure() {
+        assert_approx_eq!(1.0f32, 1.1, abs <= 0.01);
+    }
+
+    #[cfg(feature = "vector")]
+    #[test]
+    fn vectors() {
+        use crate::vector::{F32x2, F32x3};
+
+        let a = F32x2 { x: 1.0, y: -2.0 };
+        let b = F32x2 {
+            x: 1.001,
+            y: -2.001,
+        };
+        assert_approx_eq!(a, b, abs <= 0.002);
+        assert_approx_ne!(a, b, abs <= 0.0001);
+
+        let a = F32x3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        let b = F32x3 {
+            x: 1.0,
+            y: 2.0,
+            z: 3.1,
+        };
+        assert_approx_eq!(a, b, rel <= 0.05);
+        assert_approx_ne!(a, b, rel <= 0.01);
+    }
+
+    #[cfg(feature = "quaternion")]
+    #[test]
+    fn quaternions() {
+        use crate::Quaternion;
+
+        let q = Quaternion::new(1.0, 0.0, 0.0, 0.0);
+        let r = Quaternion::new(0.999_9, 0.0, 0.01, 0.0);
+        assert_approx_eq!(q, r, abs <= 0.02);
+        assert_approx_ne!(q, r, abs <= 0.001);
+    }
+}
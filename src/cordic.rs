@@ -0,0 +1,466 @@
+//! CORDIC (COordinate Rotation DIgital Computer) implementations of
+//! `sin_cos`, `atan2` and vector magnitude.
+//!
+//! CORDIC computes these functions using only shifts, additions and a small
+//! table of arctangents, which makes it a good fit for targets without an FPU
+//! or hardware multiplier. [`polar`] computes the magnitude and angle of a
+//! vector simultaneously.
+//!
+//! The functions in this module are generic over the [`Cordic`] trait, which
+//! is impl'd for [`F32`], `f32`, the fixed-point types [`Q15`], [`Q31`] and
+//! [`I16F16`], as well as `i16` and `i32`. Angles are in the natural unit of
+//! each type:
+//!
+//! - [`F32`], `f32` and [`I16F16`]: radians
+//! - [`Q15`] and [`Q31`]: half-turns, i.e. `[-1, 1)` covers a full circle
+//! - `i16` and `i32`: binary angles, i.e. the full range of the integer covers
+//!   a full circle, with `MIN` being `-π`
+//!
+//! Results of `sin_cos` for `i16` and `i32` are scaled like [`Q15`] and
+//! [`Q31`] respectively, and all integer and fixed-point results saturate at
+//! the bounds of the type.
+//!
+//! ```
+//! use micromath::cordic;
+//!
+//! // Heading and magnitude of an accelerometer reading, without floats
+//! let (magnitude, heading) = cordic::polar(3000_i16, 4000);
+//!
+//! assert_eq!(magnitude, 5000);
+//! assert_eq!(heading, 9672); // 53.13 degrees as a binary angle
+//! ```
+//!
+//! Method described at: <https://en.wikipedia.org/wiki/CORDIC>
+
+use crate::{
+    fixed::{Fixed, WIDE_FRAC_BITS},
+    F32, I16F16, Q15, Q31,
+};
+use core::f32::consts::PI;
+
+/// Number of CORDIC iterations, one per bit of precision.
+const ITERATIONS: usize = 30;
+
+/// `atan(2^-i)` as binary angles, where `1 << 32` is a full turn.
+const ATAN_TABLE: [i64; ITERATIONS] = [
+    536_870_912,
+    316_933_406,
+    167_458_907,
+    85_004_756,
+    42_667_331,
+    21_354_465,
+    10_679_838,
+    5_340_245,
+    2_670_163,
+    1_335_087,
+    667_544,
+    333_772,
+    166_886,
+    83_443,
+    41_722,
+    20_861,
+    10_430,
+    5_215,
+    2_608,
+    1_304,
+    652,
+    326,
+    163,
+    81,
+    41,
+    20,
+    10,
+    5,
+    3,
+    1,
+];
+
+/// Inverse of the CORDIC gain, `∏ 1/√(1 + 2^-2i)`, with 30 fractional bits.
+const INV_GAIN: i64 = 652_032_874;
+
+/// Bit position of the most significant bit of the largest coordinate after
+/// normalizing a vector, leaving headroom for the CORDIC gain.
+const COORDINATE_BITS: i32 = 40;
+
+/// Types supported by the CORDIC functions in this module.
+///
+/// This trait is sealed and impl'd for [`F32`], `f32`, [`Q15`], [`Q31`],
+/// [`I16F16`], `i16` and `i32`.
+pub trait Cordic: Copy + sealed::Sealed {}
+
+impl<T: sealed::Sealed> Cordic for T {}
+
+/// Simultaneously computes the sine and cosine of `angle`.
+/// Returns `(sin(angle), cos(angle))`.
+pub fn sin_cos<T: Cordic>(angle: T) -> (T, T) {
+    match angle.to_angle() {
+        Some(angle) => {
+            let (sin, cos) = rotate(angle);
+            (T::from_unit(sin), T::from_unit(cos))
+        }
+        None => (T::NAN, T::NAN),
+    }
+}
+
+/// Computes the four quadrant arctangent of `y` and `x`, in the range
+/// `(-π, π]` (saturating at the largest representable angle for types which
+/// can't represent `π`).
+pub fn atan2<T: Cordic>(y: T, x: T) -> T {
+    polar(x, y).1
+}
+
+/// Computes the magnitude of the vector `(x, y)`, i.e. `sqrt(x² + y²)`.
+pub fn magnitude<T: Cordic>(x: T, y: T) -> T {
+    polar(x, y).0
+}
+
+/// Simultaneously computes the magnitude and angle of the vector `(x, y)`.
+/// Returns `(magnitude, angle)`.
+pub fn polar<T: Cordic>(x: T, y: T) -> (T, T) {
+    match T::to_coordinates(x, y) {
+        Some((x, y, exponent)) => {
+            let (magnitude, angle) = vector(x, y);
+            (
+                T::from_coordinate(magnitude, exponent),
+                T::from_angle(angle),
+            )
+        }
+        None => (T::NAN, T::NAN),
+    }
+}
+
+/// Rotation mode: computes `(sin, cos)` of a binary angle, with 30
+/// fractional bits.
+fn rotate(angle: u32) -> (i64, i64) {
+    // Fold into `[-π/2, π/2]` using `sin(x ± π) = -sin(x)`
+    let mut z = i64::from(angle as i32);
+    let negate = z.abs() > 1 << 30;
+
+    if negate {
+        z -= z.signum() << 31;
+    }
+
+    let (mut x, mut y) = (INV_GAIN, 0);
+
+    for (i, &atan) in ATAN_TABLE.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+
+        if z >= 0 {
+            x -= dx;
+            y += dy;
+            z -= atan;
+        } else {
+            x += dx;
+            y -= dy;
+            z += atan;
+        }
+    }
+
+    if negate {
+        (-y, -x)
+    } else {
+        (y, x)
+    }
+}
+
+/// Vectoring mode: computes the magnitude and angle of `(x, y)`, where the
+/// angle is a binary angle in which `1 << 31` is `π`.
+fn vector(x: i64, y: i64) -> (i64, i64) {
+    if x == 0 && y == 0 {
+        return (0, 0);
+    }
+
+    // Normalize so the largest coordinate has `COORDINATE_BITS` bits
+    let shift = x.abs().max(y.abs()).leading_zeros() as i32 - (63 - COORDINATE_BITS);
+    let (mut x, mut y) = if shift >= 0 {
+        (x << shift, y << shift)
+    } else {
+        (x >> -shift, y >> -shift)
+    };
+
+    // Rotate into the right half-plane
+    let mut angle = 0;
+
+    if x < 0 {
+        angle = if y < 0 { -1 << 31 } else { 1 << 31 };
+        x = -x;
+        y = -y;
+    }
+
+    for (i, &atan) in ATAN_TABLE.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+
+        if y > 0 {
+            x += dx;
+            y -= dy;
+            angle += atan;
+        } else {
+            x -= dx;
+            y += dy;
+            angle -= atan;
+        }
+    }
+
+    let magnitude = (i128::from(x) * i128::from(INV_GAIN)) >> WIDE_FRAC_BITS;
+    let magnitude = if shift >= 0 {
+        (magnitude + ((1 << shift) >> 1)) >> shift
+    } else {
+        magnitude << -shift
+    };
+
+    (magnitude as i64, angle)
+}
+
+/// Compute `x * 2^n` for `n` in the range `[-252, 252]`.
+fn scale(x: f32, n: i32) -> f32 {
+    let exp2 = |n: i32| f32::from_bits(((n + 127) as u32) << 23);
+    x * exp2(n / 2) * exp2(n - n / 2)
+}
+
+mod sealed {
+    use super::{scale, Fixed, COORDINATE_BITS, F32, I16F16, PI, Q15, Q31, WIDE_FRAC_BITS};
+
+    /// Sealed trait which also carries the conversions to and from the
+    /// representation used by the CORDIC engine.
+    pub trait Sealed: Copy {
+        /// Result for invalid inputs.
+        const NAN: Self;
+
+        /// Convert to a binary angle where `1 << 32` is a full turn, or `None`
+        /// if the angle is invalid.
+        fn to_angle(self) -> Option<u32>;
+
+        /// Create an angle from a binary angle where `1 << 31` is `π`.
+        fn from_angle(angle: i64) -> Self;
+
+        /// Create a value from a result with 30 fractional bits.
+        fn from_unit(unit: i64) -> Self;
+
+        /// Convert a vector to integer coordinates and an exponent, such that
+        /// `x = x' * 2^exponent`, or `None` if the vector is invalid.
+        fn to_coordinates(x: Self, y: Self) -> Option<(i64, i64, i32)>;
+
+        /// Create a value from an integer coordinate and exponent.
+        fn from_coordinate(coordinate: i64, exponent: i32) -> Self;
+    }
+
+    impl Sealed for F32 {
+        const NAN: Self = F32::NAN;
+
+        fn to_angle(self) -> Option<u32> {
+            if self.is_finite() {
+                let angle = self.reduce_angle() * (2_147_483_648.0 / PI);
+                Some(angle.0 as i64 as u32)
+            } else {
+                None
+            }
+        }
+
+        fn from_angle(angle: i64) -> Self {
+            F32(angle as f32 * (PI / 2_147_483_648.0))
+        }
+
+        fn from_unit(unit: i64) -> Self {
+            F32(unit as f32 * (1.0 / (1 << WIDE_FRAC_BITS) as f32))
+        }
+
+        fn to_coordinates(x: Self, y: Self) -> Option<(i64, i64, i32)> {
+            if !x.is_finite() || !y.is_finite() {
+                return None;
+            }
+
+            // Scale so the larger coordinate is an integer of
+            // `COORDINATE_BITS` bits, which is exact
+            let exponent =
+                x.extract_exponent_value().max(y.extract_exponent_value()) - COORDINATE_BITS;
+            let (x, y) = (scale(x.0, -exponent), scale(y.0, -exponent));
+            Some((x as i64, y as i64, exponent))
+        }
+
+        fn from_coordinate(coordinate: i64, exponent: i32) -> Self {
+            F32(scale(coordinate as f32, exponent))
+        }
+    }
+
+    impl Sealed for f32 {
+        const NAN: Self = f32::NAN;
+
+        fn to_angle(self) -> Option<u32> {
+            F32(self).to_angle()
+        }
+
+        fn from_angle(angle: i64) -> Self {
+            F32::from_angle(angle).0
+        }
+
+        fn from_unit(unit: i64) -> Self {
+            F32::from_unit(unit).0
+        }
+
+        fn to_coordinates(x: Self, y: Self) -> Option<(i64, i64, i32)> {
+            F32::to_coordinates(F32(x), F32(y))
+        }
+
+        fn from_coordinate(coordinate: i64, exponent: i32) -> Self {
+            F32::from_coordinate(coordinate, exponent).0
+        }
+    }
+
+    macro_rules! impl_sealed_fixed {
+        ($fixed:ty) => {
+            impl Sealed for $fixed {
+                const NAN: Self = <$fixed>::ZERO;
+
+                fn to_angle(self) -> Option<u32> {
+                    Some(Fixed::to_angle(self))
+                }
+
+                fn from_angle(angle: i64) -> Self {
+                    Fixed::from_angle(angle)
+                }
+
+                fn from_unit(unit: i64) -> Self {
+                    Self::from_wide(unit)
+                }
+
+                fn to_coordinates(x: Self, y: Self) -> Option<(i64, i64, i32)> {
+                    Some((x.to_raw(), y.to_raw(), 0))
+                }
+
+                fn from_coordinate(coordinate: i64, _exponent: i32) -> Self {
+                    Self::from_raw(coordinate)
+                }
+            }
+        };
+    }
+
+    impl_sealed_fixed!(Q15);
+    impl_sealed_fixed!(Q31);
+    impl_sealed_fixed!(I16F16);
+
+    macro_rules! impl_sealed_int {
+        ($int:ty, $fixed:ty) => {
+            impl Sealed for $int {
+                const NAN: Self = 0;
+
+                fn to_angle(self) -> Option<u32> {
+                    Sealed::to_angle(<$fixed>::from_bits(self))
+                }
+
+                fn from_angle(angle: i64) -> Self {
+                    <$fixed as Sealed>::from_angle(angle).to_bits()
+                }
+
+                fn from_unit(unit: i64) -> Self {
+                    <$fixed>::from_unit(unit).to_bits()
+                }
+
+                fn to_coordinates(x: Self, y: Self) -> Option<(i64, i64, i32)> {
+                    Some((x.into(), y.into(), 0))
+                }
+
+                fn from_coordinate(coordinate: i64, exponent: i32) -> Self {
+                    <$fixed>::from_coordinate(coordinate, exponent).to_bits()
+                }
+            }
+        };
+    }
+
+    impl_sealed_int!(i16, Q15);
+    impl_sealed_int!(i32, Q31);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{atan2, magnitude, polar, sin_cos};
+    use crate::{F32, I16F16, Q15, Q31};
+    use core::f32::consts::{PI, TAU};
+
+    /// Maximum error for `F32`
+    const MAX_ERROR: f32 = 1e-6;
+
+    #[test]
+    fn f32_sin_cos() {
+        for i in -10_000..=10_000 {
+            let x = i as f32 * (TAU / 1_000.0);
+            let (sin, cos) = sin_cos(F32(x));
+
+            for &(actual, expected) in &[(sin.0, x.sin()), (cos.0, x.cos())] {
+                let delta = (actual - expected).abs();
+                assert!(delta <= MAX_ERROR, "delta {} too large for {}", delta, x);
+            }
+        }
+
+        assert!(sin_cos(F32::INFINITY).0.is_nan());
+    }
+
+    #[test]
+    fn f32_polar() {
+        for i in 0..1_000 {
+            let angle = i as f32 * (TAU / 1_000.0) - PI;
+
+            for &radius in &[1.0e-20_f32, 0.001, 1.0, 12_345.0, 1.0e30] {
+                let (x, y) = (radius * angle.cos(), radius * angle.sin());
+                let (actual_magnitude, actual_angle) = polar(F32(x), F32(y));
+
+                let delta = (actual_angle.0 - y.atan2(x)).abs();
+                assert!(
+                    delta <= MAX_ERROR,
+                    "delta {} too large for {}",
+                    delta,
+                    angle
+                );
+
+                let expected = x.hypot(y);
+                let delta = (actual_magnitude.0 - expected).abs() / expected;
+                assert!(
+                    delta <= MAX_ERROR,
+                    "delta {} too large for {}",
+                    delta,
+                    radius
+                );
+            }
+        }
+
+        assert_eq!(polar(F32(0.0), F32(0.0)), (F32(0.0), F32(0.0)));
+        assert_eq!(atan2(0.0, -1.0), PI);
+        assert!(magnitude(F32::NAN, F32(1.0)).is_nan());
+    }
+
+    #[test]
+    fn fixed_sin_cos() {
+        // Half a turn is `π` for `Q15` and `Q31`
+        assert_eq!(sin_cos(Q15::from_f32(0.5)), (Q15::MAX, Q15::ZERO));
+
+        let (sin, cos) = sin_cos(Q31::from_f32(-0.5));
+        assert_eq!(sin, Q31::MIN);
+        assert!(cos.to_bits().abs() <= 8);
+
+        let (sin, cos) = sin_cos(I16F16::from_f32(1.0));
+        assert!((sin.to_f32() - 1.0_f32.sin()).abs() <= 2e-5);
+        assert!((cos.to_f32() - 1.0_f32.cos()).abs() <= 2e-5);
+    }
+
+    #[test]
+    fn integer_polar() {
+        assert_eq!(polar(3000_i16, 4000), (5000, 9672));
+        assert_eq!(polar(-30_000_i16, 0), (30_000, i16::MAX));
+        assert_eq!(polar(0_i16, -1), (1, -16_384));
+        assert_eq!(magnitude(30_000_i16, 30_000), i16::MAX);
+        assert_eq!(magnitude(i32::MAX, 0), i32::MAX);
+        assert_eq!(magnitude(1_i32 << 20, 1 << 20), 1_482_910);
+        assert!((atan2(1_i32, 1) - (1 << 29)).abs() <= 8);
+        assert_eq!(sin_cos(1_i16 << 14), (i16::MAX, 0));
+    }
+
+    #[test]
+    fn fixed_polar() {
+        let (magnitude, angle) = polar(I16F16::from_f32(-3.0), I16F16::from_f32(4.0));
+        assert!((magnitude.to_f32() - 5.0).abs() <= 2e-5);
+        assert!((angle.to_f32() - 4.0_f32.atan2(-3.0)).abs() <= 2e-5);
+
+        let (magnitude, angle) = polar(Q31::from_f32(0.5), Q31::from_f32(-0.5));
+        assert!((magnitude.to_f32() - 0.5_f32.hypot(0.5)).abs() <= 1e-6);
+        assert!((angle.to_f32() + 0.25).abs() <= 1e-6);
+    }
+}
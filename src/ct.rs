@@ -0,0 +1,332 @@
+//! Branch-free, constant-time variants of core [`F32`] operations.
+//!
+//! The functions in this module execute the same sequence of instructions
+//! regardless of their inputs: there are no early returns or data-dependent
+//! loops. Special cases like zero, infinity and NaN are handled by computing
+//! every candidate result and choosing between them with bit masks, which
+//! pass through [`core::hint::black_box`] so the optimizer doesn't turn them
+//! back into branches.
+//!
+//! This makes them suitable for code which must not leak information through
+//! timing, or which needs a deterministic cycle budget. Note that:
+//!
+//! - Rust doesn't guarantee that the generated code is branch-free. CI runs
+//!   `ci/ct-branches.sh`, which checks the x86_64 assembly of every function
+//!   in this module for conditional branches; it also supports aarch64, but
+//!   the output for other targets should be inspected when it matters.
+//! - Many FPUs take extra cycles to process subnormal operands or results.
+//!   Subnormal inputs are supported, but avoiding them entirely is the only
+//!   way to rule out that timing difference.
+//!
+//! ```
+//! use micromath::{ct, F32};
+//!
+//! let x = ct::sqrt(F32(2.0));
+//! assert!((x.0 - 1.414_213_5).abs() < 1e-6);
+//! ```
+
+use crate::{
+    float::{exp::EXP_TAYLOR_SERIES, ln_1p::ATANH_SERIES, EXPONENT_MASK, MANTISSA_MASK, SIGN_MASK},
+    poly, F32,
+};
+use core::{f32::consts::LOG2_E, hint::black_box};
+
+/// Number of Newton's method iterations used by [`invsqrt`] and [`sqrt`].
+const NEWTON_ITERATIONS: usize = 3;
+
+/// Inputs above `ln(f32::MAX)` overflow [`exp`] to infinity.
+const EXP_OVERFLOW: f32 = 88.722_84;
+
+/// Inputs below `ln(2^-150)` underflow [`exp`] to zero.
+const EXP_UNDERFLOW: f32 = -103.972_08;
+
+/// Adding `1.5 * 2^23` rounds an `f32` of magnitude less than `2^22` to an
+/// integer, which can then be read from the low bits of the sum.
+const ROUNDING_SHIFT: f32 = 12_582_912.0;
+
+/// Upper bits of `ln(2)`, such that `n * LN_2_HI` is exact for `|n| < 2^8`.
+const LN_2_HI: f32 = 0.693_145_75;
+
+/// `ln(2) - LN_2_HI`
+const LN_2_LO: f32 = 1.428_606_8e-6;
+
+/// Mantissa bits of `sqrt(2)`.
+const SQRT_2_MANTISSA: u32 = 0x0035_04f3;
+
+/// `2^25`, used to normalize subnormals.
+const SUBNORMAL_SCALE: f32 = 33_554_432.0;
+
+/// Returns `a` if `choice` is `true`, otherwise `b`, without branching.
+pub fn select(choice: bool, a: F32, b: F32) -> F32 {
+    F32::from_bits(select_bits(mask(choice), a.to_bits(), b.to_bits()))
+}
+
+/// Computes the absolute value of `x`.
+pub fn abs(x: F32) -> F32 {
+    F32::from_bits(x.to_bits() & !SIGN_MASK)
+}
+
+/// Returns a number composed of the magnitude of `x` and the sign of `sign`.
+pub fn copysign(x: F32, sign: F32) -> F32 {
+    F32::from_bits((x.to_bits() & !SIGN_MASK) | (sign.to_bits() & SIGN_MASK))
+}
+
+/// Returns the minimum of `x` and `y`. If one of the arguments is NaN the
+/// other is returned, as with [`F32::min`].
+pub fn min(x: F32, y: F32) -> F32 {
+    select((y.0 < x.0) | x.is_nan(), y, x)
+}
+
+/// Returns the maximum of `x` and `y`. If one of the arguments is NaN the
+/// other is returned, as with [`F32::max`].
+pub fn max(x: F32, y: F32) -> F32 {
+    select((y.0 > x.0) | x.is_nan(), y, x)
+}
+
+/// Approximates `1/sqrt(x)` with a maximum relative error of `5e-7`.
+///
+/// Returns [`F32::NAN`] if `x` is negative, and an infinity with the sign of
+/// `x` if `x` is zero.
+pub fn invsqrt(x: F32) -> F32 {
+    let (scaled, scale) = normalize(x);
+    let mut result = invsqrt_newton(scaled) * select(scale, F32(4096.0), F32::ONE);
+
+    result = select(x.0 == 0.0, copysign(F32::INFINITY, x), result);
+    result = select(x.is_infinite(), F32::ZERO, result);
+    select((x.0 < 0.0) | x.is_nan(), F32::NAN, result)
+}
+
+/// Approximates `sqrt(x)` with a maximum relative error of `5e-7`.
+///
+/// Returns [`F32::NAN`] if `x` is negative.
+pub fn sqrt(x: F32) -> F32 {
+    let (scaled, scale) = normalize(x);
+    let mut result = scaled * invsqrt_newton(scaled);
+    result *= select(scale, F32(1.0 / 4096.0), F32::ONE);
+
+    result = select(x.is_infinite(), x, result);
+    select((x.0 < 0.0) | x.is_nan(), F32::NAN, result)
+}
+
+/// Approximates `e^x` with a maximum relative error of `5e-7`.
+pub fn exp(x: F32) -> F32 {
+    let clamped = max(min(x, F32(EXP_OVERFLOW)), F32(EXP_UNDERFLOW)).0;
+
+    // Split into `n * ln(2) + r` where `|r| <= ln(2)/2`
+    let shifted = clamped * LOG2_E + ROUNDING_SHIFT;
+    let n = (shifted.to_bits() as i32).wrapping_sub(ROUNDING_SHIFT.to_bits() as i32);
+    let n_f = shifted - ROUNDING_SHIFT;
+    let r = (clamped - n_f * LN_2_HI) - n_f * LN_2_LO;
+
+    let p = poly::horner(F32(r), &EXP_TAYLOR_SERIES).0;
+
+    // Scale by `2^n` in two steps so neither factor over- or underflows
+    let half = n >> 1;
+    let mut result = F32(p * pow2(half) * pow2(n - half));
+
+    result = select(x.0 > EXP_OVERFLOW, F32::INFINITY, result);
+    result = select(x.0 < EXP_UNDERFLOW, F32::ZERO, result);
+    select(x.is_nan(), x, result)
+}
+
+/// Approximates `ln(x)` with a maximum absolute error of `2e-7` for `x` in
+/// `[0.5, 2]`, and a maximum relative error of `2e-7` elsewhere.
+///
+/// Returns [`F32::NEG_INFINITY`] if `x` is zero, and [`F32::NAN`] if `x` is
+/// negative.
+pub fn ln(x: F32) -> F32 {
+    let is_subnormal = x.to_bits() & EXPONENT_MASK == 0;
+    let bits = select(is_subnormal, x * SUBNORMAL_SCALE, x).to_bits();
+
+    // Split into `m * 2^e` where `sqrt(1/2) <= m < sqrt(2)`
+    let mantissa = bits & MANTISSA_MASK;
+    let adjust = (mantissa + (0x0080_0000 - SQRT_2_MANTISSA)) >> 23;
+    let m = F32::from_bits(mantissa | ((127 - adjust) << 23)).0;
+    let e = ((bits & EXPONENT_MASK) >> 23) as i32 - 127 + adjust as i32
+        - (mask(is_subnormal) & 25) as i32;
+
+    // `ln(m) = 2 * atanh(s)` where `s = (m - 1) / (m + 1)`
+    let s = (m - 1.0) / (m + 1.0);
+    let p = poly::horner(F32(s * s), &ATANH_SERIES).0;
+
+    let e = e as f32;
+    let mut result = F32(e * LN_2_HI + (e * LN_2_LO + 2.0 * s * p));
+
+    result = select(x.is_infinite(), x, result);
+    result = select(x.0 == 0.0, F32::NEG_INFINITY, result);
+    select((x.0 < 0.0) | x.is_nan(), F32::NAN, result)
+}
+
+/// Computes `x^n` by repeated squaring, always performing 32 steps.
+pub fn powi(x: F32, n: i32) -> F32 {
+    let mut base = select(n < 0, F32(1.0 / x.0), x).0;
+    let mut exponent = n.unsigned_abs();
+    let mut result = 1.0;
+
+    for _ in 0..32 {
+        result *= select(exponent & 1 == 1, F32(base), F32::ONE).0;
+        base *= base;
+        exponent >>= 1;
+    }
+
+    F32(result)
+}
+
+/// All ones if `choice` is `true`, otherwise zero.
+#[inline]
+fn mask(choice: bool) -> u32 {
+    black_box(u32::from(choice)).wrapping_neg()
+}
+
+/// Bits of `a` where `mask` is set, otherwise bits of `b`.
+#[inline]
+fn select_bits(mask: u32, a: u32, b: u32) -> u32 {
+    (a & mask) | (b & !mask)
+}
+
+/// Scale subnormals by `2^24` so the bit hack in [`invsqrt_newton`] gives a
+/// good initial guess. Returns `(scaled, is_subnormal)`.
+#[inline]
+fn normalize(x: F32) -> (F32, bool) {
+    let is_subnormal = (x.to_bits() & EXPONENT_MASK == 0) & (x.0 != 0.0);
+    (select(is_subnormal, x * 16_777_216.0, x), is_subnormal)
+}
+
+/// Approximate `1/sqrt(x)` for positive normal `x` with a bit hack and a
+/// fixed number of Newton's method iterations.
+#[inline]
+fn invsqrt_newton(x: F32) -> F32 {
+    let mut y = F32::from_bits(0x5f37_5a86u32.wrapping_sub(x.to_bits() >> 1)).0;
+
+    for _ in 0..NEWTON_ITERATIONS {
+        y *= 1.5 - 0.5 * x.0 * y * y;
+    }
+
+    F32(y)
+}
+
+/// Computes `2^n` for `n` in `[-126, 127]`.
+#[inline]
+fn pow2(n: i32) -> f32 {
+    f32::from_bits(((n + 127) as u32) << 23)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Maximum relative error of [`sqrt`], [`invsqrt`] and [`exp`]
+    const MAX_ERROR: f32 = 5e-7;
+
+    /// Maximum error of [`ln`]
+    const LN_MAX_ERROR: f32 = 2e-7;
+
+    /// Inputs spread over the whole `f32` range, including subnormals
+    fn inputs() -> impl Iterator<Item = f32> {
+        (1..=0x7f7f_ffffu32).step_by(0x1_0001).map(f32::from_bits)
+    }
+
+    fn relative_error(actual: f32, expected: f64) -> f64 {
+        ((f64::from(actual) - expected) / expected).abs()
+    }
+
+    #[test]
+    fn select_abs_copysign() {
+        assert_eq!(select(true, F32(1.0), F32(2.0)), F32(1.0));
+        assert_eq!(select(false, F32(1.0), F32(2.0)), F32(2.0));
+        assert_eq!(abs(F32(-3.0)), F32(3.0));
+        assert_eq!(abs(F32(3.0)), F32(3.0));
+        assert_eq!(copysign(F32(3.0), F32(-0.0)), F32(-3.0));
+    }
+
+    #[test]
+    fn min_max() {
+        for &(x, y) in &[(1.0, 2.0), (2.0, 1.0), (-1.0, 1.0), (f32::NAN, 1.0)] {
+            assert_eq!(min(F32(x), F32(y)), F32(x).min(F32(y)));
+            assert_eq!(max(F32(x), F32(y)), F32(x).max(F32(y)));
+            assert_eq!(min(F32(y), F32(x)), F32(y).min(F32(x)));
+            assert_eq!(max(F32(y), F32(x)), F32(y).max(F32(x)));
+        }
+    }
+
+    #[test]
+    fn sqrt_invsqrt() {
+        for x in inputs() {
+            let expected = f64::from(x).sqrt();
+            let error = relative_error(sqrt(F32(x)).0, expected);
+            assert!(error <= f64::from(MAX_ERROR), "error {} for {}", error, x);
+
+            let error = relative_error(invsqrt(F32(x)).0, 1.0 / expected);
+            assert!(error <= f64::from(MAX_ERROR), "error {} for {}", error, x);
+        }
+
+        assert_eq!(sqrt(F32(0.0)).to_bits(), 0);
+        assert_eq!(sqrt(F32(-0.0)).to_bits(), (-0.0f32).to_bits());
+        assert_eq!(sqrt(F32::INFINITY), F32::INFINITY);
+        assert!(sqrt(F32(-1.0)).is_nan());
+        assert!(sqrt(F32::NAN).is_nan());
+
+        assert_eq!(invsqrt(F32(0.0)), F32::INFINITY);
+        assert_eq!(invsqrt(F32(-0.0)), F32::NEG_INFINITY);
+        assert_eq!(invsqrt(F32::INFINITY), F32::ZERO);
+        assert!(invsqrt(F32(-1.0)).is_nan());
+        assert!(invsqrt(F32::NAN).is_nan());
+    }
+
+    #[test]
+    fn exp_ln() {
+        for i in -104_000..=88_000 {
+            let x = i as f32 * 0.001;
+            let expected = f64::from(x).exp();
+
+            // Subnormal results lose precision
+            if expected >= f64::from(f32::MIN_POSITIVE) {
+                let error = relative_error(exp(F32(x)).0, expected);
+                assert!(error <= f64::from(MAX_ERROR), "error {} for {}", error, x);
+            }
+        }
+
+        for x in inputs() {
+            let expected = f64::from(x).ln();
+            let error = (f64::from(ln(F32(x)).0) - expected).abs() / expected.abs().max(1.0);
+            assert!(
+                error <= f64::from(LN_MAX_ERROR),
+                "error {} for {}",
+                error,
+                x
+            );
+        }
+
+        assert_eq!(exp(F32(0.0)), F32::ONE);
+        assert_eq!(exp(F32(100.0)), F32::INFINITY);
+        assert_eq!(exp(F32(-200.0)), F32::ZERO);
+        assert_eq!(exp(F32::NEG_INFINITY), F32::ZERO);
+        assert!(exp(F32::NAN).is_nan());
+
+        assert_eq!(ln(F32::ONE), F32::ZERO);
+        assert_eq!(ln(F32(0.0)), F32::NEG_INFINITY);
+        assert_eq!(ln(F32::INFINITY), F32::INFINITY);
+        assert!(ln(F32(-1.0)).is_nan());
+        assert!(ln(F32::NAN).is_nan());
+    }
+
+    #[test]
+    fn powi_matches() {
+        for &x in &[0.0, -0.0, 0.5, -1.5, 2.0, 10.0, f32::INFINITY] {
+            for &n in &[0, 1, 2, 3, -1, -2, -5, 7, 30, -30, i32::MAX, i32::MIN] {
+                let actual = powi(F32(x), n).0;
+                let expected = f64::from(x).powi(n) as f32;
+
+                if expected.is_finite() && expected != 0.0 {
+                    let error = relative_error(actual, f64::from(expected));
+                    assert!(error <= 4e-6, "{}^{}: {} vs {}", x, n, actual, expected);
+                } else {
+                    assert_eq!(actual, expected, "{}^{}", x, n);
+                }
+            }
+        }
+
+        assert_eq!(powi(F32::NAN, 0), F32::ONE);
+        assert!(powi(F32::NAN, 2).is_nan());
+    }
+}
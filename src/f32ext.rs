@@ -1,6 +1,6 @@
 //! `f32` extension
 
-use crate::float::F32;
+use crate::{float::F32, precision::Precision};
 
 /// `f32` extension providing various arithmetic approximations and polyfills
 /// for `std` functionality.
@@ -12,10 +12,16 @@ pub trait F32Ext: Sized {
     /// Approximates `acos(x)` in radians in the range `[0, pi]`
     fn acos(self) -> f32;
 
+    /// Approximates the inverse hyperbolic cosine with a maximum error of `7e-5`.
+    fn acosh(self) -> f32;
+
     /// Approximates `asin(x)` in radians in the range `[-pi/2, pi/2]`.
     fn asin(self) -> f32;
 
-    /// Approximates `atan(x)` in radians with a maximum error of `0.002`.
+    /// Approximates the inverse hyperbolic sine with a maximum error of `7e-5`.
+    fn asinh(self) -> f32;
+
+    /// Approximates `atan(x)` in radians with a maximum error of `0.0029`.
     fn atan(self) -> f32;
 
     /// Approximates `atan(x)` normalized to the `[−1,1]` range with a maximum
@@ -23,47 +29,121 @@ pub trait F32Ext: Sized {
     fn atan_norm(self) -> f32;
 
     /// Approximates the four quadrant arctangent `atan2(x)` in radians, with
-    /// a maximum error of `0.002`.
+    /// a maximum error of `0.0029`.
     fn atan2(self, other: f32) -> f32;
 
+    /// Approximates the four quadrant arctangent in degrees, in the range
+    /// `(-180, 180]`, with a maximum error of `0.1620` degrees.
+    fn atan2_deg(self, other: f32) -> f32;
+
     /// Approximates the four quadrant arctangent.
     /// Normalized to the `[0,4)` range with a maximum error of `0.1620` degrees.
     fn atan2_norm(self, other: f32) -> f32;
 
+    /// Approximates the inverse hyperbolic tangent with a maximum error of
+    /// `3.2e-5`.
+    fn atanh(self) -> f32;
+
+    /// Approximates cube root with an average deviation of ~3%.
+    fn cbrt(self) -> f32;
+
+    /// Approximates cube root using the given [`Precision`] tier.
+    fn cbrt_with<P: Precision>(self) -> f32;
+
     /// Approximates floating point ceiling.
     fn ceil(self) -> f32;
 
     /// Copies the sign from one number to another and returns it.
     fn copysign(self, sign: f32) -> f32;
 
-    /// Approximates cosine in radians with a maximum error of `0.002`.
+    /// Approximates cosine in radians with a maximum error of `0.0011`.
     fn cos(self) -> f32;
 
+    /// Approximates `cos(x)` in degrees with a maximum error of `0.002`.
+    fn cos_deg(self) -> f32;
+
+    /// Approximates `cos(x)` in turns (`1.0` is a full circle) with a maximum
+    /// error of `0.002`.
+    fn cos_turns(self) -> f32;
+
+    /// Approximates cosine in radians using the given [`Precision`] tier.
+    fn cos_with<P: Precision>(self) -> f32;
+
+    /// Approximates the hyperbolic cosine with a maximum relative error of
+    /// `7.6e-4`.
+    fn cosh(self) -> f32;
+
     /// Calculates Euclidean division, the matching method for `rem_euclid`.
     fn div_euclid(self, other: f32) -> f32;
 
+    /// Approximates the error function with a maximum error of `8e-7`.
+    fn erf(self) -> f32;
+
+    /// Approximates the complementary error function `1 - erf(x)` with a maximum
+    /// relative error of `2e-5`.
+    fn erfc(self) -> f32;
+
     /// Approximates `e^x`.
     fn exp(self) -> f32;
 
+    /// Approximates `2^x`.
+    fn exp2(self) -> f32;
+
+    /// Approximates `e^x - 1`, more accurately than `x.exp() - 1.0` for `x`
+    /// close to zero.
+    fn exp_m1(self) -> f32;
+
+    /// Approximates `e^x` using the given [`Precision`] tier.
+    fn exp_with<P: Precision>(self) -> f32;
+
     /// Approximates floating point floor.
     fn floor(self) -> f32;
 
     /// Retrieve the fractional part of floating point with sign.
     fn fract(self) -> f32;
 
+    /// Splits a number into a fraction `m` and an exponent `e` such that
+    /// `x = m * 2^e`, where `0.5 <= |m| < 1`. Returns `(m, e)`.
+    fn frexp(self) -> (f32, i32);
+
+    /// Approximates the gamma function `Γ(x)` with a maximum relative error of
+    /// `2e-5` for positive `x`.
+    fn gamma(self) -> f32;
+
     /// Approximates the length of the hypotenuse of a right-angle triangle given
     /// legs of length `x` and `y`.
     fn hypot(self, other: f32) -> f32;
 
+    /// Returns the unbiased exponent of a number, i.e. `floor(log2(|x|))`.
+    fn ilogb(self) -> i32;
+
     /// Approximates `1/x` with an average deviation of ~8%.
     fn inv(self) -> f32;
 
+    /// Approximates the inverse error function with a maximum relative error of
+    /// `2e-6`.
+    fn inverse_erf(self) -> f32;
+
     /// Approximates inverse square root with an average deviation of ~5%.
     fn invsqrt(self) -> f32;
 
+    /// Approximates inverse square root using the given [`Precision`] tier.
+    fn invsqrt_with<P: Precision>(self) -> f32;
+
+    /// Computes `x * 2^exp`, the inverse of `frexp`.
+    fn ldexp(self, exp: i32) -> f32;
+
     /// Approximates `ln(x)`.
     fn ln(self) -> f32;
 
+    /// Approximates `ln(1 + x)`, more accurately than `(1.0 + x).ln()` for `x`
+    /// close to zero.
+    fn ln_1p(self) -> f32;
+
+    /// Approximates the natural logarithm of the absolute value of the gamma
+    /// function, `ln(|Γ(x)|)`.
+    fn ln_gamma(self) -> f32;
+
     /// Approximates `log` with an arbitrary base.
     fn log(self, base: f32) -> f32;
 
@@ -73,9 +153,27 @@ pub trait F32Ext: Sized {
     /// Approximates `log10`.
     fn log10(self) -> f32;
 
+    /// Returns the maximum of two numbers, propagating NaN. `+0.0` is
+    /// considered greater than `-0.0`.
+    fn maximum(self, other: f32) -> f32;
+
+    /// Returns the minimum of two numbers, propagating NaN. `-0.0` is
+    /// considered less than `+0.0`.
+    fn minimum(self, other: f32) -> f32;
+
     /// Computes `(self * a) + b`.
     fn mul_add(self, a: f32, b: f32) -> f32;
 
+    /// Returns the greatest number less than `self`.
+    fn next_down(self) -> f32;
+
+    /// Returns the least number greater than `self`.
+    fn next_up(self) -> f32;
+
+    /// Approximates the `n`th root, i.e. `self^(1/n)`, with a maximum relative
+    /// error of `0.0001`.
+    fn nth_root(self, n: i32) -> f32;
+
     /// Approximates `self^n`.
     fn powf(self, n: f32) -> f32;
 
@@ -91,24 +189,52 @@ pub trait F32Ext: Sized {
     /// Round the number part of floating point with sign.
     fn round(self) -> f32;
 
+    /// Computes `x * 2^n` exactly, other than overflowing to infinity or
+    /// rounding subnormal results.
+    fn scalbn(self, n: i32) -> f32;
+
     /// Returns a number that represents the sign of `self`.
     fn signum(self) -> f32;
 
-    /// Approximates sine in radians with a maximum error of `0.002`.
+    /// Approximates sine in radians with a maximum error of `0.0011`.
     fn sin(self) -> f32;
 
     /// Simultaneously computes the sine and cosine of the number, `x`.
     /// Returns `(sin(x), cos(x))`.
     fn sin_cos(self) -> (f32, f32);
 
+    /// Approximates `sin(x)` in degrees with a maximum error of `0.002`.
+    fn sin_deg(self) -> f32;
+
+    /// Approximates `sin(x)` in turns (`1.0` is a full circle) with a maximum
+    /// error of `0.002`.
+    fn sin_turns(self) -> f32;
+
+    /// Approximates sine in radians using the given [`Precision`] tier.
+    fn sin_with<P: Precision>(self) -> f32;
+
+    /// Approximates the hyperbolic sine with a maximum relative error of `0.0018`.
+    fn sinh(self) -> f32;
+
     /// Approximates square root with an average deviation of ~5%.
     fn sqrt(self) -> f32;
 
-    /// Approximates `tan(x)` in radians with a maximum error of `0.6`.
+    /// Approximates square root using the given [`Precision`] tier.
+    fn sqrt_with<P: Precision>(self) -> f32;
+
+    /// Approximates `tan(x)` in radians with a maximum error of `0.038` in the
+    /// range `[-1.4, 1.4]`.
     fn tan(self) -> f32;
 
+    /// Approximates the hyperbolic tangent with a maximum error of `3.4e-4`.
+    fn tanh(self) -> f32;
+
     /// Retrieve whole number part of floating point with sign.
     fn trunc(self) -> f32;
+
+    /// Returns the unit in the last place of a number: the distance between
+    /// `|x|` and the next float of larger magnitude.
+    fn ulp(self) -> f32;
 }
 
 impl F32Ext for f32 {
@@ -122,11 +248,21 @@ impl F32Ext for f32 {
         F32(self).acos().0
     }
 
+    #[inline]
+    fn acosh(self) -> f32 {
+        F32(self).acosh().0
+    }
+
     #[inline]
     fn asin(self) -> f32 {
         F32(self).asin().0
     }
 
+    #[inline]
+    fn asinh(self) -> f32 {
+        F32(self).asinh().0
+    }
+
     #[inline]
     fn atan(self) -> f32 {
         F32(self).atan().0
@@ -142,11 +278,31 @@ impl F32Ext for f32 {
         F32(self).atan2(F32(other)).0
     }
 
+    #[inline]
+    fn atan2_deg(self, other: f32) -> f32 {
+        F32(self).atan2_deg(F32(other)).0
+    }
+
     #[inline]
     fn atan2_norm(self, other: f32) -> f32 {
         F32(self).atan2_norm(F32(other)).0
     }
 
+    #[inline]
+    fn atanh(self) -> f32 {
+        F32(self).atanh().0
+    }
+
+    #[inline]
+    fn cbrt(self) -> f32 {
+        F32(self).cbrt().0
+    }
+
+    #[inline]
+    fn cbrt_with<P: Precision>(self) -> f32 {
+        F32(self).cbrt_with::<P>().0
+    }
+
     #[inline]
     fn ceil(self) -> f32 {
         F32(self).ceil().0
@@ -162,16 +318,61 @@ impl F32Ext for f32 {
         F32(self).cos().0
     }
 
+    #[inline]
+    fn cos_deg(self) -> f32 {
+        F32(self).cos_deg().0
+    }
+
+    #[inline]
+    fn cos_turns(self) -> f32 {
+        F32(self).cos_turns().0
+    }
+
+    #[inline]
+    fn cos_with<P: Precision>(self) -> f32 {
+        F32(self).cos_with::<P>().0
+    }
+
+    #[inline]
+    fn cosh(self) -> f32 {
+        F32(self).cosh().0
+    }
+
     #[inline]
     fn div_euclid(self, other: f32) -> f32 {
         F32(self).div_euclid(F32(other)).0
     }
 
+    #[inline]
+    fn erf(self) -> f32 {
+        F32(self).erf().0
+    }
+
+    #[inline]
+    fn erfc(self) -> f32 {
+        F32(self).erfc().0
+    }
+
     #[inline]
     fn exp(self) -> f32 {
         F32(self).exp().0
     }
 
+    #[inline]
+    fn exp2(self) -> f32 {
+        F32(self).exp2().0
+    }
+
+    #[inline]
+    fn exp_m1(self) -> f32 {
+        F32(self).exp_m1().0
+    }
+
+    #[inline]
+    fn exp_with<P: Precision>(self) -> f32 {
+        F32(self).exp_with::<P>().0
+    }
+
     #[inline]
     fn floor(self) -> f32 {
         F32(self).floor().0
@@ -182,26 +383,67 @@ impl F32Ext for f32 {
         F32(self).fract().0
     }
 
+    #[inline]
+    fn frexp(self) -> (f32, i32) {
+        let (fraction, exponent) = F32(self).frexp();
+        (fraction.0, exponent)
+    }
+
+    #[inline]
+    fn gamma(self) -> f32 {
+        F32(self).gamma().0
+    }
+
     #[inline]
     fn hypot(self, other: f32) -> f32 {
         F32(self).hypot(other.into()).0
     }
 
+    #[inline]
+    fn ilogb(self) -> i32 {
+        F32(self).ilogb()
+    }
+
     #[inline]
     fn inv(self) -> f32 {
         F32(self).inv().0
     }
 
+    #[inline]
+    fn inverse_erf(self) -> f32 {
+        F32(self).inverse_erf().0
+    }
+
     #[inline]
     fn invsqrt(self) -> f32 {
         F32(self).invsqrt().0
     }
 
+    #[inline]
+    fn invsqrt_with<P: Precision>(self) -> f32 {
+        F32(self).invsqrt_with::<P>().0
+    }
+
+    #[inline]
+    fn ldexp(self, exp: i32) -> f32 {
+        F32(self).ldexp(exp).0
+    }
+
     #[inline]
     fn ln(self) -> f32 {
         F32(self).ln().0
     }
 
+    #[inline]
+    fn ln_1p(self) -> f32 {
+        F32(self).ln_1p().0
+    }
+
+    #[inline]
+    fn ln_gamma(self) -> f32 {
+        F32(self).ln_gamma().0
+    }
+
     #[inline]
     fn log(self, base: f32) -> f32 {
         F32(self).log(F32(base)).0
@@ -217,11 +459,36 @@ impl F32Ext for f32 {
         F32(self).log10().0
     }
 
+    #[inline]
+    fn maximum(self, other: f32) -> f32 {
+        F32(self).maximum(F32(other)).0
+    }
+
+    #[inline]
+    fn minimum(self, other: f32) -> f32 {
+        F32(self).minimum(F32(other)).0
+    }
+
     #[inline]
     fn mul_add(self, a: f32, b: f32) -> f32 {
         F32(self).mul_add(F32(a), F32(b)).0
     }
 
+    #[inline]
+    fn next_down(self) -> f32 {
+        F32(self).next_down().0
+    }
+
+    #[inline]
+    fn next_up(self) -> f32 {
+        F32(self).next_up().0
+    }
+
+    #[inline]
+    fn nth_root(self, n: i32) -> f32 {
+        F32(self).nth_root(n).0
+    }
+
     #[inline]
     fn powf(self, n: f32) -> f32 {
         F32(self).powf(F32(n)).0
@@ -247,6 +514,11 @@ impl F32Ext for f32 {
         F32(self).round().0
     }
 
+    #[inline]
+    fn scalbn(self, n: i32) -> f32 {
+        F32(self).scalbn(n).0
+    }
+
     #[inline]
     fn signum(self) -> f32 {
         F32(self).signum().0
@@ -259,7 +531,28 @@ impl F32Ext for f32 {
 
     #[inline]
     fn sin_cos(self) -> (f32, f32) {
-        (F32(self).sin().0, F32(self).cos().0)
+        let (sin, cos) = F32(self).sin_cos();
+        (sin.0, cos.0)
+    }
+
+    #[inline]
+    fn sin_deg(self) -> f32 {
+        F32(self).sin_deg().0
+    }
+
+    #[inline]
+    fn sin_turns(self) -> f32 {
+        F32(self).sin_turns().0
+    }
+
+    #[inline]
+    fn sin_with<P: Precision>(self) -> f32 {
+        F32(self).sin_with::<P>().0
+    }
+
+    #[inline]
+    fn sinh(self) -> f32 {
+        F32(self).sinh().0
     }
 
     #[inline]
@@ -267,13 +560,28 @@ impl F32Ext for f32 {
         F32(self).sqrt().0
     }
 
+    #[inline]
+    fn sqrt_with<P: Precision>(self) -> f32 {
+        F32(self).sqrt_with::<P>().0
+    }
+
     #[inline]
     fn tan(self) -> f32 {
         F32(self).tan().0
     }
 
+    #[inline]
+    fn tanh(self) -> f32 {
+        F32(self).tanh().0
+    }
+
     #[inline]
     fn trunc(self) -> f32 {
         F32(self).trunc().0
     }
+
+    #[inline]
+    fn ulp(self) -> f32 {
+        F32(self).ulp().0
+    }
 }
@@ -0,0 +1,382 @@
+//! `f64` extension
+
+use crate::float64::F64;
+
+/// `f64` extension providing various arithmetic approximations and polyfills
+/// for `std` functionality.
+pub trait F64Ext: Sized {
+    /// Compute absolute value with a constant-time, data-independent
+    /// implementation.
+    fn abs(self) -> f64;
+
+    /// Approximates `acos(x)` in radians in the range `[0, pi]`, with a
+    /// maximum error of `1e-15`.
+    fn acos(self) -> f64;
+
+    /// Approximates the inverse hyperbolic cosine with a maximum relative
+    /// error of `5e-16`.
+    fn acosh(self) -> f64;
+
+    /// Approximates `asin(x)` in radians in the range `[-pi/2, pi/2]`, with a
+    /// maximum relative error of `7e-16`.
+    fn asin(self) -> f64;
+
+    /// Approximates the inverse hyperbolic sine with a maximum relative
+    /// error of `5e-16`.
+    fn asinh(self) -> f64;
+
+    /// Approximates `atan(x)` in radians with a maximum relative error of
+    /// `5e-16`.
+    fn atan(self) -> f64;
+
+    /// Approximates `atan(x)` normalized to the `[−1,1]` range with a maximum
+    /// relative error of `6e-16`.
+    fn atan_norm(self) -> f64;
+
+    /// Approximates the four quadrant arctangent `atan2(x)` in radians, with
+    /// a maximum error of `7e-16`.
+    fn atan2(self, other: f64) -> f64;
+
+    /// Approximates the four quadrant arctangent.
+    /// Normalized to the `[0,4)` range with a maximum error of `6e-16`.
+    fn atan2_norm(self, other: f64) -> f64;
+
+    /// Approximates the inverse hyperbolic tangent with a maximum relative
+    /// error of `4e-16`.
+    fn atanh(self) -> f64;
+
+    /// Approximates cube root with a maximum relative error of `3e-16`.
+    fn cbrt(self) -> f64;
+
+    /// Approximates floating point ceiling.
+    fn ceil(self) -> f64;
+
+    /// Copies the sign from one number to another and returns it.
+    fn copysign(self, sign: f64) -> f64;
+
+    /// Approximates cosine in radians with a maximum error of `4e-16`.
+    fn cos(self) -> f64;
+
+    /// Approximates the hyperbolic cosine with a maximum relative error of
+    /// `5e-16`.
+    fn cosh(self) -> f64;
+
+    /// Calculates Euclidean division, the matching method for `rem_euclid`.
+    fn div_euclid(self, other: f64) -> f64;
+
+    /// Approximates `e^x` with a maximum relative error of `3e-16`.
+    fn exp(self) -> f64;
+
+    /// Approximates `2^x` with a maximum relative error of `3e-16`.
+    fn exp2(self) -> f64;
+
+    /// Approximates `e^x - 1`, more accurately than `x.exp() - 1.0` for `x`
+    /// close to zero.
+    fn exp_m1(self) -> f64;
+
+    /// Approximates floating point floor.
+    fn floor(self) -> f64;
+
+    /// Retrieve the fractional part of floating point with sign.
+    fn fract(self) -> f64;
+
+    /// Approximates the length of the hypotenuse of a right-angle triangle given
+    /// legs of length `x` and `y`.
+    fn hypot(self, other: f64) -> f64;
+
+    /// Approximates `1/x` with an average deviation of ~8%.
+    fn inv(self) -> f64;
+
+    /// Approximates inverse square root with a maximum relative error of
+    /// `5e-16`.
+    fn invsqrt(self) -> f64;
+
+    /// Approximates `ln(x)` with a maximum relative error of `3e-16`.
+    fn ln(self) -> f64;
+
+    /// Approximates `ln(1 + x)`, more accurately than `(1.0 + x).ln()` for `x`
+    /// close to zero.
+    fn ln_1p(self) -> f64;
+
+    /// Approximates `log` with an arbitrary base, with a maximum relative error
+    /// of `4e-16`.
+    fn log(self, base: f64) -> f64;
+
+    /// Approximates `log2` with a maximum relative error of `4e-16`.
+    fn log2(self) -> f64;
+
+    /// Approximates `log10` with a maximum relative error of `4e-16`.
+    fn log10(self) -> f64;
+
+    /// Computes `(self * a) + b`.
+    fn mul_add(self, a: f64, b: f64) -> f64;
+
+    /// Approximates the `n`th root, i.e. `self^(1/n)`, with a maximum relative
+    /// error of `4e-16`.
+    fn nth_root(self, n: i32) -> f64;
+
+    /// Approximates `self^n`, with a relative error of about
+    /// `|n * ln(self)| * 2^-52`.
+    fn powf(self, n: f64) -> f64;
+
+    /// Approximates `self^n` where n is an `i32`
+    fn powi(self, n: i32) -> f64;
+
+    /// Returns the reciprocal (inverse) of a number, `1/x`.
+    fn recip(self) -> f64;
+
+    /// Calculates the least nonnegative remainder of `self (mod other)`.
+    fn rem_euclid(self, other: f64) -> f64;
+
+    /// Round the number part of floating point with sign.
+    fn round(self) -> f64;
+
+    /// Returns a number that represents the sign of `self`.
+    fn signum(self) -> f64;
+
+    /// Approximates sine in radians with a maximum error of `4e-16`.
+    fn sin(self) -> f64;
+
+    /// Simultaneously computes the sine and cosine of the number, `x`.
+    /// Returns `(sin(x), cos(x))`.
+    fn sin_cos(self) -> (f64, f64);
+
+    /// Approximates the hyperbolic sine with a maximum relative error of `5e-16`.
+    fn sinh(self) -> f64;
+
+    /// Approximates square root with a maximum relative error of `3e-16`.
+    fn sqrt(self) -> f64;
+
+    /// Approximates `tan(x)` in radians with a maximum relative error of
+    /// `5e-16`.
+    fn tan(self) -> f64;
+
+    /// Approximates the hyperbolic tangent with a maximum relative error of
+    /// `6e-16`.
+    fn tanh(self) -> f64;
+
+    /// Retrieve whole number part of floating point with sign.
+    fn trunc(self) -> f64;
+}
+
+impl F64Ext for f64 {
+    #[inline]
+    fn abs(self) -> f64 {
+        F64(self).abs().0
+    }
+
+    #[inline]
+    fn acos(self) -> f64 {
+        F64(self).acos().0
+    }
+
+    #[inline]
+    fn acosh(self) -> f64 {
+        F64(self).acosh().0
+    }
+
+    #[inline]
+    fn asin(self) -> f64 {
+        F64(self).asin().0
+    }
+
+    #[inline]
+    fn asinh(self) -> f64 {
+        F64(self).asinh().0
+    }
+
+    #[inline]
+    fn atan(self) -> f64 {
+        F64(self).atan().0
+    }
+
+    #[inline]
+    fn atan_norm(self) -> f64 {
+        F64(self).atan_norm().0
+    }
+
+    #[inline]
+    fn atan2(self, other: f64) -> f64 {
+        F64(self).atan2(F64(other)).0
+    }
+
+    #[inline]
+    fn atan2_norm(self, other: f64) -> f64 {
+        F64(self).atan2_norm(F64(other)).0
+    }
+
+    #[inline]
+    fn atanh(self) -> f64 {
+        F64(self).atanh().0
+    }
+
+    #[inline]
+    fn cbrt(self) -> f64 {
+        F64(self).cbrt().0
+    }
+
+    #[inline]
+    fn ceil(self) -> f64 {
+        F64(self).ceil().0
+    }
+
+    #[inline]
+    fn copysign(self, sign: f64) -> f64 {
+        F64(self).copysign(F64(sign)).0
+    }
+
+    #[inline]
+    fn cos(self) -> f64 {
+        F64(self).cos().0
+    }
+
+    #[inline]
+    fn cosh(self) -> f64 {
+        F64(self).cosh().0
+    }
+
+    #[inline]
+    fn div_euclid(self, other: f64) -> f64 {
+        F64(self).div_euclid(F64(other)).0
+    }
+
+    #[inline]
+    fn exp(self) -> f64 {
+        F64(self).exp().0
+    }
+
+    #[inline]
+    fn exp2(self) -> f64 {
+        F64(self).exp2().0
+    }
+
+    #[inline]
+    fn exp_m1(self) -> f64 {
+        F64(self).exp_m1().0
+    }
+
+    #[inline]
+    fn floor(self) -> f64 {
+        F64(self).floor().0
+    }
+
+    #[inline]
+    fn fract(self) -> f64 {
+        F64(self).fract().0
+    }
+
+    #[inline]
+    fn hypot(self, other: f64) -> f64 {
+        F64(self).hypot(other.into()).0
+    }
+
+    #[inline]
+    fn inv(self) -> f64 {
+        F64(self).inv().0
+    }
+
+    #[inline]
+    fn invsqrt(self) -> f64 {
+        F64(self).invsqrt().0
+    }
+
+    #[inline]
+    fn ln(self) -> f64 {
+        F64(self).ln().0
+    }
+
+    #[inline]
+    fn ln_1p(self) -> f64 {
+        F64(self).ln_1p().0
+    }
+
+    #[inline]
+    fn log(self, base: f64) -> f64 {
+        F64(self).log(F64(base)).0
+    }
+
+    #[inline]
+    fn log2(self) -> f64 {
+        F64(self).log2().0
+    }
+
+    #[inline]
+    fn log10(self) -> f64 {
+        F64(self).log10().0
+    }
+
+    #[inline]
+    fn mul_add(self, a: f64, b: f64) -> f64 {
+        F64(self).mul_add(F64(a), F64(b)).0
+    }
+
+    #[inline]
+    fn nth_root(self, n: i32) -> f64 {
+        F64(self).nth_root(n).0
+    }
+
+    #[inline]
+    fn powf(self, n: f64) -> f64 {
+        F64(self).powf(F64(n)).0
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> f64 {
+        F64(self).powi(n).0
+    }
+
+    #[inline]
+    fn recip(self) -> f64 {
+        F64(self).recip().0
+    }
+
+    #[inline]
+    fn rem_euclid(self, other: f64) -> f64 {
+        F64(self).rem_euclid(F64(other)).0
+    }
+
+    #[inline]
+    fn round(self) -> f64 {
+        F64(self).round().0
+    }
+
+    #[inline]
+    fn signum(self) -> f64 {
+        F64(self).signum().0
+    }
+
+    #[inline]
+    fn sin(self) -> f64 {
+        F64(self).sin().0
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (f64, f64) {
+        (F64(self).sin().0, F64(self).cos().0)
+    }
+
+    #[inline]
+    fn sinh(self) -> f64 {
+        F64(self).sinh().0
+    }
+
+    #[inline]
+    fn sqrt(self) -> f64 {
+        F64(self).sqrt().0
+    }
+
+    #[inline]
+    fn tan(self) -> f64 {
+        F64(self).tan().0
+    }
+
+    #[inline]
+    fn tanh(self) -> f64 {
+        F64(self).tanh().0
+    }
+
+    #[inline]
+    fn trunc(self) -> f64 {
+        F64(self).trunc().0
+    }
+}
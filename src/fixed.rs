@@ -0,0 +1,342 @@
+//! Fixed-point number types.
+//!
+//! These types provide the same math surface as [`F32`][`crate::F32`] on
+//! targets without an FPU, using only integer arithmetic:
+//!
+//! - [`Q15`]: 16-bit signed fraction in the range `[-1, 1)`
+//! - [`Q31`]: 32-bit signed fraction in the range `[-1, 1)`
+//! - [`I16F16`]: 32-bit signed number with 16 integer and 16 fractional bits
+//!
+//! All arithmetic saturates at the bounds of the type instead of wrapping.
+//!
+//! Since [`Q15`] and [`Q31`] can't represent angles in radians, their
+//! trigonometric functions use half-turns instead: `-1.0` is `-π` and the
+//! range `[-1, 1)` covers a full circle, with `atan2` saturating at `MAX` for
+//! an angle of `π`. [`I16F16`] uses radians.
+
+pub(crate) mod atan2;
+pub(crate) mod exp;
+pub(crate) mod ln;
+pub(crate) mod sin_cos;
+pub(crate) mod sqrt;
+
+/// Number of fractional bits of the intermediate "wide" representation used
+/// by the math kernels: `i64` values where `1 << 30` is `1.0`.
+pub(crate) const WIDE_FRAC_BITS: u32 = 30;
+
+/// `1.0` in the wide representation.
+pub(crate) const WIDE_ONE: i64 = 1 << WIDE_FRAC_BITS;
+
+/// Multiply two wide values, rounding to nearest.
+#[inline]
+pub(crate) fn wide_mul(a: i64, b: i64) -> i64 {
+    (a * b + (WIDE_ONE >> 1)) >> WIDE_FRAC_BITS
+}
+
+/// Evaluate the polynomial with the given wide coefficients (lowest order
+/// first) at `x` using Horner's method.
+pub(crate) fn wide_horner(coefficients: &[i64], x: i64) -> i64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0, |acc, &coeff| coeff + wide_mul(acc, x))
+}
+
+/// Shift `n` right by `shift` bits, rounding to nearest.
+///
+/// Saturates instead of overflowing when rounding `n` up, so that saturated
+/// kernel results such as `i64::MAX` stay saturated.
+#[inline]
+pub(crate) fn round_shr(n: i64, shift: u32) -> i64 {
+    if shift == 0 {
+        n
+    } else {
+        n.saturating_add(1 << (shift - 1)) >> shift
+    }
+}
+
+/// Internal interface the math kernels use to operate on fixed-point types.
+pub(crate) trait Fixed: Copy {
+    /// Number of fractional bits.
+    const FRAC_BITS: u32;
+
+    /// Get the raw value.
+    fn to_raw(self) -> i64;
+
+    /// Create a value from a raw value, saturating at the bounds of the type.
+    fn from_raw(raw: i64) -> Self;
+
+    /// Convert this angle to a binary angle where `1 << 32` is a full turn.
+    fn to_angle(self) -> u32;
+
+    /// Create an angle from a binary angle where `1 << 31` is a half-turn,
+    /// saturating at the bounds of the type.
+    fn from_angle(angle: i64) -> Self;
+
+    /// Convert to the wide representation.
+    #[inline]
+    fn to_wide(self) -> i64 {
+        if Self::FRAC_BITS <= WIDE_FRAC_BITS {
+            self.to_raw() << (WIDE_FRAC_BITS - Self::FRAC_BITS)
+        } else {
+            round_shr(self.to_raw(), Self::FRAC_BITS - WIDE_FRAC_BITS)
+        }
+    }
+
+    /// Create a value from the wide representation, saturating at the bounds
+    /// of the type.
+    #[inline]
+    fn from_wide(wide: i64) -> Self {
+        if Self::FRAC_BITS <= WIDE_FRAC_BITS {
+            Self::from_raw(round_shr(wide, WIDE_FRAC_BITS - Self::FRAC_BITS))
+        } else {
+            Self::from_raw(wide.saturating_mul(1 << (Self::FRAC_BITS - WIDE_FRAC_BITS)))
+        }
+    }
+}
+
+/// Implement saturating arithmetic, conversions, formatting and math
+/// functions for a fixed-point type.
+macro_rules! impl_fixed {
+    ($fixed:ident, $raw:ty, $angle:literal) => {
+        impl $fixed {
+            /// The value `0`.
+            pub const ZERO: Self = Self(0);
+
+            /// Smallest representable value.
+            pub const MIN: Self = Self(<$raw>::MIN);
+
+            /// Largest representable value.
+            pub const MAX: Self = Self(<$raw>::MAX);
+
+            /// Smallest positive value, i.e. the difference between two
+            /// adjacent values.
+            pub const DELTA: Self = Self(1);
+
+            /// Number of fractional bits.
+            pub const FRAC_BITS: u32 = <Self as Fixed>::FRAC_BITS;
+
+            /// Raw transmutation from the underlying integer.
+            #[inline]
+            pub const fn from_bits(bits: $raw) -> Self {
+                Self(bits)
+            }
+
+            /// Raw transmutation to the underlying integer.
+            #[inline]
+            pub const fn to_bits(self) -> $raw {
+                self.0
+            }
+
+            /// Convert an `f32` to the nearest representable value,
+            /// saturating at the bounds of the type. `NaN` becomes zero.
+            pub fn from_f32(n: f32) -> Self {
+                let scaled = f64::from(n) * (1_u64 << Self::FRAC_BITS) as f64;
+                let rounded = if scaled < 0.0 {
+                    scaled - 0.5
+                } else {
+                    scaled + 0.5
+                };
+
+                Self::from_raw(rounded as i64)
+            }
+
+            /// Convert this value to the nearest `f32`.
+            pub fn to_f32(self) -> f32 {
+                (f64::from(self.0) / (1_u64 << Self::FRAC_BITS) as f64) as f32
+            }
+
+            /// Compute the absolute value, saturating at [`Self::MAX`].
+            #[inline]
+            pub fn abs(self) -> Self {
+                Self(self.0.saturating_abs())
+            }
+
+            /// Computes the square root, rounded down.
+            ///
+            /// Returns [`Self::ZERO`] if `self` is negative.
+            pub fn sqrt(self) -> Self {
+                sqrt::sqrt(self)
+            }
+
+            #[doc = concat!("Approximates `sin(x)`, where `x` is in ", $angle, ".")]
+            pub fn sin(self) -> Self {
+                Self::from_wide(sin_cos::sin_angle(self.to_angle()))
+            }
+
+            #[doc = concat!("Approximates `cos(x)`, where `x` is in ", $angle, ".")]
+            pub fn cos(self) -> Self {
+                Self::from_wide(sin_cos::cos_angle(self.to_angle()))
+            }
+
+            /// Approximates the four quadrant arctangent of `self` (y) and
+            #[doc = concat!("`rhs` (x), returning an angle in ", $angle, ".")]
+            pub fn atan2(self, rhs: Self) -> Self {
+                Self::from_angle(atan2::atan2_angle(self.to_raw(), rhs.to_raw()))
+            }
+
+            /// Approximates `e^(self)`, saturating at [`Self::MAX`].
+            pub fn exp(self) -> Self {
+                Self::from_wide(exp::exp_wide(self.to_wide()))
+            }
+
+            /// Approximates the natural logarithm, saturating at
+            /// [`Self::MIN`].
+            ///
+            /// Returns [`Self::MIN`] if `self` is zero or negative.
+            pub fn ln(self) -> Self {
+                if self.0 <= 0 {
+                    Self::MIN
+                } else {
+                    Self::from_wide(ln::ln_raw(self.to_raw(), Self::FRAC_BITS))
+                }
+            }
+        }
+
+        impl Add for $fixed {
+            type Output = $fixed;
+
+            #[inline]
+            fn add(self, rhs: $fixed) -> $fixed {
+                $fixed(self.0.saturating_add(rhs.0))
+            }
+        }
+
+        impl AddAssign for $fixed {
+            #[inline]
+            fn add_assign(&mut self, rhs: $fixed) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl Debug for $fixed {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(fmt, "{}({:?})", stringify!($fixed), self.to_f32())
+            }
+        }
+
+        impl Display for $fixed {
+            #[inline]
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(fmt, "{}", self.to_f32())
+            }
+        }
+
+        impl Div for $fixed {
+            type Output = $fixed;
+
+            /// Saturating division: dividing by zero saturates to
+            /// [`Self::MAX`] or [`Self::MIN`] depending on the sign of
+            /// `self`, and `0 / 0` is zero.
+            #[inline]
+            fn div(self, rhs: $fixed) -> $fixed {
+                let lhs = self.to_raw();
+
+                match rhs.to_raw() {
+                    0 => $fixed::from_raw(lhs.signum() * i64::MAX),
+                    rhs => $fixed::from_raw((lhs << Self::FRAC_BITS) / rhs),
+                }
+            }
+        }
+
+        impl DivAssign for $fixed {
+            #[inline]
+            fn div_assign(&mut self, rhs: $fixed) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl From<f32> for $fixed {
+            #[inline]
+            fn from(n: f32) -> $fixed {
+                $fixed::from_f32(n)
+            }
+        }
+
+        impl From<F32> for $fixed {
+            #[inline]
+            fn from(n: F32) -> $fixed {
+                $fixed::from_f32(n.0)
+            }
+        }
+
+        impl From<$fixed> for f32 {
+            #[inline]
+            fn from(n: $fixed) -> f32 {
+                n.to_f32()
+            }
+        }
+
+        impl From<$fixed> for F32 {
+            #[inline]
+            fn from(n: $fixed) -> F32 {
+                F32(n.to_f32())
+            }
+        }
+
+        impl Mul for $fixed {
+            type Output = $fixed;
+
+            #[inline]
+            fn mul(self, rhs: $fixed) -> $fixed {
+                $fixed::from_raw(round_shr(self.to_raw() * rhs.to_raw(), Self::FRAC_BITS))
+            }
+        }
+
+        impl MulAssign for $fixed {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $fixed) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl Neg for $fixed {
+            type Output = $fixed;
+
+            #[inline]
+            fn neg(self) -> $fixed {
+                $fixed(self.0.saturating_neg())
+            }
+        }
+
+        impl Sub for $fixed {
+            type Output = $fixed;
+
+            #[inline]
+            fn sub(self, rhs: $fixed) -> $fixed {
+                $fixed(self.0.saturating_sub(rhs.0))
+            }
+        }
+
+        impl SubAssign for $fixed {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $fixed) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl Sum for $fixed {
+            #[inline]
+            fn sum<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = $fixed>,
+            {
+                iter.fold($fixed::ZERO, Add::add)
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+        impl defmt::Format for $fixed {
+            fn format(&self, fmt: defmt::Formatter<'_>) {
+                defmt::write!(fmt, "{}", self.to_f32())
+            }
+        }
+    };
+}
+
+mod i16f16;
+mod q15;
+mod q31;
+
+pub use self::{i16f16::I16F16, q15::Q15, q31::Q31};
@@ -0,0 +1,127 @@
+//! Fixed-point four quadrant arctangent.
+
+use super::{wide_horner, wide_mul, WIDE_FRAC_BITS, WIDE_ONE};
+
+/// Coefficients of the odd polynomial approximating `atan(t) / (π/4)` for `t`
+/// in `[0, tan(π/8)]`, in terms of `t²` (least squares fit, maximum error
+/// `5e-9`).
+const ATAN_POLYNOMIAL: [i64; 5] = [
+    1_367_130_445,
+    -455_696_148,
+    272_932_031,
+    -188_231_352,
+    106_193_277,
+];
+
+/// `tan(π/8)` as a wide value.
+const TAN_FRAC_PI_8: i64 = 444_758_426;
+
+/// Approximate the four quadrant arctangent of `y` and `x`, which must have
+/// the same scale, returning a binary angle where `1 << 31` is a half-turn.
+pub(crate) fn atan2_angle(y: i64, x: i64) -> i64 {
+    if x == 0 && y == 0 {
+        return 0;
+    }
+
+    // Reduce to the first octant, where `t = min / max` is in `[0, 1]`
+    let (ax, ay) = (x.unsigned_abs(), y.unsigned_abs());
+    let swapped = ay > ax;
+    let (num, den) = if swapped { (ax, ay) } else { (ay, ax) };
+    let t = ((num << WIDE_FRAC_BITS) / den) as i64;
+
+    // `atan(t) = π/4 + atan((t - 1) / (t + 1))`, in units of `π/4`
+    let eighths = if t > TAN_FRAC_PI_8 {
+        let u = ((t - WIDE_ONE) << WIDE_FRAC_BITS) / (t + WIDE_ONE);
+        WIDE_ONE + atan_polynomial(u)
+    } else {
+        atan_polynomial(t)
+    };
+
+    // An eighth of a turn is `1 << 29`
+    let mut angle = (eighths + 1) >> 1;
+
+    if swapped {
+        angle = (1 << 30) - angle;
+    }
+
+    if x < 0 {
+        angle = (1 << 31) - angle;
+    }
+
+    if y < 0 {
+        -angle
+    } else {
+        angle
+    }
+}
+
+/// Evaluate [`ATAN_POLYNOMIAL`] at `t`.
+fn atan_polynomial(t: i64) -> i64 {
+    wide_mul(wide_horner(&ATAN_POLYNOMIAL, wide_mul(t, t)), t)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixed::sin_cos::tests::q31_to_f64;
+    use crate::{I16F16, Q15, Q31};
+    use core::f64::consts::PI;
+
+    #[test]
+    fn half_turns() {
+        for i in -100..=100 {
+            for j in -100..=100 {
+                let (y, x) = (i as f32 / 100.0, j as f32 / 100.0);
+                let (y15, x15) = (Q15::from_f32(y), Q15::from_f32(x));
+                let (y31, x31) = (Q31::from_f32(y), Q31::from_f32(x));
+
+                // `π` isn't representable and saturates
+                if i == 0 && j < 0 {
+                    assert_eq!(y15.atan2(x15), Q15::MAX);
+                    assert_eq!(y31.atan2(x31), Q31::MAX);
+                    continue;
+                }
+
+                let expected = (y15.to_f32() as f64).atan2(x15.to_f32() as f64) / PI;
+                let actual = y15.atan2(x15).to_f32() as f64;
+                assert!(
+                    (actual - expected).abs() <= 5e-5,
+                    "{} vs {}",
+                    actual,
+                    expected
+                );
+
+                let expected = q31_to_f64(y31).atan2(q31_to_f64(x31)) / PI;
+                let actual = q31_to_f64(y31.atan2(x31));
+                assert!(
+                    (actual - expected).abs() <= 1e-8,
+                    "{} vs {}",
+                    actual,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn radians() {
+        for i in -100..=100 {
+            for j in -100..=100 {
+                let (y, x) = (
+                    I16F16::from_f32(i as f32 * 3.7),
+                    I16F16::from_f32(j as f32 * 0.13),
+                );
+                let expected = (y.to_f32() as f64).atan2(x.to_f32() as f64);
+                let actual = y.atan2(x).to_f32() as f64;
+
+                assert!(
+                    (actual - expected).abs() <= 5e-5,
+                    "delta too large for atan2({}, {}): {} vs {}",
+                    y,
+                    x,
+                    actual,
+                    expected
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Fixed-point exponential function.
+
+use super::{round_shr, wide_horner, wide_mul, WIDE_FRAC_BITS, WIDE_ONE};
+
+/// `log2(e)` as a wide value.
+const LOG2_E: i64 = 1_549_082_005;
+
+/// Coefficients of the polynomial approximating `2^f - 1` for `f` in
+/// `[0, 1)`, divided by `f` (least squares fit, maximum error `3e-9`).
+const EXP2_POLYNOMIAL: [i64; 6] = [
+    744_260_926,
+    257_944_910,
+    59_573_808,
+    10_395_145,
+    1_333_010,
+    234_022,
+];
+
+/// Approximate `e^x` of a wide value, returning a wide value.
+///
+/// Results which don't fit in any of the fixed-point types saturate.
+pub(crate) fn exp_wide(x: i64) -> i64 {
+    // `e^-32` is below the resolution, and `e^16` above the range of every type
+    if x < -32 * WIDE_ONE {
+        return 0;
+    }
+
+    if x > 16 * WIDE_ONE {
+        return i64::MAX;
+    }
+
+    // `e^x = 2^(x * log2(e)) = 2^n * 2^f`, splitting `x` into its integer
+    // and fractional parts to avoid overflowing the multiplication
+    let t = (x >> WIDE_FRAC_BITS) * LOG2_E + wide_mul(x & (WIDE_ONE - 1), LOG2_E);
+    let n = t >> WIDE_FRAC_BITS;
+    let f = t & (WIDE_ONE - 1);
+    let exp2_f = WIDE_ONE + wide_mul(wide_horner(&EXP2_POLYNOMIAL, f), f);
+
+    if n >= 0 {
+        exp2_f << n
+    } else {
+        round_shr(exp2_f, (-n) as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixed::sin_cos::tests::q31_to_f64;
+    use crate::{I16F16, Q15, Q31};
+
+    #[test]
+    fn fractions() {
+        for i in -1000..1000 {
+            let q15 = Q15::from_f32(i as f32 / 1000.0);
+            let q31 = Q31::from_bits(i * 2_147_483);
+
+            // Saturates at `MAX` for positive inputs
+            let expected = (q15.to_f32() as f64).exp().min(Q15::MAX.to_f32() as f64);
+            let actual = q15.exp().to_f32() as f64;
+            assert!(
+                (actual - expected).abs() <= 5e-5,
+                "{} vs {}",
+                actual,
+                expected
+            );
+
+            let expected = q31_to_f64(q31).exp().min(q31_to_f64(Q31::MAX));
+            let actual = q31_to_f64(q31.exp());
+            assert!(
+                (actual - expected).abs() <= 1e-8,
+                "{} vs {}",
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn i16f16() {
+        for i in -2000..=1100 {
+            let x = I16F16::from_f32(i as f32 / 100.0);
+            let expected = (x.to_f32() as f64).exp().min(I16F16::MAX.to_f32() as f64);
+            let actual = x.exp().to_f32() as f64;
+            let relative_error = ((actual - expected) / expected).abs();
+
+            // Results are limited by the absolute resolution of `2^-16`
+            assert!(
+                (actual - expected).abs() <= 2e-5 || relative_error <= 1e-6,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                actual,
+                expected
+            );
+        }
+
+        assert_eq!(I16F16::from(11i16).exp(), I16F16::MAX);
+        assert_eq!(I16F16::from(-100i16).exp(), I16F16::ZERO);
+    }
+
+    #[test]
+    fn saturates_past_overflow() {
+        assert_eq!(I16F16::from_f32(16.5).exp(), I16F16::MAX);
+        assert_eq!(I16F16::from(17i16).exp(), I16F16::MAX);
+        assert_eq!(I16F16::MAX.exp(), I16F16::MAX);
+        assert_eq!(Q15::MAX.exp(), Q15::MAX);
+        assert_eq!(Q31::MAX.exp(), Q31::MAX);
+
+        for i in 11..=i16::MAX {
+            assert_eq!(I16F16::from(i).exp(), I16F16::MAX, "exp({})", i);
+        }
+    }
+}
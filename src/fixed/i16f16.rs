@@ -0,0 +1,89 @@
+//! I16F16 fixed-point number type.
+
+use super::{atan2, exp, ln, round_shr, sin_cos, sqrt, Fixed};
+use crate::F32;
+use core::{
+    fmt::{self, Debug, Display},
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// `2^32 / 2π`: radians in 16.16 format to binary angle.
+const RADIANS_TO_ANGLE: i64 = 683_565_276;
+
+/// `π` with 29 fractional bits: binary angle to radians in 16.16 format.
+const ANGLE_TO_RADIANS: i64 = 1_686_629_713;
+
+/// 32-bit signed fixed-point number with 16 integer and 16 fractional bits,
+/// in the range `[-32768, 32768)`.
+#[derive(Copy, Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct I16F16(i32);
+
+impl I16F16 {
+    /// The value `1`.
+    pub const ONE: Self = Self(1 << 16);
+}
+
+impl Fixed for I16F16 {
+    const FRAC_BITS: u32 = 16;
+
+    #[inline]
+    fn to_raw(self) -> i64 {
+        self.0.into()
+    }
+
+    #[inline]
+    fn from_raw(raw: i64) -> Self {
+        Self(raw.clamp(i32::MIN.into(), i32::MAX.into()) as i32)
+    }
+
+    #[inline]
+    fn to_angle(self) -> u32 {
+        // Only the angle modulo a full turn is needed, so wrapping is fine
+        ((self.to_raw() * RADIANS_TO_ANGLE) >> 16) as u32
+    }
+
+    #[inline]
+    fn from_angle(angle: i64) -> Self {
+        Self::from_raw(round_shr(angle * ANGLE_TO_RADIANS, 29 + 15))
+    }
+}
+
+impl From<i16> for I16F16 {
+    #[inline]
+    fn from(n: i16) -> I16F16 {
+        I16F16(i32::from(n) << 16)
+    }
+}
+
+impl_fixed!(I16F16, i32, "radians");
+
+#[cfg(test)]
+mod tests {
+    use super::I16F16;
+
+    #[test]
+    fn f32_conversion() {
+        assert_eq!(I16F16::from_f32(1.0), I16F16::ONE);
+        assert_eq!(I16F16::from_f32(-2.5).to_bits(), -0x0002_8000);
+        assert_eq!(I16F16::from_f32(1.0e6), I16F16::MAX);
+        assert_eq!(I16F16::from_f32(-1.0e6), I16F16::MIN);
+        assert_eq!(I16F16::from(-3i16).to_f32(), -3.0);
+        assert_eq!(I16F16::DELTA.to_f32(), 1.0 / 65536.0);
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        let a = I16F16::from_f32(1.5);
+        let b = I16F16::from_f32(-0.25);
+
+        assert_eq!(a + b, I16F16::from_f32(1.25));
+        assert_eq!(a - b, I16F16::from_f32(1.75));
+        assert_eq!(a * b, I16F16::from_f32(-0.375));
+        assert_eq!(a / b, I16F16::from_f32(-6.0));
+        assert_eq!(I16F16::MAX + a, I16F16::MAX);
+        assert_eq!(I16F16::MIN - a, I16F16::MIN);
+        assert_eq!(I16F16::from(300i16) * I16F16::from(300i16), I16F16::MAX);
+        assert_eq!(a / I16F16::ZERO, I16F16::MAX);
+    }
+}
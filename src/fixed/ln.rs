@@ -0,0 +1,94 @@
+//! Fixed-point natural logarithm.
+
+use super::{wide_horner, wide_mul, WIDE_FRAC_BITS, WIDE_ONE};
+
+/// `ln(2)` as a wide value.
+const LN_2: i64 = 744_261_118;
+
+/// Coefficients of the odd polynomial approximating `ln((1 + s) / (1 - s))`
+/// for `s` in `[0, 1/3]`, in terms of `s²` (least squares fit, maximum error
+/// `2e-9`).
+const LN_POLYNOMIAL: [i64; 5] = [
+    2_147_483_686,
+    715_820_389,
+    429_887_443,
+    298_725_422,
+    308_613_182,
+];
+
+/// Approximate the natural logarithm of a positive raw fixed-point value with
+/// `frac_bits` fractional bits, returning a wide value.
+pub(crate) fn ln_raw(raw: i64, frac_bits: u32) -> i64 {
+    debug_assert!(raw > 0);
+
+    // `x = 2^n * m` with `m` in `[1, 2)`
+    let msb = 63 - raw.leading_zeros() as i64;
+    let n = msb - i64::from(frac_bits);
+    let shift = msb - i64::from(WIDE_FRAC_BITS);
+    let m = if shift >= 0 {
+        raw >> shift
+    } else {
+        raw << -shift
+    };
+
+    // `ln(m) = ln((1 + s) / (1 - s))` where `s = (m - 1) / (m + 1)`
+    let s = ((m - WIDE_ONE) << WIDE_FRAC_BITS) / (m + WIDE_ONE);
+    n * LN_2 + wide_mul(wide_horner(&LN_POLYNOMIAL, wide_mul(s, s)), s)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixed::sin_cos::tests::q31_to_f64;
+    use crate::{I16F16, Q15, Q31};
+
+    #[test]
+    fn fractions() {
+        for i in 1..1000 {
+            let q15 = Q15::from_f32(i as f32 / 1000.0);
+            let q31 = Q31::from_bits(i * 2_147_483);
+
+            // Saturates at `MIN` for inputs below `1/e`
+            let expected = (q15.to_f32() as f64).ln().max(-1.0);
+            let actual = q15.ln().to_f32() as f64;
+            assert!(
+                (actual - expected).abs() <= 5e-5,
+                "{} vs {}",
+                actual,
+                expected
+            );
+
+            let expected = q31_to_f64(q31).ln().max(-1.0);
+            let actual = q31_to_f64(q31.ln());
+            assert!(
+                (actual - expected).abs() <= 1e-8,
+                "{} vs {}",
+                actual,
+                expected
+            );
+        }
+
+        assert_eq!(Q15::ZERO.ln(), Q15::MIN);
+        assert_eq!(Q31::MIN.ln(), Q31::MIN);
+    }
+
+    #[test]
+    fn i16f16() {
+        for i in 1..=32_767 {
+            let x = I16F16::from_bits(i * 65_537);
+            let expected = (x.to_f32() as f64).ln();
+            let actual = x.ln().to_f32() as f64;
+
+            assert!(
+                (actual - expected).abs() <= 2e-5,
+                "delta too large for input {}: {} vs {}",
+                x,
+                actual,
+                expected
+            );
+        }
+
+        let delta = (I16F16::DELTA.ln().to_f32() as f64 + 16.0 * core::f64::consts::LN_2).abs();
+        assert!(delta <= 2e-5);
+        assert_eq!(I16F16::from_f32(-1.0).ln(), I16F16::MIN);
+    }
+}
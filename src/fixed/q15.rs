@@ -0,0 +1,74 @@
+//! Q15 fixed-point number type.
+
+use super::{atan2, exp, ln, round_shr, sin_cos, sqrt, Fixed};
+use crate::F32;
+use core::{
+    fmt::{self, Debug, Display},
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// 16-bit signed fixed-point fraction with 15 fractional bits, in the range
+/// `[-1, 1)`.
+#[derive(Copy, Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Q15(i16);
+
+impl Fixed for Q15 {
+    const FRAC_BITS: u32 = 15;
+
+    #[inline]
+    fn to_raw(self) -> i64 {
+        self.0.into()
+    }
+
+    #[inline]
+    fn from_raw(raw: i64) -> Self {
+        Self(raw.clamp(i16::MIN.into(), i16::MAX.into()) as i16)
+    }
+
+    #[inline]
+    fn to_angle(self) -> u32 {
+        (i32::from(self.0) << 16) as u32
+    }
+
+    #[inline]
+    fn from_angle(angle: i64) -> Self {
+        Self::from_raw(round_shr(angle, 16))
+    }
+}
+
+impl_fixed!(Q15, i16, "half-turns");
+
+#[cfg(test)]
+mod tests {
+    use super::Q15;
+
+    #[test]
+    fn f32_conversion() {
+        assert_eq!(Q15::from_f32(0.5).to_bits(), 0x4000);
+        assert_eq!(Q15::from_f32(-1.0), Q15::MIN);
+        assert_eq!(Q15::from_f32(1.0), Q15::MAX);
+        assert_eq!(Q15::from_f32(-2.0), Q15::MIN);
+        assert_eq!(Q15::from_f32(f32::NAN), Q15::ZERO);
+        assert_eq!(Q15::MIN.to_f32(), -1.0);
+        assert_eq!(Q15::DELTA.to_f32(), 1.0 / 32768.0);
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        let half = Q15::from_f32(0.5);
+        let quarter = Q15::from_f32(0.25);
+
+        assert_eq!(half + quarter, Q15::from_f32(0.75));
+        assert_eq!(half - quarter, quarter);
+        assert_eq!(half * half, quarter);
+        assert_eq!(quarter / half, half);
+        assert_eq!(half + half, Q15::MAX);
+        assert_eq!(-half - half - half, Q15::MIN);
+        assert_eq!(Q15::MIN * Q15::MIN, Q15::MAX);
+        assert_eq!(-Q15::MIN, Q15::MAX);
+        assert_eq!(half / quarter, Q15::MAX);
+        assert_eq!(half / Q15::ZERO, Q15::MAX);
+        assert_eq!(-half / Q15::ZERO, Q15::MIN);
+    }
+}
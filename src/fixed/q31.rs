@@ -0,0 +1,74 @@
+//! Q31 fixed-point number type.
+
+use super::{atan2, exp, ln, round_shr, sin_cos, sqrt, Fixed};
+use crate::F32;
+use core::{
+    fmt::{self, Debug, Display},
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// 32-bit signed fixed-point fraction with 31 fractional bits, in the range
+/// `[-1, 1)`.
+#[derive(Copy, Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Q31(i32);
+
+impl Fixed for Q31 {
+    const FRAC_BITS: u32 = 31;
+
+    #[inline]
+    fn to_raw(self) -> i64 {
+        self.0.into()
+    }
+
+    #[inline]
+    fn from_raw(raw: i64) -> Self {
+        Self(raw.clamp(i32::MIN.into(), i32::MAX.into()) as i32)
+    }
+
+    #[inline]
+    fn to_angle(self) -> u32 {
+        self.0 as u32
+    }
+
+    #[inline]
+    fn from_angle(angle: i64) -> Self {
+        Self::from_raw(angle)
+    }
+}
+
+impl_fixed!(Q31, i32, "half-turns");
+
+#[cfg(test)]
+mod tests {
+    use super::Q31;
+
+    #[test]
+    fn f32_conversion() {
+        assert_eq!(Q31::from_f32(0.5).to_bits(), 0x4000_0000);
+        assert_eq!(Q31::from_f32(-1.0), Q31::MIN);
+        assert_eq!(Q31::from_f32(1.0), Q31::MAX);
+        assert_eq!(Q31::from_f32(-2.0), Q31::MIN);
+        assert_eq!(Q31::from_f32(f32::NAN), Q31::ZERO);
+        assert_eq!(Q31::MIN.to_f32(), -1.0);
+        assert_eq!(Q31::DELTA.to_f32(), 1.0 / 2_147_483_648.0);
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        let half = Q31::from_f32(0.5);
+        let quarter = Q31::from_f32(0.25);
+
+        assert_eq!(half + quarter, Q31::from_f32(0.75));
+        assert_eq!(half - quarter, quarter);
+        assert_eq!(half * half, quarter);
+        assert_eq!(quarter / half, half);
+        assert_eq!(half + half, Q31::MAX);
+        assert_eq!(-half - half - half, Q31::MIN);
+        assert_eq!(Q31::MIN * Q31::MIN, Q31::MAX);
+        assert_eq!(-Q31::MIN, Q31::MAX);
+        assert_eq!(half / quarter, Q31::MAX);
+        assert_eq!(half / Q31::ZERO, Q31::MAX);
+        assert_eq!(-half / Q31::ZERO, Q31::MIN);
+    }
+}
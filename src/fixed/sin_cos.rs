@@ -0,0 +1,114 @@
+//! Fixed-point sine and cosine.
+
+use super::{wide_horner, wide_mul, WIDE_ONE};
+
+/// Coefficients of the odd polynomial approximating `sin(πz/2)` for `z` in
+/// `[0, 1]`, in terms of `z²` (least squares fit, maximum error `4e-9`).
+const SIN_POLYNOMIAL: [i64; 5] = [1_686_629_681, -693_597_962, 85_565_131, -5_017_110, 162_088];
+
+/// Approximate the sine of a binary angle (`1 << 32` is a full turn),
+/// returning a wide value.
+pub(crate) fn sin_angle(angle: u32) -> i64 {
+    let quadrant = angle >> 30;
+    let mut z = i64::from(angle & 0x3fff_ffff);
+
+    // `sin(π - x) = sin(x)`
+    if quadrant & 1 == 1 {
+        z = WIDE_ONE - z;
+    }
+
+    let sin = wide_mul(wide_horner(&SIN_POLYNOMIAL, wide_mul(z, z)), z);
+
+    // `sin(x + π) = -sin(x)`
+    if quadrant & 2 == 2 {
+        -sin
+    } else {
+        sin
+    }
+}
+
+/// Approximate the cosine of a binary angle (`1 << 32` is a full turn),
+/// returning a wide value.
+pub(crate) fn cos_angle(angle: u32) -> i64 {
+    sin_angle(angle.wrapping_add(1 << 30))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::{I16F16, Q15, Q31};
+    use core::f64::consts::PI;
+
+    /// Maximum errors of each type, in units of the result
+    const Q15_MAX_ERROR: f64 = 5e-5;
+    const Q31_MAX_ERROR: f64 = 1e-8;
+    const I16F16_MAX_ERROR: f64 = 5e-5;
+
+    /// Convert a [`Q31`] to `f64` without rounding through `f32`.
+    pub(crate) fn q31_to_f64(x: Q31) -> f64 {
+        f64::from(x.to_bits()) / 2_147_483_648.0
+    }
+
+    #[test]
+    fn half_turns() {
+        for i in -1000..1000 {
+            let q15 = Q15::from_f32(i as f32 / 1000.0);
+            let q31 = Q31::from_bits(i * 2_147_483);
+
+            for (actual, expected, max_error) in [
+                (
+                    q15.sin().to_f32() as f64,
+                    (q15.to_f32() as f64 * PI).sin(),
+                    Q15_MAX_ERROR,
+                ),
+                (
+                    q15.cos().to_f32() as f64,
+                    (q15.to_f32() as f64 * PI).cos(),
+                    Q15_MAX_ERROR,
+                ),
+                (
+                    q31_to_f64(q31.sin()),
+                    (q31_to_f64(q31) * PI).sin(),
+                    Q31_MAX_ERROR,
+                ),
+                (
+                    q31_to_f64(q31.cos()),
+                    (q31_to_f64(q31) * PI).cos(),
+                    Q31_MAX_ERROR,
+                ),
+            ] {
+                let delta = (actual - expected).abs();
+                assert!(
+                    delta <= max_error,
+                    "delta {} too large for input {}: {} vs {}",
+                    delta,
+                    i,
+                    actual,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn radians() {
+        for i in -10_000..10_000 {
+            let x = I16F16::from_f32(i as f32 / 100.0);
+            let radians = x.to_f32() as f64;
+
+            for (actual, expected) in [
+                (x.sin().to_f32(), radians.sin()),
+                (x.cos().to_f32(), radians.cos()),
+            ] {
+                let delta = (actual as f64 - expected).abs();
+                assert!(
+                    delta <= I16F16_MAX_ERROR,
+                    "delta {} too large for input {}: {} vs {}",
+                    delta,
+                    radians,
+                    actual,
+                    expected
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Fixed-point square root.
+
+use super::Fixed;
+use crate::integer::isqrt;
+
+/// Square root of a fixed-point number, rounded down.
+///
+/// Returns zero for negative inputs.
+pub(crate) fn sqrt<T: Fixed>(x: T) -> T {
+    match x.to_raw() {
+        raw if raw <= 0 => T::from_raw(0),
+        // `sqrt(raw / 2^f) = sqrt(raw * 2^f) / 2^f`
+        raw => T::from_raw(i64::from(isqrt((raw as u64) << T::FRAC_BITS))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fixed::sin_cos::tests::q31_to_f64, I16F16, Q15, Q31};
+
+    #[test]
+    fn sanity_check() {
+        // Results are rounded down, so they're within one `DELTA` of the actual value
+        for raw in 0..=i16::MAX {
+            let x = Q15::from_bits(raw);
+            let expected = (x.to_f32() as f64).sqrt();
+            let delta = expected - x.sqrt().to_f32() as f64;
+            assert!((0.0..=Q15::DELTA.to_f32() as f64).contains(&delta), "{}", x);
+        }
+
+        for i in 0..=1000 {
+            let x = Q31::from_bits(i * 2_147_483);
+            let expected = q31_to_f64(x).sqrt();
+            let delta = expected - q31_to_f64(x.sqrt());
+            assert!((0.0..=q31_to_f64(Q31::DELTA)).contains(&delta), "{:?}", x);
+
+            let x = I16F16::from_bits(i * 2_147_483);
+            let expected = (f64::from(x.to_bits()) / 65536.0).sqrt();
+            let delta = expected - f64::from(x.sqrt().to_bits()) / 65536.0;
+            assert!((0.0..=1.0 / 65536.0).contains(&delta), "{}", x);
+        }
+
+        assert_eq!(Q15::from_f32(-0.5).sqrt(), Q15::ZERO);
+    }
+}
@@ -2,36 +2,75 @@
 
 pub(crate) mod abs;
 pub(crate) mod acos;
+pub(crate) mod acosh;
 pub(crate) mod asin;
+pub(crate) mod asinh;
 pub(crate) mod atan;
 pub(crate) mod atan2;
+pub(crate) mod atan2_deg;
+pub(crate) mod atanh;
+pub(crate) mod cbrt;
 pub(crate) mod ceil;
+pub(crate) mod clamp;
 pub(crate) mod copysign;
 pub(crate) mod cos;
+pub(crate) mod cos_deg;
+pub(crate) mod cos_turns;
 pub(crate) mod cosh;
 pub(crate) mod div_euclid;
+pub(crate) mod erf;
+pub(crate) mod erfc;
 pub(crate) mod exp;
+pub(crate) mod exp2;
+pub(crate) mod exp_m1;
 pub(crate) mod floor;
 pub(crate) mod fract;
+pub(crate) mod frexp;
+pub(crate) mod gamma;
 pub(crate) mod hypot;
+pub(crate) mod ilogb;
 pub(crate) mod inv;
+pub(crate) mod inverse_erf;
 pub(crate) mod invsqrt;
+pub(crate) mod ldexp;
 pub(crate) mod ln;
+pub(crate) mod ln_1p;
+pub(crate) mod ln_gamma;
 pub(crate) mod log;
 pub(crate) mod log10;
 pub(crate) mod log2;
+pub(crate) mod max;
+pub(crate) mod maximum;
+pub(crate) mod min;
+pub(crate) mod minimum;
 pub(crate) mod mul_add;
+pub(crate) mod next_down;
+pub(crate) mod next_up;
+pub(crate) mod nth_root;
+pub(crate) mod parse_bytes;
 pub(crate) mod powf;
 pub(crate) mod powi;
 pub(crate) mod recip;
+pub(crate) mod reduce_angle;
 pub(crate) mod rem_euclid;
 pub(crate) mod round;
+pub(crate) mod scalbn;
 pub(crate) mod signum;
 pub(crate) mod sin;
 pub(crate) mod sin_cos;
+pub(crate) mod sin_deg;
+pub(crate) mod sin_turns;
+pub(crate) mod sinh;
+pub(crate) mod slice;
 pub(crate) mod sqrt;
 pub(crate) mod tan;
+pub(crate) mod tanh;
+pub(crate) mod total_cmp;
 pub(crate) mod trunc;
+pub(crate) mod ulp;
+pub(crate) mod write_fixed;
+
+pub use self::total_cmp::TotalOrd;
 
 use core::{
     cmp::Ordering,
@@ -43,7 +82,15 @@ use core::{
 };
 
 #[cfg(feature = "num-traits")]
-use num_traits::{Inv, Num, One, Zero};
+use {
+    core::num::FpCategory,
+    num_traits::{
+        float::FloatCore, Bounded, FromPrimitive, Inv, Num, One, Signed, ToPrimitive, Zero,
+    },
+};
+
+#[cfg(feature = "num-traits-float")]
+use num_traits::Float;
 
 /// Sign mask.
 pub(crate) const SIGN_MASK: u32 = 0b1000_0000_0000_0000_0000_0000_0000_0000;
@@ -64,6 +111,17 @@ pub(crate) const MANTISSA_BITS: u32 = 23;
 
 /// 32-bit floating point wrapper which implements fast approximation-based
 /// operations.
+///
+/// Operations which only manipulate bits or use basic arithmetic, such as
+/// [`F32::abs`], [`F32::floor`], [`F32::sqrt`] and [`F32::powi`], are
+/// `const fn` and can be used to compute constants at compile time:
+///
+/// ```
+/// use micromath::{precision::Precise, F32};
+///
+/// const SQRT_2: F32 = F32(2.0).sqrt_with::<Precise>();
+/// assert!((SQRT_2.0 - core::f32::consts::SQRT_2).abs() < 1e-5);
+/// ```
 #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
 pub struct F32(pub f32);
 
@@ -122,34 +180,34 @@ impl F32 {
 
     /// Returns `true` if this value is `NaN`.
     #[inline]
-    pub fn is_nan(self) -> bool {
+    pub const fn is_nan(self) -> bool {
         self.0.is_nan()
     }
 
     /// Returns `true` if this value is positive infinity or negative infinity, and
     /// `false` otherwise.
     #[inline]
-    pub fn is_infinite(self) -> bool {
+    pub const fn is_infinite(self) -> bool {
         self.0.is_infinite()
     }
 
     /// Returns `true` if this number is neither infinite nor `NaN`.
     #[inline]
-    pub fn is_finite(self) -> bool {
+    pub const fn is_finite(self) -> bool {
         self.0.is_finite()
     }
 
     /// Returns `true` if `self` has a positive sign, including `+0.0`, `NaN`s with
     /// positive sign bit and positive infinity.
     #[inline]
-    pub fn is_sign_positive(self) -> bool {
+    pub const fn is_sign_positive(self) -> bool {
         self.0.is_sign_positive()
     }
 
     /// Returns `true` if `self` has a negative sign, including `-0.0`, `NaN`s with
     /// negative sign bit and negative infinity.
     #[inline]
-    pub fn is_sign_negative(self) -> bool {
+    pub const fn is_sign_negative(self) -> bool {
         self.0.is_sign_negative()
     }
 
@@ -160,7 +218,7 @@ impl F32 {
     /// See [`F32::from_bits`] for some discussion of the portability of this operation
     /// (there are almost no issues).
     #[inline]
-    pub fn to_bits(self) -> u32 {
+    pub const fn to_bits(self) -> u32 {
         self.0.to_bits()
     }
 
@@ -174,30 +232,30 @@ impl F32 {
     ///
     /// See [`f32::from_bits`] for more information.
     #[inline]
-    pub fn from_bits(v: u32) -> Self {
+    pub const fn from_bits(v: u32) -> Self {
         Self(f32::from_bits(v))
     }
 
     /// Extract exponent bits.
-    pub(crate) fn extract_exponent_bits(self) -> u32 {
+    pub(crate) const fn extract_exponent_bits(self) -> u32 {
         (self.to_bits() & EXPONENT_MASK)
             .overflowing_shr(MANTISSA_BITS)
             .0
     }
 
     /// Extract the exponent of a float's value.
-    pub(crate) fn extract_exponent_value(self) -> i32 {
+    pub(crate) const fn extract_exponent_value(self) -> i32 {
         (self.extract_exponent_bits() as i32) - EXPONENT_BIAS as i32
     }
 
     /// Remove sign.
-    pub(crate) fn without_sign(self) -> Self {
+    pub(crate) const fn without_sign(self) -> Self {
         Self::from_bits(self.to_bits() & !SIGN_MASK)
     }
 
     /// Set the exponent to the given value.
-    pub(crate) fn set_exponent(self, exponent: i32) -> Self {
-        debug_assert!(exponent <= 127 && exponent >= -128);
+    pub(crate) const fn set_exponent(self, exponent: i32) -> Self {
+        debug_assert!(exponent >= -128 && exponent <= 127);
         let without_exponent: u32 = self.to_bits() & !EXPONENT_MASK;
         let only_exponent: u32 = ((exponent + EXPONENT_BIAS as i32) as u32)
             .overflowing_shl(MANTISSA_BITS)
@@ -206,6 +264,16 @@ impl F32 {
         Self::from_bits(without_exponent | only_exponent)
     }
 
+    /// Returns `a` if `choice` is `true`, otherwise `b`.
+    ///
+    /// Both values are combined with a bit mask, so the choice compiles to a
+    /// blend rather than a branch and loops over it can be vectorized.
+    #[inline(always)]
+    pub(crate) const fn select(choice: bool, a: Self, b: Self) -> Self {
+        let mask = (choice as u32).wrapping_neg();
+        Self::from_bits((a.to_bits() & mask) | (b.to_bits() & !mask))
+    }
+
     /// Is this floating point value equivalent to an integer?
     pub(crate) fn is_integer(&self) -> bool {
         let exponent = self.extract_exponent_value();
@@ -637,6 +705,466 @@ impl Inv for F32 {
     }
 }
 
+#[cfg(feature = "num-traits")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+impl Signed for F32 {
+    fn abs(&self) -> Self {
+        F32::abs(*self)
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            Self::ZERO
+        } else {
+            *self - *other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        F32::signum(*self)
+    }
+
+    /// Returns `true` if the sign bit is clear, including for `+0.0`,
+    /// [`F32::INFINITY`] and positive `NaN`s, matching `f32`.
+    fn is_positive(&self) -> bool {
+        self.is_sign_positive()
+    }
+
+    /// Returns `true` if the sign bit is set, including for `-0.0`,
+    /// [`F32::NEG_INFINITY`] and negative `NaN`s, matching `f32`.
+    fn is_negative(&self) -> bool {
+        self.is_sign_negative()
+    }
+}
+
+#[cfg(feature = "num-traits")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+impl Bounded for F32 {
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+#[cfg(feature = "num-traits")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+impl ToPrimitive for F32 {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.0.to_i128()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.0.to_u128()
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        Some(self.0)
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.0.into())
+    }
+}
+
+#[cfg(feature = "num-traits")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+impl FromPrimitive for F32 {
+    fn from_i64(n: i64) -> Option<Self> {
+        f32::from_i64(n).map(Self)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        f32::from_u64(n).map(Self)
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        f32::from_i128(n).map(Self)
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        f32::from_u128(n).map(Self)
+    }
+
+    fn from_f32(n: f32) -> Option<Self> {
+        Some(Self(n))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        f32::from_f64(n).map(Self)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+impl num_traits::NumCast for F32 {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        n.to_f32().map(Self)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+impl FloatCore for F32 {
+    fn infinity() -> Self {
+        Self::INFINITY
+    }
+
+    fn neg_infinity() -> Self {
+        Self::NEG_INFINITY
+    }
+
+    fn nan() -> Self {
+        Self::NAN
+    }
+
+    fn neg_zero() -> Self {
+        Self(-0.0)
+    }
+
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    fn min_positive_value() -> Self {
+        Self::MIN_POSITIVE
+    }
+
+    fn epsilon() -> Self {
+        Self::EPSILON
+    }
+
+    fn max_value() -> Self {
+        Self::MAX
+    }
+
+    fn is_nan(self) -> bool {
+        F32::is_nan(self)
+    }
+
+    fn is_infinite(self) -> bool {
+        F32::is_infinite(self)
+    }
+
+    fn is_finite(self) -> bool {
+        F32::is_finite(self)
+    }
+
+    fn classify(self) -> FpCategory {
+        self.0.classify()
+    }
+
+    fn floor(self) -> Self {
+        F32::floor(self)
+    }
+
+    fn ceil(self) -> Self {
+        F32::ceil(self)
+    }
+
+    fn round(self) -> Self {
+        F32::round(self)
+    }
+
+    fn trunc(self) -> Self {
+        F32::trunc(self)
+    }
+
+    fn fract(self) -> Self {
+        F32::fract(self)
+    }
+
+    fn abs(self) -> Self {
+        F32::abs(self)
+    }
+
+    fn signum(self) -> Self {
+        F32::signum(self)
+    }
+
+    fn is_sign_positive(self) -> bool {
+        F32::is_sign_positive(self)
+    }
+
+    fn is_sign_negative(self) -> bool {
+        F32::is_sign_negative(self)
+    }
+
+    fn min(self, other: Self) -> Self {
+        Self(self.0.min(other.0))
+    }
+
+    fn max(self, other: Self) -> Self {
+        Self(self.0.max(other.0))
+    }
+
+    fn recip(self) -> Self {
+        F32::recip(self)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        F32::powi(self, n)
+    }
+
+    fn to_degrees(self) -> Self {
+        Self(self.0.to_degrees())
+    }
+
+    fn to_radians(self) -> Self {
+        Self(self.0.to_radians())
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        FloatCore::integer_decode(self.0)
+    }
+}
+
+#[cfg(feature = "num-traits-float")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-traits-float")))]
+impl Float for F32 {
+    fn nan() -> Self {
+        Self::NAN
+    }
+
+    fn infinity() -> Self {
+        Self::INFINITY
+    }
+
+    fn neg_infinity() -> Self {
+        Self::NEG_INFINITY
+    }
+
+    fn neg_zero() -> Self {
+        Self(-0.0)
+    }
+
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    fn min_positive_value() -> Self {
+        Self::MIN_POSITIVE
+    }
+
+    fn epsilon() -> Self {
+        Self::EPSILON
+    }
+
+    fn max_value() -> Self {
+        Self::MAX
+    }
+
+    fn is_nan(self) -> bool {
+        F32::is_nan(self)
+    }
+
+    fn is_infinite(self) -> bool {
+        F32::is_infinite(self)
+    }
+
+    fn is_finite(self) -> bool {
+        F32::is_finite(self)
+    }
+
+    fn is_normal(self) -> bool {
+        self.0.is_normal()
+    }
+
+    fn classify(self) -> FpCategory {
+        self.0.classify()
+    }
+
+    fn floor(self) -> Self {
+        F32::floor(self)
+    }
+
+    fn ceil(self) -> Self {
+        F32::ceil(self)
+    }
+
+    fn round(self) -> Self {
+        F32::round(self)
+    }
+
+    fn trunc(self) -> Self {
+        F32::trunc(self)
+    }
+
+    fn fract(self) -> Self {
+        F32::fract(self)
+    }
+
+    fn abs(self) -> Self {
+        F32::abs(self)
+    }
+
+    fn signum(self) -> Self {
+        F32::signum(self)
+    }
+
+    fn is_sign_positive(self) -> bool {
+        F32::is_sign_positive(self)
+    }
+
+    fn is_sign_negative(self) -> bool {
+        F32::is_sign_negative(self)
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        F32::mul_add(self, a, b)
+    }
+
+    fn recip(self) -> Self {
+        F32::recip(self)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        F32::powi(self, n)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        F32::powf(self, n)
+    }
+
+    fn sqrt(self) -> Self {
+        F32::sqrt(self)
+    }
+
+    fn exp(self) -> Self {
+        F32::exp(self)
+    }
+
+    fn exp2(self) -> Self {
+        F32::exp2(self)
+    }
+
+    fn ln(self) -> Self {
+        F32::ln(self)
+    }
+
+    fn log(self, base: Self) -> Self {
+        F32::log(self, base)
+    }
+
+    fn log2(self) -> Self {
+        F32::log2(self)
+    }
+
+    fn log10(self) -> Self {
+        F32::log10(self)
+    }
+
+    fn to_degrees(self) -> Self {
+        Self(self.0.to_degrees())
+    }
+
+    fn to_radians(self) -> Self {
+        Self(self.0.to_radians())
+    }
+
+    fn max(self, other: Self) -> Self {
+        Self(self.0.max(other.0))
+    }
+
+    fn min(self, other: Self) -> Self {
+        Self(self.0.min(other.0))
+    }
+
+    fn abs_sub(self, other: Self) -> Self {
+        Signed::abs_sub(&self, &other)
+    }
+
+    fn cbrt(self) -> Self {
+        F32::cbrt(self)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        F32::hypot(self, other)
+    }
+
+    fn sin(self) -> Self {
+        F32::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        F32::cos(self)
+    }
+
+    fn tan(self) -> Self {
+        F32::tan(self)
+    }
+
+    fn asin(self) -> Self {
+        F32::asin(self)
+    }
+
+    fn acos(self) -> Self {
+        F32::acos(self)
+    }
+
+    fn atan(self) -> Self {
+        F32::atan(self)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        F32::atan2(self, other)
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        F32::sin_cos(self)
+    }
+
+    fn exp_m1(self) -> Self {
+        F32::exp_m1(self)
+    }
+
+    fn ln_1p(self) -> Self {
+        F32::ln_1p(self)
+    }
+
+    fn sinh(self) -> Self {
+        F32::sinh(self)
+    }
+
+    fn cosh(self) -> Self {
+        F32::cosh(self)
+    }
+
+    fn tanh(self) -> Self {
+        F32::tanh(self)
+    }
+
+    fn asinh(self) -> Self {
+        F32::asinh(self)
+    }
+
+    fn acosh(self) -> Self {
+        F32::acosh(self)
+    }
+
+    fn atanh(self) -> Self {
+        F32::atanh(self)
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        FloatCore::integer_decode(self.0)
+    }
+
+    fn copysign(self, sign: Self) -> Self {
+        F32::copysign(self, sign)
+    }
+}
+
 #[cfg(feature = "defmt")]
 #[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
 impl defmt::Format for F32 {
@@ -655,4 +1183,61 @@ mod tests {
     fn inv_trait() {
         assert_eq!(num_traits::Inv::inv(F32(2.0)), F32(0.5));
     }
+
+    #[cfg(feature = "num-traits-float")]
+    #[test]
+    fn float_trait() {
+        fn hypot<T: num_traits::Float>(x: T, y: T) -> T {
+            (x * x + y * y).sqrt()
+        }
+
+        let delta = (hypot(F32(3.0), F32(4.0)) - 5.0).abs();
+        assert!(delta <= 5.0 * 0.05, "delta {} too large", delta);
+        assert_eq!(num_traits::Float::max(F32(1.0), F32::NAN), F32(1.0));
+        assert_eq!(
+            num_traits::Float::integer_decode(F32(1.0)),
+            (1 << 23, -23, 1)
+        );
+    }
+
+    #[cfg(feature = "num-traits-float")]
+    #[test]
+    fn real_trait() {
+        // `Real` is blanket impl'd for all `Float` types
+        fn to_degrees<T: num_traits::real::Real>(x: T) -> T {
+            x.to_degrees()
+        }
+
+        assert_eq!(to_degrees(F32(core::f32::consts::PI)), F32(180.0));
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn float_core_trait() {
+        fn clamp<T: num_traits::float::FloatCore>(x: T, lo: T, hi: T) -> T {
+            x.max(lo).min(hi)
+        }
+
+        assert_eq!(clamp(F32(2.5), F32(0.0), F32(1.0)), F32(1.0));
+        assert_eq!(num_traits::float::FloatCore::floor(F32(-1.5)), F32(-2.0));
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn num_cast() {
+        use num_traits::{Bounded, FromPrimitive, NumCast, Signed, ToPrimitive};
+
+        assert_eq!(<F32 as NumCast>::from(3u8), Some(F32(3.0)));
+        assert_eq!(F32::from_i64(-7), Some(F32(-7.0)));
+        assert_eq!(F32(42.9).to_i32(), Some(42));
+        assert_eq!(F32(-1.0).to_u8(), None);
+        assert_eq!(F32::NAN.to_i64(), None);
+        assert_eq!(<F32 as Bounded>::max_value(), F32::MAX);
+        assert_eq!(Signed::abs_sub(&F32(1.0), &F32(3.0)), F32::ZERO);
+        assert!(Signed::is_negative(&F32(-2.0)));
+        assert!(Signed::is_positive(&F32(0.0)));
+        assert!(Signed::is_negative(&F32(-0.0)));
+        assert!(!Signed::is_positive(&F32(-0.0)));
+        assert!(Signed::is_positive(&F32::INFINITY));
+    }
 }
@@ -8,7 +8,7 @@ impl F32 {
     /// Computes the absolute value of `self`.
     ///
     /// Returns [`Self::NAN`] if the number is [`Self::NAN`].
-    pub fn abs(self) -> Self {
+    pub const fn abs(self) -> Self {
         Self::from_bits(self.to_bits() & !SIGN_MASK)
     }
 }
@@ -6,13 +6,21 @@
 use super::F32;
 use core::f32::consts::PI;
 
+/// `2^-24`, below which `acos(x)` rounds to `π/2`.
+const TWO_POW_NEG_24: f32 = 1.0 / 16_777_216.0;
+
 impl F32 {
-    /// Computes `acos(x)` approximation in radians in the range `[0, pi]`.
+    /// Computes `acos(x)` approximation in radians in the range `[0, pi]`,
+    /// with a maximum error of `0.029` (see [`accuracy::ACOS`]).
+    ///
+    /// [`accuracy::ACOS`]: crate::accuracy::ACOS
     pub(crate) fn acos(self) -> Self {
-        if self > 0.0 {
-            ((Self::ONE - self * self).sqrt() / self).atan()
-        } else if self == 0.0 {
+        if self.abs() < TWO_POW_NEG_24 {
+            // The ratio below would overflow `atan`, and the result rounds to
+            // `π/2` anyway
             Self(PI / 2.0)
+        } else if self > 0.0 {
+            ((Self::ONE - self * self).sqrt() / self).atan()
         } else {
             ((Self::ONE - self * self).sqrt() / self).atan() + PI
         }
@@ -0,0 +1,82 @@
+//! Inverse hyperbolic cosine function
+
+use super::F32;
+use crate::precision::Precise;
+use core::f32::consts::LN_2;
+
+impl F32 {
+    /// Approximates `acosh(x)` with a maximum error of `7e-5` and a maximum
+    /// relative error of `8.8e-5`, see [`accuracy::ACOSH`].
+    ///
+    /// Computed as `ln(x + sqrt(x² - 1))`.
+    ///
+    /// Returns [`Self::NAN`] if `self` is less than `1.0`.
+    ///
+    /// [`accuracy::ACOSH`]: crate::accuracy::ACOSH
+    pub fn acosh(self) -> Self {
+        if self.is_nan() || self < Self::ONE {
+            Self::NAN
+        } else if self.is_infinite() {
+            self
+        } else if self > 1e9 {
+            // `x² - 1` would overflow, and `sqrt(x² - 1) ≈ x`
+            self.ln() + LN_2
+        } else {
+            (self + (self * self - 1.0).sqrt_with::<Precise>()).ln()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum error
+    const MAX_ERROR: f32 = 0.0001;
+
+    /// Inverse hyperbolic cosine test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (1.0, 0.0),
+        (1.001, 0.044717632),
+        (1.01, 0.14130376),
+        (1.1, 0.44356826),
+        (1.5, 0.9624236),
+        (2.0, 1.316958),
+        (3.0, 1.7627472),
+        (5.0, 2.2924316),
+        (1e+01, 2.993223),
+        (1e+02, 5.298292),
+        (1e+03, 7.600902),
+        (1e+10, 23.718998),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let acosh_x = F32(x).acosh();
+            let delta = (acosh_x - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large for input {}: {} vs {}",
+                delta,
+                x,
+                acosh_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(F32(0.5).acosh().is_nan());
+        assert!(F32(-1.0).acosh().is_nan());
+        assert!(F32::NAN.acosh().is_nan());
+        assert!(F32::NEG_INFINITY.acosh().is_nan());
+    }
+
+    #[test]
+    fn infinity() {
+        assert_eq!(F32::INFINITY.acosh(), F32::INFINITY);
+    }
+}
@@ -6,7 +6,10 @@
 use super::F32;
 
 impl F32 {
-    /// Computes `asin(x)` approximation in radians in the range `[-pi/2, pi/2]`.
+    /// Computes `asin(x)` approximation in radians in the range `[-pi/2, pi/2]`,
+    /// with a maximum error of `0.018` (see [`accuracy::ASIN`]).
+    ///
+    /// [`accuracy::ASIN`]: crate::accuracy::ASIN
     pub fn asin(self) -> Self {
         (self * (Self::ONE - self * self).invsqrt()).atan()
     }
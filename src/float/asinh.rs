@@ -0,0 +1,94 @@
+//! Inverse hyperbolic sine function
+
+use super::F32;
+use crate::{poly, precision::Precise};
+use core::f32::consts::LN_2;
+
+/// Coefficients of the Taylor series of `asinh(x) / x` in `x²`.
+const ASINH_SERIES: [f32; 5] = [1.0, -1.0 / 6.0, 3.0 / 40.0, -5.0 / 112.0, 35.0 / 1_152.0];
+
+impl F32 {
+    /// Approximates `asinh(x)` with a maximum error of `7e-5` and a maximum
+    /// relative error of `8.8e-5`, see [`accuracy::ASINH`].
+    ///
+    /// Computed as `ln(|x| + sqrt(x² + 1))` with the sign of `x` restored.
+    /// For `|x| < 0.25` the Taylor series is used instead, which keeps the
+    /// relative error small near zero.
+    ///
+    /// [`accuracy::ASINH`]: crate::accuracy::ASINH
+    pub fn asinh(self) -> Self {
+        if self.is_infinite() {
+            return self;
+        }
+
+        let x = self.abs();
+
+        let asinh_x = if x < 0.25 {
+            x * poly::horner(x * x, &ASINH_SERIES)
+        } else if x > 1e9 {
+            // `x² + 1` would overflow, and `sqrt(x² + 1) ≈ x`
+            x.ln() + LN_2
+        } else {
+            (x + (x * x + 1.0).sqrt_with::<Precise>()).ln()
+        };
+
+        asinh_x.copysign(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum error
+    const MAX_ERROR: f32 = 0.0001;
+
+    /// Inverse hyperbolic sine test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (-1e+10, -23.718998),
+        (-1e+03, -7.6009026),
+        (-1e+02, -5.298342),
+        (-1e+01, -2.998223),
+        (-2.0, -1.4436355),
+        (-1.0, -0.8813736),
+        (-0.5, -0.4812118),
+        (-0.25, -0.24746646),
+        (-0.1, -0.09983408),
+        (-0.01, -0.009999833),
+        (0.0, 0.0),
+        (0.01, 0.009999833),
+        (0.1, 0.09983408),
+        (0.25, 0.24746646),
+        (0.5, 0.4812118),
+        (1.0, 0.8813736),
+        (2.0, 1.4436355),
+        (1e+01, 2.998223),
+        (1e+02, 5.298342),
+        (1e+03, 7.6009026),
+        (1e+10, 23.718998),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let asinh_x = F32(x).asinh();
+            let delta = (asinh_x - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large for input {}: {} vs {}",
+                delta,
+                x,
+                asinh_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn infinity() {
+        assert_eq!(F32::INFINITY.asinh(), F32::INFINITY);
+        assert_eq!(F32::NEG_INFINITY.asinh(), F32::NEG_INFINITY);
+        assert!(F32::NAN.asinh().is_nan());
+    }
+}
@@ -3,14 +3,17 @@
 //! Method described at:
 //! <https://ieeexplore.ieee.org/document/6375931>
 
-use super::F32;
+use super::{F32, SIGN_MASK};
+use crate::poly;
 use core::f32::consts::FRAC_PI_2;
 
 impl F32 {
     /// Approximates `atan(x)` approximation in radians with a maximum error of
-    /// `0.002`.
+    /// `0.0029`, see [`accuracy::ATAN`].
     ///
     /// Returns [`Self::NAN`] if the number is [`Self::NAN`].
+    ///
+    /// [`accuracy::ATAN`]: crate::accuracy::ATAN
     pub fn atan(self) -> Self {
         FRAC_PI_2 * self.atan_norm()
     }
@@ -18,16 +21,15 @@ impl F32 {
     /// Approximates `atan(x)` normalized to the `[−1,1]` range with a maximum
     /// error of `0.1620` degrees.
     pub fn atan_norm(self) -> Self {
-        const SIGN_MASK: u32 = 0x8000_0000;
         const B: f32 = 0.596_227;
 
         // Extract the sign bit
         let ux_s = SIGN_MASK & self.to_bits();
 
-        // Calculate the arctangent in the first quadrant
-        let bx_a = (B * self).abs();
-        let n = bx_a + self * self;
-        let atan_1q = n / (1.0 + bx_a + n);
+        // Calculate the arctangent in the first quadrant as the rational
+        // function `(B|x| + x²) / (1 + 2B|x| + x²)`
+        let x = self.abs();
+        let atan_1q = poly::horner(x, &[0.0, B, 1.0]) / poly::horner(x, &[1.0, 2.0 * B, 1.0]);
 
         // Restore the sign bit and convert to float
         Self::from_bits(ux_s | atan_1q.to_bits())
@@ -7,15 +7,18 @@ use core::f32::consts::PI;
 
 impl F32 {
     /// Approximates the four quadrant arctangent of `self` (`y`) and
-    /// `rhs` (`x`) in radians with a maximum error of `0.002`.
+    /// `rhs` (`x`) in radians with a maximum error of `0.0029`, see
+    /// [`accuracy::ATAN2`].
     ///
     /// - `x = 0`, `y = 0`: `0`
     /// - `x >= 0`: `arctan(y/x)` -> `[-pi/2, pi/2]`
     /// - `y >= 0`: `arctan(y/x) + pi` -> `(pi/2, pi]`
     /// - `y < 0`: `arctan(y/x) - pi` -> `(-pi, -pi/2)`
+    ///
+    /// [`accuracy::ATAN2`]: crate::accuracy::ATAN2
     pub fn atan2(self, rhs: Self) -> Self {
         let n = self.atan2_norm(rhs);
-        PI / 2.0 * if n > 2.0 { n - 4.0 } else { n }
+        PI / 2.0 * Self::select(n > 2.0, n - 4.0, n)
     }
 
     /// Approximates `atan2(y,x)` normalized to the `[0, 4)` range with a maximum
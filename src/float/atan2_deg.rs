@@ -0,0 +1,54 @@
+//! Four quadrant arctangent approximation for a single-precision float,
+//! returning degrees.
+
+use super::F32;
+
+impl F32 {
+    /// Approximates the four quadrant arctangent of `self` (`y`) and
+    /// `rhs` (`x`) in degrees, in the range `(-180, 180]`, with a maximum
+    /// error of `0.1620` degrees.
+    ///
+    /// See [`F32::atan2`] for the handling of zero inputs.
+    pub fn atan2_deg(self, rhs: Self) -> Self {
+        let n = self.atan2_norm(rhs);
+        90.0 * if n > 2.0 { n - 4.0 } else { n }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum error in degrees
+    const MAX_ERROR: f32 = 0.1620;
+
+    /// Arctangent test vectors - `((y, x), output)`
+    const TEST_VECTORS: &[((f32, f32), f32)] = &[
+        ((0.0, 1.0), 0.0),
+        ((1.0, 1.0), 45.0),
+        ((1.0, 0.0), 90.0),
+        ((1.0, -1.0), 135.0),
+        ((0.0, -1.0), 180.0),
+        ((-1.0, -1.0), -135.0),
+        ((-1.0, 0.0), -90.0),
+        ((-1.0, 1.0), -45.0),
+        ((3.0, 5.2), 29.982),
+        ((-500.0, 0.25), -89.971),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &((y, x), expected) in TEST_VECTORS {
+            let actual = F32(y).atan2_deg(F32(x));
+            let delta = (actual - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large: {} vs {}",
+                delta,
+                actual,
+                expected
+            );
+        }
+    }
+}
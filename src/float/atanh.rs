@@ -0,0 +1,85 @@
+//! Inverse hyperbolic tangent function
+
+use super::{ln_1p::ATANH_SERIES, F32};
+use crate::poly;
+
+impl F32 {
+    /// Approximates `atanh(x)` with a maximum error of `3.2e-5` and a maximum
+    /// relative error of `8.8e-5`, see [`accuracy::ATANH`].
+    ///
+    /// Computed as `ln((1 + x) / (1 - x)) / 2`. For `|x| < 0.25` the Taylor
+    /// series is used instead, which keeps the relative error small near zero.
+    ///
+    /// Returns [`Self::INFINITY`] (with the sign of `self`) for `±1.0`, and
+    /// [`Self::NAN`] if `|self| > 1.0`.
+    ///
+    /// [`accuracy::ATANH`]: crate::accuracy::ATANH
+    pub fn atanh(self) -> Self {
+        let x = self.abs();
+
+        if x.is_nan() || x > Self::ONE {
+            Self::NAN
+        } else if x == Self::ONE {
+            Self::INFINITY.copysign(self)
+        } else if x < 0.25 {
+            (x * poly::horner(x * x, &ATANH_SERIES)).copysign(self)
+        } else {
+            (((1.0 + x) / (1.0 - x)).ln() / 2.0).copysign(self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum error
+    const MAX_ERROR: f32 = 0.0001;
+
+    /// Inverse hyperbolic tangent test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (-0.999, -3.8002012),
+        (-0.99, -2.6466525),
+        (-0.9, -1.4722195),
+        (-0.75, -0.97295505),
+        (-0.5, -0.54930615),
+        (-0.25, -0.25541282),
+        (-0.1, -0.100335345),
+        (-0.01, -0.010000333),
+        (0.0, 0.0),
+        (0.01, 0.010000333),
+        (0.1, 0.100335345),
+        (0.25, 0.25541282),
+        (0.5, 0.54930615),
+        (0.75, 0.97295505),
+        (0.9, 1.4722195),
+        (0.99, 2.6466525),
+        (0.999, 3.8002012),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let atanh_x = F32(x).atanh();
+            let delta = (atanh_x - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large for input {}: {} vs {}",
+                delta,
+                x,
+                atanh_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(F32::ONE.atanh(), F32::INFINITY);
+        assert_eq!((-F32::ONE).atanh(), F32::NEG_INFINITY);
+        assert!(F32(1.5).atanh().is_nan());
+        assert!(F32(-1.5).atanh().is_nan());
+        assert!(F32::NAN.atanh().is_nan());
+    }
+}
@@ -0,0 +1,144 @@
+//! Cube root approximation function for a single-precision float.
+//!
+//! Method described at: <https://web.archive.org/web/20131227144655/http://metamerist.com/cbrt/cbrt.htm>
+
+use super::{F32, SIGN_MASK};
+use crate::precision::Precision;
+
+impl F32 {
+    /// Approximates the cube root of a number with an average deviation of ~3%,
+    /// and a maximum relative error of `0.033` (see [`accuracy::CBRT`]).
+    ///
+    /// Unlike [`F32::sqrt`], negative numbers have a real cube root, so the
+    /// sign of `self` is preserved. Infinities and NaN are returned unchanged.
+    ///
+    /// [`accuracy::CBRT`]: crate::accuracy::CBRT
+    pub fn cbrt(self) -> Self {
+        let bits = self.to_bits();
+        let sign = bits & SIGN_MASK;
+        let magnitude = bits & !SIGN_MASK;
+
+        if magnitude == 0 || !self.is_finite() {
+            return self;
+        }
+
+        Self::from_bits(sign | (magnitude / 3 + 0x2a51_37a0))
+    }
+
+    /// Approximates the cube root of a number using the given [`Precision`]
+    /// tier, refining [`F32::cbrt`] with [`Precision::NEWTON_ITERATIONS`]
+    /// steps of Newton's method.
+    ///
+    /// See [`Precision::CBRT_MAX_ERROR`] for the maximum error of each tier.
+    pub fn cbrt_with<P: Precision>(self) -> Self {
+        let mut y = self.cbrt();
+
+        if self == Self::ZERO || !self.is_finite() {
+            return self;
+        }
+
+        for _ in 0..P::NEWTON_ITERATIONS {
+            y = (2.0 * y + self / (y * y)) / 3.0;
+        }
+
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+    use crate::precision::{Balanced, Fast, Precise, Precision};
+
+    /// Deviation from the actual value (3.3%)
+    const MAX_ERROR: f32 = 0.033;
+
+    /// Cube root test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (-1.0e9, -1.0e3),
+        (-27.0, -3.0),
+        (-8.0, -2.0),
+        (-2.0, -1.2599211),
+        (-1.0, -1.0),
+        (-0.125, -0.5),
+        (-1.0e-6, -0.01),
+        (1.0e-6, 0.01),
+        (0.001, 0.1),
+        (0.125, 0.5),
+        (0.5, 0.7937005),
+        (1.0, 1.0),
+        (2.0, 1.2599211),
+        (3.0, 1.4422495),
+        (8.0, 2.0),
+        (1.0e1, 2.1544347),
+        (27.0, 3.0),
+        (1.0e2, 4.6415887),
+        (1.0e3, 1.0e1),
+        (12345.0, 23.111618),
+        (1.0e9, 1.0e3),
+        (1.0e30, 1.0e10),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let cbrt_x = F32(x).cbrt();
+            let relative_error = ((cbrt_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                cbrt_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(F32::ZERO.cbrt(), F32::ZERO);
+        assert!(F32(-0.0).cbrt().is_sign_negative());
+        assert_eq!(F32::ZERO.cbrt_with::<Precise>(), F32::ZERO);
+    }
+
+    #[test]
+    fn non_finite() {
+        assert!(F32::NAN.cbrt().is_nan());
+        assert!(F32::NAN.cbrt_with::<Precise>().is_nan());
+
+        for x in [F32::INFINITY, F32::NEG_INFINITY] {
+            assert_eq!(x.cbrt(), x);
+            assert_eq!(x.cbrt_with::<Fast>(), x);
+            assert_eq!(x.cbrt_with::<Precise>(), x);
+        }
+    }
+
+    fn precision_tier<P: Precision>() {
+        let mut x = 1e-30_f32;
+
+        while x < 1e30 {
+            for x in [x, -x] {
+                let expected = x.cbrt();
+                let relative_error = ((F32(x).cbrt_with::<P>() - expected) / expected).abs();
+
+                assert!(
+                    relative_error <= P::CBRT_MAX_ERROR,
+                    "relative_error {} too large for input {}",
+                    relative_error,
+                    x
+                );
+            }
+
+            x *= 1.001;
+        }
+    }
+
+    #[test]
+    fn precision_tiers() {
+        precision_tier::<Fast>();
+        precision_tier::<Balanced>();
+        precision_tier::<Precise>();
+    }
+}
@@ -4,8 +4,8 @@ use super::F32;
 
 impl F32 {
     /// Returns the smallest integer greater than or equal to a number.
-    pub fn ceil(self) -> Self {
-        -(-self).floor()
+    pub const fn ceil(self) -> Self {
+        Self(-Self(-self.0).floor().0)
     }
 }
 
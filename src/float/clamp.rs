@@ -0,0 +1,51 @@
+//! Restrict a single-precision float to an interval.
+
+use super::F32;
+
+impl F32 {
+    /// Restricts `self` to the interval `[min, max]`.
+    ///
+    /// Returns `max` if `self` is greater than `max`, `min` if `self` is less
+    /// than `min`, and `self` otherwise, including when `self` is NaN.
+    ///
+    /// # Panics
+    ///
+    /// If `min > max`, or if either `min` or `max` is NaN.
+    #[inline]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min <= max, "min > max, or either was NaN");
+
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F32(-3.0).clamp(F32(-2.0), F32(1.0)), F32(-2.0));
+        assert_eq!(F32(0.0).clamp(F32(-2.0), F32(1.0)), F32(0.0));
+        assert_eq!(F32(2.0).clamp(F32(-2.0), F32(1.0)), F32(1.0));
+        assert!(F32::NAN.clamp(F32(-2.0), F32(1.0)).is_nan());
+    }
+
+    #[test]
+    #[should_panic]
+    fn min_greater_than_max() {
+        F32(0.0).clamp(F32(1.0), F32(-1.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn nan_bound() {
+        F32(0.0).clamp(F32::NAN, F32(1.0));
+    }
+}
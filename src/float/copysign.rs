@@ -5,7 +5,7 @@ use super::{F32, SIGN_MASK};
 impl F32 {
     /// Returns a number composed of the magnitude of `self` and the sign of
     /// `sign`.
-    pub fn copysign(self, sign: Self) -> Self {
+    pub const fn copysign(self, sign: Self) -> Self {
         let source_bits = sign.to_bits();
         let source_sign = source_bits & SIGN_MASK;
         let signless_destination_bits = self.to_bits() & !SIGN_MASK;
@@ -219,28 +219,113 @@
 //! > like the exponential, logarithm and power functions.
 
 use super::F32;
-use core::f32::consts::FRAC_1_PI;
+#[cfg(feature = "trig-table")]
+use crate::trig_table::TrigTable;
+use crate::{poly, precision::Precision};
+use core::f32::consts::{FRAC_1_PI, TAU};
+
+/// Coefficients of the Taylor series of `cos(x)` in terms of `x²`, i.e.
+/// `(-1)^n / (2n)!`.
+const COS_TAYLOR_SERIES: [f32; 7] = [
+    1.0,
+    -1.0 / 2.0,
+    1.0 / 24.0,
+    -1.0 / 720.0,
+    1.0 / 40_320.0,
+    -1.0 / 3_628_800.0,
+    1.0 / 479_001_600.0,
+];
 
 impl F32 {
-    /// Approximates `cos(x)` in radians with a maximum error of `0.002`.
+    /// Approximates `cos(x)` in radians with a maximum error of `0.0011`, see
+    /// [`accuracy::COS`].
+    ///
+    /// [`accuracy::COS`]: crate::accuracy::COS
+    #[cfg(not(feature = "trig-table"))]
     pub fn cos(self) -> Self {
+        self.reduce_angle().cos_refined()
+    }
+
+    /// Approximates `cos(x)` in radians using a lookup table of
+    /// [`DEFAULT_SIZE`][`crate::trig_table::DEFAULT_SIZE`] entries, with a
+    /// maximum error of [`TrigTable::MAX_ERROR`] (see [`accuracy::COS`]).
+    ///
+    /// [`accuracy::COS`]: crate::accuracy::COS
+    #[cfg(feature = "trig-table")]
+    pub fn cos(self) -> Self {
+        crate::trig_table::DEFAULT_TABLE.cos(self)
+    }
+
+    /// Approximates `cos(x)` in radians using the given lookup table, with a
+    /// maximum error of [`TrigTable::MAX_ERROR`].
+    #[cfg(feature = "trig-table")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "trig-table")))]
+    pub fn cos_with_table<const N: usize>(self, table: &TrigTable<N>) -> Self {
+        table.cos(self)
+    }
+
+    /// Approximates `cos(x)` in radians using the given [`Precision`] tier.
+    ///
+    /// See [`Precision::COS_MAX_ERROR`] for the maximum error of each tier.
+    #[inline]
+    pub fn cos_with<P: Precision>(self) -> Self {
+        P::cos(self.reduce_angle())
+    }
+
+    /// Parabolic approximation of `cos(x)` including the "extra precision"
+    /// step, with a maximum error of `0.002`.
+    pub(crate) fn cos_refined(self) -> Self {
+        let x = self.cos_parabolic();
+        x + 0.225 * x * (x.abs() - 1.0)
+    }
+
+    /// Parabolic approximation of `cos(x)` without the "extra precision"
+    /// step, with a maximum error of `0.056`.
+    pub(crate) fn cos_parabolic(self) -> Self {
+        (self * (FRAC_1_PI / 2.0)).cos_parabolic_turns()
+    }
+
+    /// Parabolic approximation of `cos(x)` where `x` is in turns, without
+    /// the "extra precision" step.
+    pub(crate) fn cos_parabolic_turns(self) -> Self {
         let mut x = self;
-        x *= FRAC_1_PI / 2.0;
         x -= 0.25 + (x + 0.25).floor().0;
-        x *= 16.0 * (x.abs() - 0.5);
-        x += 0.225 * x * (x.abs() - 1.0);
-        x
+        x * 16.0 * (x.abs() - 0.5)
+    }
+
+    /// Approximates `cos(x)` by reducing the argument to `[0, π/2]` and
+    /// evaluating the Taylor series up to the `x^12` term.
+    pub(crate) fn cos_polynomial(self) -> Self {
+        // Reduce to a fraction of a turn in `[0, 0.5]` using `cos(-x) = cos(x)`
+        let mut t = self * (FRAC_1_PI / 2.0);
+        t = (t - (t + 0.5).floor()).abs();
+
+        // Fold `[0.25, 0.5]` onto `[0, 0.25]` using `cos(π - x) = -cos(x)`
+        let sign = if t > 0.25 {
+            t = 0.5 - t;
+            -1.0
+        } else {
+            1.0
+        };
+
+        let x = t * TAU;
+        let x2 = x * x;
+
+        Self(sign * poly::horner(x2, &COS_TAYLOR_SERIES).0)
     }
 }
 
 #[cfg(test)]
 pub(crate) mod tests {
     use super::F32;
+    use crate::precision::{Balanced, Fast, Precise, Precision};
+    use core::f32::consts::TAU;
 
     /// Maximum error in radians
     pub(crate) const MAX_ERROR: f32 = 0.002;
 
     /// Cosine test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
     const TEST_VECTORS: &[(f32, f32)] = &[
         (0.000, 1.000),
         (0.140, 0.990),
@@ -305,4 +390,51 @@ pub(crate) mod tests {
             );
         }
     }
+
+    #[test]
+    fn large_inputs() {
+        let mut x = 10.0_f32;
+
+        while x < 1.0e9 {
+            for &x in &[x, -x] {
+                let cos_x = F32(x).cos();
+                let expected = f64::from(x).cos() as f32;
+                let delta = (cos_x - expected).abs();
+
+                assert!(
+                    delta <= MAX_ERROR,
+                    "delta {} too large for input {}: {} vs {}",
+                    delta,
+                    x,
+                    cos_x,
+                    expected
+                );
+            }
+
+            x *= 1.01;
+        }
+
+        assert!(F32::INFINITY.cos().is_nan());
+    }
+
+    fn precision_tier<P: Precision>() {
+        for i in -10_000..=10_000 {
+            let x = i as f32 * (TAU / 10_000.0);
+            let delta = (F32(x).cos_with::<P>() - x.cos()).abs();
+
+            assert!(
+                delta <= P::COS_MAX_ERROR,
+                "delta {} too large for input {}",
+                delta,
+                x
+            );
+        }
+    }
+
+    #[test]
+    fn precision_tiers() {
+        precision_tier::<Fast>();
+        precision_tier::<Balanced>();
+        precision_tier::<Precise>();
+    }
 }
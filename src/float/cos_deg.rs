@@ -0,0 +1,81 @@
+//! Cosine approximation for a single-precision float, where the input is in
+//! degrees.
+
+use super::F32;
+
+impl F32 {
+    /// Approximates `cos(x)` where `x` is in degrees with a maximum error of
+    /// `0.002`.
+    ///
+    /// The argument is reduced to `(-360, 360)` degrees exactly, so there's no
+    /// precision lost for large inputs.
+    pub fn cos_deg(self) -> Self {
+        (self.reduce_degrees() / 360.0).cos_turns()
+    }
+
+    /// Reduces an angle in degrees to the equivalent angle in `(-360, 360)`
+    /// with the same sign. This is exact for all finite inputs.
+    ///
+    /// Returns [`Self::NAN`] if `self` is infinite or `NaN`.
+    pub(crate) fn reduce_degrees(self) -> Self {
+        Self(self.0 % 360.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+    use crate::float::cos::tests::MAX_ERROR;
+
+    /// Cosine test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (0.0, 1.0),
+        (30.0, 0.866),
+        (45.0, 0.707),
+        (60.0, 0.5),
+        (90.0, 0.0),
+        (135.0, -0.707),
+        (180.0, -1.0),
+        (-90.0, 0.0),
+        (270.0, 0.0),
+        (360.0, 1.0),
+        (-480.0, -0.5),
+        (1_000_170.0, 0.0),
+        (3.6e12, 0.174),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let cos_x = F32(x).cos_deg();
+            let delta = (cos_x - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large: {} vs {}",
+                delta,
+                cos_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn sweep() {
+        for i in -100_000..=100_000 {
+            let x = i as f32 / 10.0;
+            let expected = f64::from(x).to_radians().cos() as f32;
+            let delta = (F32(x).cos_deg() - expected).abs();
+
+            assert!(delta <= MAX_ERROR, "delta {} too large for {}", delta, x);
+        }
+    }
+
+    #[test]
+    fn reduce_degrees() {
+        assert_eq!(F32(725.5).reduce_degrees(), F32(5.5));
+        assert_eq!(F32(-370.0).reduce_degrees(), F32(-10.0));
+        assert!(F32::INFINITY.reduce_degrees().is_nan());
+        assert!(F32::NAN.cos_deg().is_nan());
+    }
+}
@@ -0,0 +1,92 @@
+//! Cosine approximation for a single-precision float, where the input is in
+//! turns (`1.0` is a full circle).
+
+use super::F32;
+
+/// Smallest magnitude at which every `f32` is an integer, i.e. a whole
+/// number of turns.
+const INTEGER_THRESHOLD: f32 = 8_388_608.0;
+
+impl F32 {
+    /// Approximates `cos(x)` where `x` is in turns (`1.0` is a full circle)
+    /// with a maximum error of `0.002`.
+    ///
+    /// The argument is reduced to `[-0.5, 0.5]` turns exactly, so there's no
+    /// precision lost for large inputs.
+    pub fn cos_turns(self) -> Self {
+        let x = self.reduce_turns().cos_parabolic_turns();
+        x + 0.225 * x * (x.abs() - 1.0)
+    }
+
+    /// Reduces an angle in turns to the equivalent angle in `[-0.5, 0.5]`.
+    /// This is exact for all finite inputs.
+    ///
+    /// Returns [`Self::NAN`] if `self` is infinite or `NaN`.
+    pub(crate) fn reduce_turns(self) -> Self {
+        if !self.is_finite() {
+            Self::NAN
+        } else if self.abs().0 >= INTEGER_THRESHOLD {
+            Self::ZERO
+        } else {
+            self - self.round()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+    use crate::float::cos::tests::MAX_ERROR;
+    use core::f64::consts::TAU;
+
+    /// Cosine test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (0.0, 1.0),
+        (0.125, 0.707),
+        (0.25, 0.0),
+        (0.375, -0.707),
+        (0.5, -1.0),
+        (-0.25, 0.0),
+        (1.0, 1.0),
+        (1.1, 0.809),
+        (-2.6, -0.809),
+        (1_000_000.25, 0.0),
+        (1.0e10, 1.0),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let cos_x = F32(x).cos_turns();
+            let delta = (cos_x - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large: {} vs {}",
+                delta,
+                cos_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn sweep() {
+        for i in -100_000..=100_000 {
+            let x = i as f32 / 1_000.0;
+            let expected = (f64::from(x) * TAU).cos() as f32;
+            let delta = (F32(x).cos_turns() - expected).abs();
+
+            assert!(delta <= MAX_ERROR, "delta {} too large for {}", delta, x);
+        }
+    }
+
+    #[test]
+    fn reduce_turns() {
+        assert_eq!(F32(2.75).reduce_turns(), F32(-0.25));
+        assert_eq!(F32(-1_000_000.25).reduce_turns(), F32(-0.25));
+        assert_eq!(F32(1.0e9).reduce_turns(), F32(0.0));
+        assert!(F32::INFINITY.reduce_turns().is_nan());
+        assert!(F32::NAN.cos_turns().is_nan());
+    }
+}
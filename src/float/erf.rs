@@ -0,0 +1,87 @@
+//! Error function approximation for a single-precision float.
+//!
+//! Uses the Taylor series for small inputs and [`F32::erfc`] otherwise.
+
+use super::F32;
+use crate::poly;
+use core::f32::consts::FRAC_2_SQRT_PI;
+
+/// Coefficients of the Taylor series of `erf(x) * √π/2` in terms of `x²`,
+/// i.e. `(-1)^n / (n! * (2n + 1))`.
+const ERF_TAYLOR_SERIES: [f32; 6] = [
+    1.0,
+    -1.0 / 3.0,
+    1.0 / 10.0,
+    -1.0 / 42.0,
+    1.0 / 216.0,
+    -1.0 / 1_320.0,
+];
+
+impl F32 {
+    /// Approximates the error function `erf(x)` with a maximum error of `8e-7`,
+    /// see [`accuracy::ERF`].
+    ///
+    /// The CDF of the standard normal distribution can be computed as
+    /// `0.5 * (1 + erf(x / √2))`.
+    ///
+    /// [`accuracy::ERF`]: crate::accuracy::ERF
+    pub fn erf(self) -> Self {
+        if self.abs() < 0.5 {
+            let series = poly::horner(self * self, &ERF_TAYLOR_SERIES);
+            self * FRAC_2_SQRT_PI * series
+        } else {
+            (Self::ONE - self.abs().erfc()).copysign(self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum error
+    const MAX_ERROR: f32 = 2e-6;
+
+    /// Error function test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (-2.0, -0.995_322_3),
+        (-0.5, -0.520_499_9),
+        (-0.1, -0.112_462_92),
+        (0.0, 0.0),
+        (0.001, 0.001_128_378_8),
+        (0.3, 0.328_626_76),
+        (0.5, 0.520_499_9),
+        (0.75, 0.711_155_6),
+        (1.0, 0.842_700_8),
+        (1.5, 0.966_105_15),
+        (2.5, 0.999_593_1),
+        (4.0, 1.0),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let erf_x = F32(x).erf();
+            let delta = (erf_x - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large: {} vs {}",
+                delta,
+                erf_x,
+                expected
+            );
+        }
+
+        assert!(F32::NAN.erf().is_nan());
+        assert_eq!(F32::INFINITY.erf(), F32::ONE);
+        assert_eq!(F32::NEG_INFINITY.erf(), -F32::ONE);
+    }
+
+    #[test]
+    fn small_inputs() {
+        // Relative error is preserved close to zero
+        let x = F32(1.0e-20);
+        assert_eq!(x.erf(), x * core::f32::consts::FRAC_2_SQRT_PI);
+    }
+}
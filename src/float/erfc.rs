@@ -0,0 +1,96 @@
+//! Complementary error function approximation for a single-precision float.
+//!
+//! Uses the form `erfc(x) = t * exp(-x² + P(t))` where `t = 1 / (1 + x/2)`
+//! and `P` is a polynomial fitted over `[0, 9]`, which has a small relative
+//! error even in the tail of the function.
+
+use super::F32;
+use crate::{poly, precision::Precise};
+
+/// Coefficients of `P(t)`, lowest order first.
+const ERFC_POLYNOMIAL: [f32; 8] = [
+    -1.265_453,
+    0.998_187_4,
+    0.396_614_77,
+    -0.048_867_69,
+    0.367_772_2,
+    -1.020_819,
+    0.763_124_1,
+    -0.190_559_56,
+];
+
+/// Inputs above this value underflow to zero.
+const UNDERFLOW_THRESHOLD: f32 = 9.2;
+
+impl F32 {
+    /// Approximates the complementary error function `erfc(x) = 1 - erf(x)`
+    /// with a maximum relative error of `2e-5`.
+    ///
+    /// Unlike computing `1 - erf(x)`, this is accurate for large `x`.
+    pub fn erfc(self) -> Self {
+        if self.is_nan() {
+            return Self::NAN;
+        }
+
+        let x = self.abs();
+
+        let result = if x > UNDERFLOW_THRESHOLD {
+            Self::ZERO
+        } else {
+            let t = Self::ONE / (0.5 * x + 1.0);
+            let p = poly::horner(t, &ERFC_POLYNOMIAL);
+
+            t * (p - x * x).exp_with::<Precise>()
+        };
+
+        if self.is_sign_negative() {
+            2.0 - result
+        } else {
+            result
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum relative error
+    const MAX_ERROR: f32 = 2e-5;
+
+    /// Complementary error function test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (-3.0, 1.999_978),
+        (-1.0, 1.842_700_8),
+        (-0.25, 1.276_326_4),
+        (0.0, 1.0),
+        (0.1, 0.887_537_1),
+        (0.5, 0.479_500_1),
+        (1.0, 0.157_299_2),
+        (2.0, 4.677_735e-3),
+        (3.0, 2.209_05e-5),
+        (5.0, 1.537_46e-12),
+        (9.0, 4.137_031_7e-37),
+        (10.0, 0.0),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let erfc_x = F32(x).erfc();
+            let relative_error = ((erfc_x - expected) / expected).abs();
+
+            assert!(
+                expected == 0.0 && erfc_x == 0.0 || relative_error <= MAX_ERROR,
+                "relative error {} too large: {} vs {}",
+                relative_error,
+                erfc_x,
+                expected
+            );
+        }
+
+        assert!(F32::NAN.erfc().is_nan());
+        assert_eq!(F32::INFINITY.erfc(), F32::ZERO);
+        assert_eq!(F32::NEG_INFINITY.erfc(), F32(2.0));
+    }
+}
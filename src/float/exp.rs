@@ -3,54 +3,105 @@
 //! Method described at: <https://stackoverflow.com/a/6985769/2036035>
 
 use super::{EXPONENT_BIAS, F32};
+use crate::{
+    poly,
+    precision::{Precise, Precision},
+};
 use core::f32::consts;
 
+/// Coefficients of the Taylor series of `e^x`, i.e. `1 / n!`, up to the
+/// largest [`Precision::EXP_SERIES_TERMS`].
+pub(crate) const EXP_TAYLOR_SERIES: [f32; 8] = [
+    1.0,
+    1.0,
+    1.0 / 2.0,
+    1.0 / 6.0,
+    1.0 / 24.0,
+    1.0 / 120.0,
+    1.0 / 720.0,
+    1.0 / 5_040.0,
+];
+
+/// Arguments of [`F32::exp2_approx`] beyond `±EXP2_LIMIT` always saturate to
+/// zero or infinity.
+const EXP2_LIMIT: f32 = 256.0;
+
+// Every tier's series must fit in the table, and `Precise` has the longest
+const _: () = assert!(
+    (Precise::EXP_SERIES_TERMS as usize) < EXP_TAYLOR_SERIES.len(),
+    "EXP_TAYLOR_SERIES is shorter than Precise::EXP_SERIES_TERMS"
+);
+
 impl F32 {
-    /// Returns `e^(self)`, (the exponential function).
+    /// Returns `e^(self)`, (the exponential function), with a maximum relative
+    /// error of `0.0024` (see [`accuracy::EXP`]).
+    ///
+    /// [`accuracy::EXP`]: crate::accuracy::EXP
     #[inline]
     pub fn exp(self) -> Self {
         self.exp_ln2_approx(4)
     }
 
+    /// Returns `e^(self)` using the given [`Precision`] tier, which selects the
+    /// number of series terms ([`Precision::EXP_SERIES_TERMS`]).
+    ///
+    /// See [`Precision::EXP_MAX_ERROR`] for the maximum error of each tier.
+    #[inline]
+    pub fn exp_with<P: Precision>(self) -> Self {
+        self.exp_ln2_approx(P::EXP_SERIES_TERMS)
+    }
+
     /// Exp approximation for `f32`.
+    ///
+    /// Branch-free, so that loops over it can be vectorized.
+    #[inline]
     pub(crate) fn exp_ln2_approx(self, partial_iter: u32) -> Self {
-        if self == Self::ZERO {
-            return Self::ONE;
-        }
-
-        if (self - Self::ONE).abs() < f32::EPSILON {
-            return consts::E.into();
-        }
-
-        if (self - (-Self::ONE)).abs() < f32::EPSILON {
-            return Self::ONE / consts::E;
-        }
-
         // log base 2(E) == 1/ln(2)
         // x_fract + x_whole = x/ln2_recip
         // ln2*(x_fract + x_whole) = x
-        let x_ln2recip = self * consts::LOG2_E;
-        let x_fract = x_ln2recip.fract();
-        let x_trunc = x_ln2recip.trunc();
+        let result = (self * consts::LOG2_E).exp2_approx(partial_iter);
+
+        // Return the exact values at `±1`
+        let result = Self::select(
+            (self - Self::ONE).abs() < f32::EPSILON,
+            consts::E.into(),
+            result,
+        );
+
+        Self::select(
+            (self - (-Self::ONE)).abs() < f32::EPSILON,
+            Self::ONE / consts::E,
+            result,
+        )
+    }
+
+    /// `2^x` approximation for `f32`.
+    ///
+    /// Splits `x` into its whole and fractional parts, approximates
+    /// `2^x_fract` with [`F32::exp_smallx`], and adds `x_whole` to the exponent.
+    ///
+    /// Branch-free, so that loops over it can be vectorized.
+    #[inline]
+    pub(crate) fn exp2_approx(self, partial_iter: u32) -> Self {
+        // Clamping doesn't change the saturated results, and keeps the whole
+        // part within `i32`
+        let x = self.0.clamp(-EXP2_LIMIT, EXP2_LIMIT);
+
+        let x_trunc = x as i32;
+        let x_fract = Self(x - x_trunc as f32);
 
         //guaranteed to be 0 < x < 1.0
         let x_fract = x_fract * consts::LN_2;
         let fract_exp = x_fract.exp_smallx(partial_iter);
 
         //need the 2^n portion, we can just extract that from the whole number exp portion
-        let fract_exponent: i32 = fract_exp
-            .extract_exponent_value()
-            .saturating_add(x_trunc.0 as i32);
-
-        if fract_exponent < -(EXPONENT_BIAS as i32) {
-            return Self::ZERO;
-        }
-
-        if fract_exponent > ((EXPONENT_BIAS + 1) as i32) {
-            return Self::INFINITY;
-        }
+        let fract_exponent: i32 = fract_exp.extract_exponent_value() + x_trunc;
+        let max_exponent = EXPONENT_BIAS as i32;
 
-        fract_exp.set_exponent(fract_exponent)
+        let result = fract_exp.set_exponent(fract_exponent.clamp(-max_exponent, max_exponent));
+        let result = Self::select(fract_exponent < -max_exponent, Self::ZERO, result);
+        let result = Self::select(fract_exponent > max_exponent, Self::INFINITY, result);
+        Self::select(self.is_nan(), self, result)
     }
 
     /// if x is between 0.0 and 1.0, we can approximate it with the a series
@@ -61,19 +112,14 @@ impl F32 {
     /// e^x ~= 1 + x(1 + x/2(1 + (x?
     #[inline]
     pub(crate) fn exp_smallx(self, iter: u32) -> Self {
-        let mut total = 1.0;
-
-        for i in (1..=iter).rev() {
-            total = 1.0 + ((self.0 / (i as f32)) * total);
-        }
-
-        Self(total)
+        poly::horner(self, &EXP_TAYLOR_SERIES[..=iter as usize])
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::F32;
+    use crate::precision::{Balanced, Fast, Precise, Precision};
 
     pub(crate) const MAX_ERROR: f32 = 0.001;
 
@@ -103,6 +149,8 @@ mod tests {
     #[test]
     fn sanity_check() {
         assert_eq!(F32(-1000000.0).exp(), F32::ZERO);
+        assert!(F32::NAN.exp().is_nan());
+        assert!((-F32::NAN).exp().is_nan());
 
         for &(x, expected) in TEST_VECTORS {
             let exp_x = F32(x).exp();
@@ -118,4 +166,26 @@ mod tests {
             );
         }
     }
+
+    fn precision_tier<P: Precision>() {
+        for i in -8_000..=8_000 {
+            let x = i as f32 / 100.0;
+            let expected = x.exp();
+            let relative_error = ((F32(x).exp_with::<P>() - expected) / expected).abs();
+
+            assert!(
+                relative_error <= P::EXP_MAX_ERROR,
+                "relative_error {} too large for input {}",
+                relative_error,
+                x
+            );
+        }
+    }
+
+    #[test]
+    fn precision_tiers() {
+        precision_tier::<Fast>();
+        precision_tier::<Balanced>();
+        precision_tier::<Precise>();
+    }
 }
@@ -0,0 +1,72 @@
+//! `2^x` approximation for a single-precision float.
+
+use super::F32;
+
+impl F32 {
+    /// Returns `2^(self)` with a maximum relative error of `0.0024`, see
+    /// [`accuracy::EXP2`].
+    ///
+    /// Exact for integer values of `self`.
+    ///
+    /// [`accuracy::EXP2`]: crate::accuracy::EXP2
+    #[inline]
+    pub fn exp2(self) -> Self {
+        self.exp2_approx(4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum relative error
+    const MAX_ERROR: f32 = 0.0025;
+
+    /// `2^x` test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (-1.0e2, 7.888609e-31),
+        (-10.5, 0.00069053395),
+        (-3.0, 0.125),
+        (-1.0, 0.5),
+        (-0.5, 0.70710677),
+        (-0.1, 0.933033),
+        (0.1, 1.0717734),
+        (0.5, 1.4142135),
+        (1.0, 2.0),
+        (1.5, 2.828427),
+        (3.0, 8.0),
+        (10.5, 1448.1547),
+        (1.0e2, 1.2676506e30),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let exp2_x = F32(x).exp2();
+            let relative_error = ((exp2_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                exp2_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn integers() {
+        for n in -126..=127 {
+            assert_eq!(F32(n as f32).exp2(), F32(2.0).powi(n));
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(F32(128.0).exp2(), F32::INFINITY);
+        assert_eq!(F32(-1000.0).exp2(), F32::ZERO);
+    }
+}
@@ -0,0 +1,73 @@
+//! `e^x - 1` approximation for a single-precision float.
+
+use super::{exp::EXP_TAYLOR_SERIES, F32};
+use crate::{
+    poly,
+    precision::{Precise, Precision},
+};
+
+impl F32 {
+    /// Approximates `e^x - 1` more accurately than `x.exp() - 1.0` when `x` is
+    /// close to zero, with a maximum relative error of `4.6e-6` (see
+    /// [`accuracy::EXP_M1`]).
+    ///
+    /// [`accuracy::EXP_M1`]: crate::accuracy::EXP_M1
+    pub fn exp_m1(self) -> Self {
+        if self.abs() < 0.5 {
+            // Taylor series of `e^x - 1`, which has no constant term to cancel
+            self * poly::horner(self, &EXP_TAYLOR_SERIES[1..])
+        } else {
+            self.exp_ln2_approx(Precise::EXP_SERIES_TERMS) - Self::ONE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum relative error
+    const MAX_ERROR: f32 = 0.0001;
+
+    /// `e^x - 1` test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (-1.0e1, -0.9999546),
+        (-1.0, -0.63212055),
+        (-0.5, -0.39346933),
+        (-0.1, -0.095162585),
+        (-0.01, -0.009950167),
+        (-0.001, -0.0009995002),
+        (-1.0e-5, -9.99995e-6),
+        (-1.0e-7, -9.9999994e-8),
+        (1.0e-7, 1.0000001e-7),
+        (1.0e-5, 1.000005e-5),
+        (0.001, 0.0010005002),
+        (0.01, 0.010050167),
+        (0.1, 0.10517092),
+        (0.5, 0.6487213),
+        (1.0, 1.7182819),
+        (1.0e1, 22025.465),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let exp_m1_x = F32(x).exp_m1();
+            let relative_error = ((exp_m1_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                exp_m1_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(F32::ZERO.exp_m1(), F32::ZERO);
+    }
+}
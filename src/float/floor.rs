@@ -4,7 +4,7 @@ use super::F32;
 
 impl F32 {
     /// Returns the largest integer less than or equal to a number.
-    pub fn floor(self) -> Self {
+    pub const fn floor(self) -> Self {
         let mut res = (self.0 as i32) as f32;
 
         if self.0 < res {
@@ -0,0 +1,63 @@
+//! Split a single-precision float into a normalized fraction and exponent.
+
+use super::{EXPONENT_BIAS, EXPONENT_MASK, F32, MANTISSA_BITS};
+
+impl F32 {
+    /// Splits `self` into a fraction `m` and an exponent `e` such that
+    /// `self = m * 2^e`, where `0.5 <= |m| < 1`. Returns `(m, e)`.
+    ///
+    /// Zero, infinity and NaN are returned unchanged with an exponent of `0`.
+    pub const fn frexp(self) -> (Self, i32) {
+        if self.0 == 0.0 || !self.is_finite() {
+            return (self, 0);
+        }
+
+        // Normalize subnormals so the exponent bits are non-zero
+        let (x, offset) = if self.extract_exponent_bits() == 0 {
+            (Self(self.0 * (1u32 << 25) as f32), -25)
+        } else {
+            (self, 0)
+        };
+
+        let exponent = x.extract_exponent_bits() as i32 - (EXPONENT_BIAS as i32 - 1);
+        let fraction_bits = (x.to_bits() & !EXPONENT_MASK) | ((EXPONENT_BIAS - 1) << MANTISSA_BITS);
+
+        (Self::from_bits(fraction_bits), exponent + offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// `frexp` test vectors - `(input, (fraction, exponent))`
+    const TEST_VECTORS: &[(f32, (f32, i32))] = &[
+        (1.0, (0.5, 1)),
+        (-1.0, (-0.5, 1)),
+        (0.5, (0.5, 0)),
+        (3.0, (0.75, 2)),
+        (-10.0, (-0.625, 4)),
+        (0.1, (0.8, -3)),
+        (f32::MAX, (0.999_999_94, 128)),
+        (f32::MIN_POSITIVE, (0.5, -125)),
+        (1e-40, (0.544_448_85, -132)),
+        (-1e-45, (-0.5, -148)),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, (fraction, exponent)) in TEST_VECTORS {
+            let (m, e) = F32(x).frexp();
+            assert_eq!((m.0, e), (fraction, exponent), "frexp({})", x);
+            assert_eq!(m.ldexp(e).0, x);
+        }
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(F32(0.0).frexp(), (F32(0.0), 0));
+        assert!(F32(-0.0).frexp().0.is_sign_negative());
+        assert_eq!(F32::INFINITY.frexp(), (F32::INFINITY, 0));
+        assert!(F32::NAN.frexp().0.is_nan());
+    }
+}
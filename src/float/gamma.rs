@@ -0,0 +1,109 @@
+//! Gamma function approximation for a single-precision float.
+//!
+//! Computed as `exp(ln(Γ(x)))` using [`F32::ln_gamma`], except for small
+//! positive integers where `Γ(n) = (n - 1)!` is computed directly.
+
+use super::F32;
+use crate::precision::Precise;
+use core::f32::consts::PI;
+
+/// Largest argument for which `Γ(x)` is finite.
+const OVERFLOW_THRESHOLD: f32 = 35.040_09;
+
+impl F32 {
+    /// Approximates the gamma function `Γ(x)`, which extends the factorial
+    /// function to non-integers as `Γ(n + 1) = n!`, with a maximum relative
+    /// error of `2e-5` for positive `x` (see [`accuracy::GAMMA`]).
+    ///
+    /// Returns [`Self::NAN`] for zero and negative integers, and
+    /// [`Self::INFINITY`] if the result is too large to represent.
+    ///
+    /// [`accuracy::GAMMA`]: crate::accuracy::GAMMA
+    pub fn gamma(self) -> Self {
+        if self.is_nan() || (self <= Self::ZERO && self.trunc() == self) {
+            Self::NAN
+        } else if self > OVERFLOW_THRESHOLD {
+            Self::INFINITY
+        } else if self < Self::ZERO {
+            // Reflection: Γ(x) = π / (sin(πx) * Γ(1 - x))
+            PI / (self.sin_pi() * (Self::ONE - self).gamma())
+        } else if self.trunc() == self {
+            // Factorial: the product is exact up to `13!`
+            let mut result = Self::ONE;
+            let mut n = Self(2.0);
+
+            while n < self {
+                result *= n;
+                n += 1.0;
+            }
+
+            result
+        } else {
+            let (x, product) = self.shift_to_stirling_range();
+            x.ln_gamma_stirling().exp_with::<Precise>() / product
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum relative error
+    const MAX_ERROR: f32 = 2e-5;
+
+    /// Gamma test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (0.001, 999.423_77),
+        (0.1, 9.513_507),
+        (0.5, 1.772_453_9),
+        (1.0, 1.0),
+        (1.5, 0.886_226_9),
+        (2.0, 1.0),
+        (4.0, 6.0),
+        (4.5, 11.631_728),
+        (7.9, 4_122.709_5),
+        (10.0, 362_880.0),
+        (14.0, 6_227_020_800.0),
+        (20.5, 5.406_243e17),
+        (35.0, 2.952_328e38),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let actual = F32(x).gamma();
+            let relative_error = ((actual - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative error {} too large: {} vs {}",
+                relative_error,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn factorial() {
+        let mut factorial = 1.0;
+
+        for n in 1..=13 {
+            factorial *= n as f32;
+            assert_eq!(F32(n as f32 + 1.0).gamma(), F32(factorial));
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        // Γ(-0.5) = -2√π
+        let delta = (F32(-0.5).gamma() - (-3.544_907_7)).abs();
+        assert!(delta <= 0.01, "delta {} too large", delta);
+
+        assert_eq!(F32(36.0).gamma(), F32::INFINITY);
+        assert!(F32(0.0).gamma().is_nan());
+        assert!(F32(-2.0).gamma().is_nan());
+        assert!(F32::NAN.gamma().is_nan());
+    }
+}
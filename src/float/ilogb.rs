@@ -0,0 +1,62 @@
+//! Extract the exponent of a single-precision float.
+
+use super::{F32, MANTISSA_BITS};
+
+impl F32 {
+    /// Returns the unbiased exponent of `self`, i.e. `floor(log2(|x|))`, as an
+    /// integer. Subnormals are treated as if they were normalized.
+    ///
+    /// Returns [`i32::MIN`] for zero and NaN, and [`i32::MAX`] for infinity.
+    pub const fn ilogb(self) -> i32 {
+        if self.is_nan() || self.0 == 0.0 {
+            return i32::MIN;
+        }
+
+        if self.is_infinite() {
+            return i32::MAX;
+        }
+
+        if self.extract_exponent_bits() == 0 {
+            // Subnormal: count the leading zeros of the mantissa
+            let mantissa = self.to_bits() << (32 - MANTISSA_BITS);
+            return Self::MIN_EXP - 2 - mantissa.leading_zeros() as i32;
+        }
+
+        self.extract_exponent_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// `ilogb` test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, i32)] = &[
+        (1.0, 0),
+        (1.999, 0),
+        (2.0, 1),
+        (-10.0, 3),
+        (0.1, -4),
+        (f32::MAX, 127),
+        (f32::MIN_POSITIVE, -126),
+        (5.877_472e-39, -127),
+        (1e-40, -133),
+        (-1e-45, -149),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            assert_eq!(F32(x).ilogb(), expected, "ilogb({})", x);
+        }
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(F32(0.0).ilogb(), i32::MIN);
+        assert_eq!(F32(-0.0).ilogb(), i32::MIN);
+        assert_eq!(F32::NAN.ilogb(), i32::MIN);
+        assert_eq!(F32::INFINITY.ilogb(), i32::MAX);
+        assert_eq!(F32::NEG_INFINITY.ilogb(), i32::MAX);
+    }
+}
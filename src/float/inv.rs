@@ -9,7 +9,7 @@ impl F32 {
     ///
     /// Assumes that the underlying data is in IEEE 754 format.
     #[doc(alias = "recip")]
-    pub fn inv(self) -> Self {
+    pub const fn inv(self) -> Self {
         // Perform the bit manipulation for the approximation
         // The constant 0x7f00_0000 corresponds to the bit pattern for 1.0 in IEEE 754 format.
         // Subtracting the bits of the original number from this constant effectively inverts the exponent,
@@ -0,0 +1,130 @@
+//! Inverse error function approximation for a single-precision float.
+//!
+//! Uses the polynomial approximations in `w = -ln(1 - x²)` from Mike Giles,
+//! "Approximating the erfinv function", refined with a step of Newton's
+//! method to correct for the error of [`F32::ln`].
+//!
+//! Method described at: <https://people.maths.ox.ac.uk/gilesm/files/gems_erfinv.pdf>
+
+use super::F32;
+use crate::{poly, precision::Precise};
+use core::f32::consts::FRAC_2_SQRT_PI;
+
+/// Coefficients of the central approximation in `w - 2.5`, lowest order
+/// first, for `w < 5`.
+const CENTRAL_POLYNOMIAL: [f32; 9] = [
+    1.501_409_4,
+    0.246_640_73,
+    -0.004_177_681_6,
+    -0.001_253_725,
+    0.000_218_580_87,
+    -4.391_506_5e-6,
+    -3.523_387_7e-6,
+    3.432_739_4e-7,
+    2.810_226_4e-8,
+];
+
+/// Coefficients of the tail approximation in `√w - 3`, lowest order first,
+/// for `w >= 5`.
+const TAIL_POLYNOMIAL: [f32; 9] = [
+    2.832_976_8,
+    1.001_674,
+    0.009_438_870_5,
+    -0.007_622_461,
+    0.005_739_507_7,
+    -0.003_673_428_4,
+    0.001_349_343_2,
+    0.000_100_950_56,
+    -0.000_200_214_26,
+];
+
+impl F32 {
+    /// Approximates the inverse error function, i.e. the `y` for which
+    /// `erf(y) = x`, with a maximum relative error of `2e-6`.
+    ///
+    /// Returns [`Self::INFINITY`] or [`Self::NEG_INFINITY`] for `1.0` and
+    /// `-1.0` respectively, and [`Self::NAN`] for inputs outside of `[-1, 1]`.
+    pub fn inverse_erf(self) -> Self {
+        let x = self.abs();
+
+        if x.is_nan() || x > Self::ONE {
+            return Self::NAN;
+        } else if x == Self::ONE {
+            return Self::INFINITY.copysign(self);
+        }
+
+        let w = -((Self::ONE - x) * (Self::ONE + x)).ln();
+        let (polynomial, w) = if w < 5.0 {
+            (&CENTRAL_POLYNOMIAL, w - 2.5)
+        } else {
+            (&TAIL_POLYNOMIAL, w.sqrt_with::<Precise>() - 3.0)
+        };
+
+        let mut y = x * poly::horner(w, polynomial);
+
+        // Newton step, computing the residual with `erfc` for large `x` where
+        // `erf(y)` would lose precision
+        let residual = if x < 0.5 {
+            y.erf() - x
+        } else {
+            (Self::ONE - x) - y.erfc()
+        };
+
+        y -= residual / (FRAC_2_SQRT_PI * (-y * y).exp_with::<Precise>());
+        y.copysign(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum relative error
+    const MAX_ERROR: f32 = 2e-6;
+
+    /// Inverse error function test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (-0.999, -2.326_756_3),
+        (-0.5, -0.476_936_28),
+        (0.001, 0.000_886_227_2),
+        (0.1, 0.088_855_99),
+        (0.3, 0.272_462_73),
+        (0.5, 0.476_936_28),
+        (0.7, 0.732_869_1),
+        (0.9, 1.163_087_2),
+        (0.99, 1.821_386_6),
+        (0.999_9, 2.751_035_4),
+        (0.999_999_94, 3.832_507),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let actual = F32(x).inverse_erf();
+            let relative_error = ((actual - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative error {} too large: {} vs {}",
+                relative_error,
+                actual,
+                expected
+            );
+        }
+
+        assert_eq!(F32(0.0).inverse_erf(), F32(0.0));
+        assert_eq!(F32(1.0).inverse_erf(), F32::INFINITY);
+        assert_eq!(F32(-1.0).inverse_erf(), F32::NEG_INFINITY);
+        assert!(F32(1.5).inverse_erf().is_nan());
+        assert!(F32::NAN.inverse_erf().is_nan());
+    }
+
+    #[test]
+    fn round_trip() {
+        for i in -999..=999 {
+            let x = i as f32 / 1_000.0;
+            let delta = (F32(x).inverse_erf().erf() - x).abs();
+            assert!(delta <= 4e-6, "delta {} too large for {}", delta, x);
+        }
+    }
+}
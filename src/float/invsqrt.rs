@@ -3,18 +3,42 @@
 //! Method described at: <https://bits.stephan-brumme.com/invSquareRoot.html>
 
 use super::F32;
+use crate::precision::Precision;
 
 impl F32 {
     /// Approximate inverse square root with an average deviation of ~5%.
-    pub fn invsqrt(self) -> Self {
+    ///
+    /// The maximum relative error is `0.035`, see [`accuracy::INVSQRT`].
+    ///
+    /// [`accuracy::INVSQRT`]: crate::accuracy::INVSQRT
+    pub const fn invsqrt(self) -> Self {
         Self::from_bits(0x5f37_5a86 - (self.to_bits() >> 1))
     }
+
+    /// Approximates inverse square root using the given [`Precision`] tier,
+    /// refining [`F32::invsqrt`] with [`Precision::NEWTON_ITERATIONS`] steps
+    /// of Newton's method.
+    ///
+    /// See [`Precision::INVSQRT_MAX_ERROR`] for the maximum error of each tier.
+    pub const fn invsqrt_with<P: Precision>(self) -> Self {
+        let mut y = self.invsqrt();
+
+        let mut i = 0;
+
+        while i < P::NEWTON_ITERATIONS {
+            y.0 *= 1.5 - 0.5 * self.0 * y.0 * y.0;
+            i += 1;
+        }
+
+        y
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::F32;
     use crate::float::sqrt::tests::TEST_VECTORS;
+    use crate::precision::{Balanced, Fast, Precise, Precision};
 
     /// Deviation from the actual value (5%)
     const MAX_ERROR: f32 = 0.05;
@@ -38,4 +62,29 @@ mod tests {
             );
         }
     }
+
+    fn precision_tier<P: Precision>() {
+        let mut x = 1e-30_f32;
+
+        while x < 1e30 {
+            let expected = 1.0 / x.sqrt();
+            let relative_error = ((F32(x).invsqrt_with::<P>() - expected) / expected).abs();
+
+            assert!(
+                relative_error <= P::INVSQRT_MAX_ERROR,
+                "relative_error {} too large for input {}",
+                relative_error,
+                x
+            );
+
+            x *= 1.001;
+        }
+    }
+
+    #[test]
+    fn precision_tiers() {
+        precision_tier::<Fast>();
+        precision_tier::<Balanced>();
+        precision_tier::<Precise>();
+    }
 }
@@ -0,0 +1,26 @@
+//! Multiply a single-precision float by an integer power of two.
+
+use super::F32;
+
+impl F32 {
+    /// Computes `self * 2^exp`, the inverse of [`F32::frexp`].
+    ///
+    /// This is identical to [`F32::scalbn`], since `f32` has a radix of `2`.
+    #[inline]
+    pub const fn ldexp(self, exp: i32) -> Self {
+        self.scalbn(exp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F32(0.75).ldexp(2), F32(3.0));
+        assert_eq!(F32(-0.625).ldexp(4), F32(-10.0));
+        assert_eq!(F32(0.5).ldexp(-149), F32(0.0));
+        assert_eq!(F32(0.5).ldexp(129), F32::INFINITY);
+    }
+}
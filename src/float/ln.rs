@@ -6,25 +6,57 @@
 //! Also got rid of most of the slow conversions. Should work for all positive values of x.
 
 use super::{EXPONENT_MASK, F32};
+use crate::poly;
 use core::f32::consts::LN_2;
 
+/// Coefficients of the polynomial approximating `ln(x)` on `[1, 2)`, generated
+/// from maple in the post using the [Remez algorithm].
+///
+/// [Remez algorithm]: https://en.wikipedia.org/wiki/Remez_algorithm
+// Note: excessive precision ignored because it hides the origin of the numbers used for the
+// ln(1.0->2.0) polynomial
+#[allow(clippy::excessive_precision)]
+const LN_POLYNOMIAL: [f32; 5] = [
+    -1.741_793_9,
+    2.821_202_6,
+    -1.469_956_8,
+    0.447_179_55,
+    -0.056_570_851,
+];
+
 impl F32 {
-    /// Approximates the natural logarithm of the number.
-    // Note: excessive precision ignored because it hides the origin of the numbers used for the
-    // ln(1.0->2.0) polynomial
-    #[allow(clippy::excessive_precision)]
+    /// Approximates the natural logarithm of the number with a maximum absolute
+    /// error of `7e-5`, see [`accuracy::LN`].
+    ///
+    /// [`accuracy::LN`]: crate::accuracy::LN
+    #[inline]
     pub fn ln(self) -> Self {
+        // Every path is computed and the result chosen with bit masks, so that
+        // loops over this function can be vectorized.
+        let result = self.ln_reduced();
+
+        // Near 1.0 the polynomial below loses most of its relative precision, but `x - 1` is
+        // exact in this range so we can use the `ln(1 + x)` series instead.
+        let result = Self::select(
+            (0.5..2.0).contains(&self.0),
+            (self - Self::ONE).ln_1p_series(),
+            result,
+        );
+
         // x may essentially be 1.0 but, as clippy notes, these kinds of
         // floating point comparisons can fail when the bit pattern is not the sames
-        if (self - Self::ONE).abs() < f32::EPSILON {
-            return Self::ZERO;
-        }
+        Self::select((self - Self::ONE).abs() < f32::EPSILON, Self::ZERO, result)
+    }
 
+    /// Approximates `ln(x)` by splitting `x` into a power of two and a value in
+    /// `[1, 2)`, which is accurate away from `1.0`.
+    #[inline]
+    fn ln_reduced(self) -> Self {
         let x_less_than_1 = self < 1.0;
 
         // Note: we could use the fast inverse approximation here found in super::inv::inv_approx, but
         // the precision of such an approximation is assumed not good enough.
-        let x_working = if x_less_than_1 { self.inv() } else { self };
+        let x_working = Self::select(x_less_than_1, Self::ONE / self, self);
 
         // according to the SO post ln(x) = ln((2^n)*y)= ln(2^n) + ln(y) = ln(2) * n + ln(y)
         // get exponent value
@@ -34,22 +66,12 @@ impl F32 {
         // supposedly normalizing between 1.0 and 2.0
         let x_working = x_working / divisor;
 
-        // approximate polynomial generated from maple in the post using Remez Algorithm:
-        // https://en.wikipedia.org/wiki/Remez_algorithm
-        let ln_1to2_polynomial = -1.741_793_9
-            + (2.821_202_6
-                + (-1.469_956_8 + (0.447_179_55 - 0.056_570_851 * x_working) * x_working)
-                    * x_working)
-                * x_working;
+        let ln_1to2_polynomial = poly::horner(x_working, &LN_POLYNOMIAL);
 
         // ln(2) * n + ln(y)
         let result = (base2_exponent as f32) * LN_2 + ln_1to2_polynomial;
 
-        if x_less_than_1 {
-            -result
-        } else {
-            result
-        }
+        Self::select(x_less_than_1, -result, result)
     }
 }
 
@@ -60,6 +82,7 @@ mod tests {
     pub(crate) const MAX_ERROR: f32 = 0.001;
 
     /// ln(x) test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
     pub(crate) const TEST_VECTORS: &[(f32, f32)] = &[
         (1e-20, -46.0517),
         (1e-19, -43.749115),
@@ -107,7 +130,7 @@ mod tests {
         assert_eq!(F32::ONE.ln(), F32::ZERO);
         for &(x, expected) in TEST_VECTORS {
             let ln_x = F32(x).ln();
-            let relative_error = (ln_x - expected).abs() / expected;
+            let relative_error = ((ln_x - expected) / expected).abs();
 
             assert!(
                 relative_error <= MAX_ERROR,
@@ -118,4 +141,37 @@ mod tests {
             );
         }
     }
+
+    /// ln(x) test vectors near `1.0` - `(input, output)`
+    #[allow(clippy::approx_constant)]
+    const TEST_VECTORS_NEAR_ONE: &[(f32, f32)] = &[
+        (0.5, -0.6931472),
+        (0.75, -0.2876821),
+        (0.9, -0.105360515),
+        (0.99, -0.010050336),
+        (0.999, -0.0010005003),
+        (1.001, 0.0009995003),
+        (1.01, 0.0099503305),
+        (1.1, 0.09531018),
+        (1.25, 0.22314355),
+        (1.5, 0.4054651),
+        (1.99, 0.6881346),
+    ];
+
+    #[test]
+    fn near_one() {
+        for &(x, expected) in TEST_VECTORS_NEAR_ONE {
+            let ln_x = F32(x).ln();
+            let relative_error = ((ln_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                ln_x,
+                expected
+            );
+        }
+    }
 }
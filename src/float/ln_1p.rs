@@ -0,0 +1,98 @@
+//! `ln(1 + x)` approximation for a single-precision float.
+
+use super::F32;
+use crate::poly;
+
+/// Coefficients of the series `atanh(s) / s` in terms of `s²`, i.e.
+/// `1 / (2n + 1)`.
+pub(crate) const ATANH_SERIES: [f32; 6] =
+    [1.0, 1.0 / 3.0, 1.0 / 5.0, 1.0 / 7.0, 1.0 / 9.0, 1.0 / 11.0];
+
+impl F32 {
+    /// Approximates `ln(1 + x)` more accurately than `(1 + x).ln()` when `x`
+    /// is close to zero, with a maximum relative error of `8.8e-5` (see
+    /// [`accuracy::LN_1P`]).
+    ///
+    /// Returns [`Self::NEG_INFINITY`] for `-1.0`, and [`Self::NAN`] if `self`
+    /// is less than `-1.0`.
+    ///
+    /// [`accuracy::LN_1P`]: crate::accuracy::LN_1P
+    pub fn ln_1p(self) -> Self {
+        if self.is_nan() || self < -Self::ONE {
+            Self::NAN
+        } else if self == -Self::ONE {
+            Self::NEG_INFINITY
+        } else if (-0.5..1.0).contains(&self.0) {
+            self.ln_1p_series()
+        } else {
+            (Self::ONE + self).ln()
+        }
+    }
+
+    /// Approximates `ln(1 + x)` for `x` in the range `[-0.5, 1.0)` using the
+    /// series `ln(1 + x) = 2 * atanh(s)` where `s = x / (2 + x)`.
+    pub(crate) fn ln_1p_series(self) -> Self {
+        let s = self / (2.0 + self);
+        2.0 * s * poly::horner(s * s, &ATANH_SERIES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum relative error
+    const MAX_ERROR: f32 = 0.0001;
+
+    /// `ln(1 + x)` test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (-0.999, -6.9077554),
+        (-0.9, -2.3025851),
+        (-0.5, -0.6931472),
+        (-0.1, -0.105360515),
+        (-0.01, -0.010050336),
+        (-0.001, -0.0010005003),
+        (-1.0e-5, -1.000005e-5),
+        (-1.0e-7, -1.0000001e-7),
+        (1.0e-7, 9.9999994e-8),
+        (1.0e-5, 9.99995e-6),
+        (0.001, 0.0009995003),
+        (0.01, 0.0099503305),
+        (0.1, 0.09531018),
+        (0.5, 0.4054651),
+        (1.0, 0.6931472),
+        (1.0e1, 2.3978953),
+        (1.0e3, 6.908755),
+        (1.0e10, 23.02585),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let ln_1p_x = F32(x).ln_1p();
+            let relative_error = ((ln_1p_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                ln_1p_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(F32::ZERO.ln_1p(), F32::ZERO);
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!((-F32::ONE).ln_1p(), F32::NEG_INFINITY);
+        assert!(F32(-2.0).ln_1p().is_nan());
+        assert!(F32::NAN.ln_1p().is_nan());
+    }
+}
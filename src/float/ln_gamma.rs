@@ -0,0 +1,198 @@
+//! Log-gamma function approximation for a single-precision float.
+//!
+//! Uses Stirling's series for large arguments, the recurrence
+//! `Γ(x + 1) = x * Γ(x)` to shift small arguments into that range, and the
+//! reflection formula `Γ(x) * Γ(1 - x) = π / sin(πx)` for negative arguments.
+//!
+//! Method described at: <https://en.wikipedia.org/wiki/Stirling%27s_approximation>
+
+use super::F32;
+use crate::{poly, precision::Precise};
+use core::f32::consts::{LN_2, PI, SQRT_2};
+
+/// Smallest argument for which Stirling's series is used directly.
+const STIRLING_THRESHOLD: f32 = 8.0;
+
+/// `ln(2π) / 2`
+const HALF_LN_2PI: f32 = 0.918_938_5;
+
+/// `2^24`, which scales every subnormal into the normal range.
+const TWO_POW_24: f32 = 16_777_216.0;
+
+/// `2^-24`, below which `ln(Γ(1 + x)) ≈ -γx` is smaller than the resolution
+/// of `ln(|Γ(x)|) ≈ -ln(|x|)`.
+const TWO_POW_NEG_24: f32 = 1.0 / TWO_POW_24;
+
+/// Coefficients of Stirling's series in terms of `1/x²`, i.e.
+/// `B(2n) / (2n * (2n - 1))`.
+const STIRLING_SERIES: [f32; 3] = [1.0 / 12.0, -1.0 / 360.0, 1.0 / 1_260.0];
+
+impl F32 {
+    /// Approximates the natural logarithm of the absolute value of the gamma
+    /// function, `ln(|Γ(x)|)`.
+    ///
+    /// For positive `x` the maximum error is `4e-6`, relative to the result
+    /// when it's larger than `1`. Negative `x` are less accurate close to the
+    /// poles at negative integers.
+    ///
+    /// Returns [`Self::INFINITY`] for zero and negative integers.
+    pub fn ln_gamma(self) -> Self {
+        if self.is_nan() {
+            Self::NAN
+        } else if self != Self::ZERO && self.abs() < TWO_POW_NEG_24 {
+            // `Γ(x) = Γ(x + 1) / x` where `Γ(x + 1)` rounds to `1`. The
+            // reflection below would overflow computing `π / sin(πx)` for
+            // subnormals, and the recurrence would take the logarithm of a
+            // subnormal product.
+            -self.abs().ln_accurate()
+        } else if self <= Self::ZERO {
+            if self.trunc() == self {
+                return Self::INFINITY;
+            }
+
+            // Reflection: ln|Γ(x)| = ln(π / |sin(πx)|) - ln|Γ(1 - x)|
+            (PI / (self.sin_pi()).abs()).ln() - (Self::ONE - self).ln_gamma()
+        } else {
+            let (x, product) = self.shift_to_stirling_range();
+            x.ln_gamma_stirling() - product.ln_accurate()
+        }
+    }
+
+    /// Shift `x` into the range where Stirling's series is accurate using the
+    /// recurrence `Γ(x + 1) = x * Γ(x)`.
+    ///
+    /// Returns the shifted `x` and the product `x * (x + 1) * ...` of the
+    /// values it was shifted by.
+    pub(crate) fn shift_to_stirling_range(self) -> (Self, Self) {
+        let mut x = self;
+        let mut product = Self::ONE;
+
+        while x < STIRLING_THRESHOLD {
+            product *= x;
+            x += 1.0;
+        }
+
+        (x, product)
+    }
+
+    /// Stirling's series for `ln(Γ(x))`, which is accurate for large `x`.
+    pub(crate) fn ln_gamma_stirling(self) -> Self {
+        let recip = Self::ONE / self;
+        let series = poly::horner(recip * recip, &STIRLING_SERIES);
+
+        (self - 0.5) * self.ln_accurate() - self + HALF_LN_2PI + recip * series
+    }
+
+    /// Computes `ln(x)` for positive, finite `x` by splitting off the exponent
+    /// and evaluating the `ln(1 + x)` series on the mantissa, which is more
+    /// accurate than [`F32::ln`] at the cost of a division.
+    pub(crate) fn ln_accurate(self) -> Self {
+        // Subnormals have no implicit leading bit, so rescale them first
+        let (x, offset) = if self.0.is_subnormal() {
+            (self * TWO_POW_24, -24)
+        } else {
+            (self, 0)
+        };
+
+        // Split into `m * 2^e` with `m` in `[√½, √2)`
+        let mut exponent = x.extract_exponent_value() + offset;
+        let mut mantissa = x.set_exponent(0);
+
+        if mantissa > SQRT_2 {
+            mantissa *= 0.5;
+            exponent += 1;
+        }
+
+        (mantissa - 1.0).ln_1p_series() + exponent as f32 * LN_2
+    }
+
+    /// Computes `sin(πx)` with the argument reduced to `[-1, 1]` first, so
+    /// it is exact at integers.
+    pub(crate) fn sin_pi(self) -> Self {
+        let x = self - 2.0 * (self * 0.5).round();
+        (x * PI).sin_with::<Precise>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum error, relative to the result when it's larger than `1`
+    const MAX_ERROR: f32 = 4e-6;
+
+    /// Log-gamma test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (0.001, 6.907_179),
+        (0.1, 2.252_713),
+        (0.5, 0.572_364_9),
+        (1.0, 0.0),
+        (1.5, -0.120_782_24),
+        (2.0, 0.0),
+        (3.0, core::f32::consts::LN_2),
+        (4.5, 2.453_736_6),
+        (7.9, 8.324_266),
+        (10.0, 12.801_827),
+        (25.5, 56.389_168),
+        (100.0, 359.134_2),
+        (1000.0, 5_905.220_5),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let actual = F32(x).ln_gamma();
+            let delta = (actual - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR * expected.abs().max(1.0),
+                "delta {} too large: {} vs {}",
+                delta,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn negative() {
+        // Γ(-0.5) = -2√π, Γ(-2.5) = -8√π/15
+        let delta = (F32(-0.5).ln_gamma() - 1.265_512_1).abs();
+        assert!(delta <= 0.002, "delta {} too large", delta);
+
+        let delta = (F32(-2.5).ln_gamma() - (-0.056_243_716)).abs();
+        assert!(delta <= 0.002, "delta {} too large", delta);
+
+        assert_eq!(F32(0.0).ln_gamma(), F32::INFINITY);
+        assert_eq!(F32(-0.0).ln_gamma(), F32::INFINITY);
+        assert_eq!(F32(-3.0).ln_gamma(), F32::INFINITY);
+        assert!(F32::NAN.ln_gamma().is_nan());
+    }
+    #[test]
+    fn subnormal() {
+        // `ln(|Γ(x)|)` test vectors around the subnormal range - `(input, output)`
+        let test_vectors: &[(f32, f32)] = &[
+            (1e-45, 103.278_93),
+            (1e-40, 92.103_41),
+            (3e-39, 88.702_21),
+            (1e-30, 69.077_55),
+            (f32::MIN_POSITIVE, 87.336_55),
+        ];
+
+        for &(x, expected) in test_vectors {
+            for &x in &[x, -x] {
+                let actual = F32(x).ln_gamma();
+                let delta = (actual - expected).abs();
+
+                assert!(
+                    delta <= MAX_ERROR * expected,
+                    "delta {} too large for input {}: {} vs {}",
+                    delta,
+                    x,
+                    actual,
+                    expected
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Maximum of two single-precision floats.
+
+use super::F32;
+
+impl F32 {
+    /// Returns the maximum of `self` and `other`.
+    ///
+    /// If one of the arguments is NaN the other is returned, as in the
+    /// `maxNum` operation of IEEE 754-2008. See [`F32::maximum`] for a version
+    /// which propagates NaN.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        if other > self || self.is_nan() {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F32(1.0).max(F32(2.0)), F32(2.0));
+        assert_eq!(F32(2.0).max(F32(-1.0)), F32(2.0));
+        assert_eq!(F32::NAN.max(F32(1.0)), F32(1.0));
+        assert_eq!(F32(1.0).max(F32::NAN), F32(1.0));
+        assert!(F32::NAN.max(F32::NAN).is_nan());
+    }
+}
@@ -0,0 +1,43 @@
+//! NaN-propagating maximum of two single-precision floats.
+
+use super::F32;
+
+impl F32 {
+    /// Returns the maximum of `self` and `other`, as in the `maximum`
+    /// operation of IEEE 754-2019.
+    ///
+    /// If either argument is NaN, NaN is returned. `+0.0` is considered
+    /// greater than `-0.0`.
+    #[inline]
+    pub fn maximum(self, other: Self) -> Self {
+        if self > other {
+            self
+        } else if other > self {
+            other
+        } else if self == other {
+            // Equal values may differ in sign if they're zeros
+            if self.is_sign_positive() {
+                self
+            } else {
+                other
+            }
+        } else {
+            Self::NAN
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F32(1.0).maximum(F32(2.0)), F32(2.0));
+        assert_eq!(F32(2.0).maximum(F32(-1.0)), F32(2.0));
+        assert!(F32(0.0).maximum(F32(-0.0)).is_sign_positive());
+        assert!(F32(-0.0).maximum(F32(0.0)).is_sign_positive());
+        assert!(F32::NAN.maximum(F32(1.0)).is_nan());
+        assert!(F32(1.0).maximum(F32::NAN).is_nan());
+    }
+}
@@ -0,0 +1,43 @@
+//! NaN-propagating minimum of two single-precision floats.
+
+use super::F32;
+
+impl F32 {
+    /// Returns the minimum of `self` and `other`, as in the `minimum`
+    /// operation of IEEE 754-2019.
+    ///
+    /// If either argument is NaN, NaN is returned. `-0.0` is considered less
+    /// than `+0.0`.
+    #[inline]
+    pub fn minimum(self, other: Self) -> Self {
+        if self < other {
+            self
+        } else if other < self {
+            other
+        } else if self == other {
+            // Equal values may differ in sign if they're zeros
+            if self.is_sign_negative() {
+                self
+            } else {
+                other
+            }
+        } else {
+            Self::NAN
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F32(1.0).minimum(F32(2.0)), F32(1.0));
+        assert_eq!(F32(2.0).minimum(F32(-1.0)), F32(-1.0));
+        assert!(F32(0.0).minimum(F32(-0.0)).is_sign_negative());
+        assert!(F32(-0.0).minimum(F32(0.0)).is_sign_negative());
+        assert!(F32::NAN.minimum(F32(1.0)).is_nan());
+        assert!(F32(1.0).minimum(F32::NAN).is_nan());
+    }
+}
@@ -4,8 +4,8 @@ use super::F32;
 
 impl F32 {
     /// Computes `(self * a) + b`.
-    pub fn mul_add(self, a: Self, b: Self) -> Self {
-        self * a + b
+    pub const fn mul_add(self, a: Self, b: Self) -> Self {
+        Self(self.0 * a.0 + b.0)
     }
 }
 
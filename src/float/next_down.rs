@@ -0,0 +1,41 @@
+//! Next representable single-precision float towards negative infinity.
+
+use super::F32;
+
+impl F32 {
+    /// Returns the greatest number less than `self`.
+    ///
+    /// Returns the negative subnormal closest to zero for `±0.0`, and `self`
+    /// for NaN and negative infinity. Positive numbers step towards `+0.0`.
+    #[inline]
+    pub const fn next_down(self) -> Self {
+        Self(-Self(-self.0).next_up().0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// `next_down` test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (1.0, 0.999_999_94),
+        (-1.0, -1.000_000_1),
+        (0.0, -1e-45),
+        (-0.0, -1e-45),
+        (1e-45, 0.0),
+        (f32::MIN, f32::NEG_INFINITY),
+        (f32::INFINITY, f32::MAX),
+        (f32::NEG_INFINITY, f32::NEG_INFINITY),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let actual = F32(x).next_down();
+            assert_eq!(actual.to_bits(), expected.to_bits(), "next_down({})", x);
+        }
+
+        assert!(F32::NAN.next_down().is_nan());
+    }
+}
@@ -0,0 +1,56 @@
+//! Next representable single-precision float towards positive infinity.
+
+use super::{F32, SIGN_MASK};
+
+impl F32 {
+    /// Returns the least number greater than `self`.
+    ///
+    /// Returns the smallest positive subnormal for `±0.0`, and `self` for NaN
+    /// and positive infinity. Negative numbers step towards `-0.0`.
+    pub const fn next_up(self) -> Self {
+        let bits = self.to_bits();
+
+        if self.is_nan() || bits == Self::INFINITY.to_bits() {
+            return self;
+        }
+
+        let magnitude = bits & !SIGN_MASK;
+
+        let next = if magnitude == 0 {
+            1
+        } else if bits == magnitude {
+            bits + 1
+        } else {
+            bits - 1
+        };
+
+        Self::from_bits(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// `next_up` test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (1.0, 1.000_000_1),
+        (-1.0, -0.999_999_94),
+        (0.0, 1e-45),
+        (-0.0, 1e-45),
+        (-1e-45, -0.0),
+        (f32::MAX, f32::INFINITY),
+        (f32::NEG_INFINITY, f32::MIN),
+        (f32::INFINITY, f32::INFINITY),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let actual = F32(x).next_up();
+            assert_eq!(actual.to_bits(), expected.to_bits(), "next_up({})", x);
+        }
+
+        assert!(F32::NAN.next_up().is_nan());
+    }
+}
@@ -0,0 +1,109 @@
+//! `n`th root approximation for a single-precision float.
+
+use super::F32;
+
+impl F32 {
+    /// Approximates the `n`th root of a number, i.e. `self^(1/n)`.
+    ///
+    /// The initial estimate `e^(ln(|x|) / n)` is refined with one step of
+    /// Newton's method, with a maximum relative error of `0.0001`.
+    ///
+    /// Negative numbers have a real root when `n` is odd, in which case the
+    /// sign of `self` is preserved. Returns [`Self::NAN`] if `self` is
+    /// negative and `n` is even, or if `n` is zero.
+    pub fn nth_root(self, n: i32) -> Self {
+        if n == 0 || self.is_nan() || (self < Self::ZERO && n % 2 == 0) {
+            return Self::NAN;
+        }
+
+        if n < 0 {
+            return Self::ONE / self.nth_root_abs(n.unsigned_abs());
+        }
+
+        self.nth_root_abs(n as u32)
+    }
+
+    /// Approximate the `n`th root for a positive `n`, preserving the sign.
+    fn nth_root_abs(self, n: u32) -> Self {
+        let x = self.abs();
+
+        if n == 1 || x == Self::ZERO || x.is_infinite() {
+            return self;
+        }
+
+        let n_f32 = n as f32;
+        let mut y = (x.ln() / n_f32).exp();
+
+        // Newton's method: y = ((n - 1) * y + x / y^(n - 1)) / n
+        let y_pow = y.powi((n - 1) as i32);
+
+        if y_pow.is_finite() && y_pow != Self::ZERO {
+            y = ((n_f32 - 1.0) * y + x / y_pow) / n_f32;
+        }
+
+        y.copysign(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum relative error
+    const MAX_ERROR: f32 = 0.0001;
+
+    /// `n`th root test vectors - `(input, n, output)`
+    #[allow(clippy::approx_constant)]
+    const TEST_VECTORS: &[(f32, i32, f32)] = &[
+        (2.0, 2, 1.4142135),
+        (1.0e1, 2, 3.1622777),
+        (27.0, 3, 3.0),
+        (-27.0, 3, -3.0),
+        (1.0e2, 3, 4.6415887),
+        (16.0, 4, 2.0),
+        (0.001, 4, 0.17782794),
+        (-32.0, 5, -2.0),
+        (1.0e10, 5, 1.0e2),
+        (128.0, 7, 2.0),
+        (-2.0, 7, -1.1040895),
+        (1.0e3, 10, 1.9952623),
+        (2.0, -2, 0.70710677),
+        (8.0, -3, 0.5),
+        (-8.0, -3, -0.5),
+        (5.0, 1, 5.0),
+        (5.0, -1, 0.2),
+        (1.0e30, 31, 9.284145),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, n, expected) in TEST_VECTORS {
+            let root = F32(x).nth_root(n);
+            let relative_error = ((root - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}.nth_root({}): {} vs {}",
+                relative_error,
+                x,
+                n,
+                root,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(F32::ZERO.nth_root(3), F32::ZERO);
+        assert_eq!(F32::ZERO.nth_root(-2), F32::INFINITY);
+    }
+
+    #[test]
+    fn nan() {
+        assert!(F32(-4.0).nth_root(2).is_nan());
+        assert!(F32(-4.0).nth_root(-4).is_nan());
+        assert!(F32(4.0).nth_root(0).is_nan());
+        assert!(F32::NAN.nth_root(3).is_nan());
+    }
+}
@@ -0,0 +1,275 @@
+//! Decimal parsing without `core::fmt`.
+
+use super::F32;
+
+/// Maximum number of significant digits accumulated by [`F32::parse_bytes`].
+const MAX_DIGITS: u32 = 19;
+
+/// Decimal exponent beyond which any mantissa of [`MAX_DIGITS`] digits
+/// overflows or underflows.
+const MAX_EXPONENT: i32 = MAX_DIGITS as i32 + 64;
+
+/// First [`MAX_DIGITS`] significant digits of `2^128 - 2^103`, the halfway
+/// point between `f32::MAX` and `2^128`, above which numbers round to infinity.
+const OVERFLOW_DIGITS: u64 = 3_402_823_567_797_336_616;
+
+/// Decimal exponent of the overflow threshold, written as `0.ddd * 10^39`.
+const OVERFLOW_EXPONENT: i32 = 39;
+
+/// Powers of ten which are exact in `f32`.
+const POWERS_OF_10: [f32; 11] = [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+
+impl F32 {
+    /// Parses a decimal number such as `-12.5` or `6.02e23` from ASCII bytes,
+    /// e.g. received over a serial console.
+    ///
+    /// Accepts an optional sign, digits with an optional decimal point and an
+    /// optional exponent, as well as `inf`, `infinity` and `nan` in any case.
+    /// Returns `None` if the bytes aren't a valid number.
+    ///
+    /// Unlike [`str::parse`], the result isn't always correctly rounded: only
+    /// the first 19 significant digits are used, and the result may be a few
+    /// ULPs away from the nearest `f32`, in exchange for a much smaller code
+    /// size. Overflow to infinity is decided from the digits, so numbers just
+    /// below the overflow threshold still round to [`f32::MAX`].
+    ///
+    /// ```
+    /// use micromath::F32;
+    ///
+    /// assert_eq!(F32::parse_bytes(b"-12.5"), Some(F32(-12.5)));
+    /// assert_eq!(F32::parse_bytes(b"1e3"), Some(F32(1000.0)));
+    /// assert_eq!(F32::parse_bytes(b"1.2.3"), None);
+    /// ```
+    pub fn parse_bytes(bytes: &[u8]) -> Option<Self> {
+        let (negative, bytes) = match bytes.split_first() {
+            Some((b'-', rest)) => (true, rest),
+            Some((b'+', rest)) => (false, rest),
+            _ => (false, bytes),
+        };
+
+        let magnitude =
+            if bytes.eq_ignore_ascii_case(b"inf") || bytes.eq_ignore_ascii_case(b"infinity") {
+                Self::INFINITY
+            } else if bytes.eq_ignore_ascii_case(b"nan") {
+                Self::NAN
+            } else {
+                Self::parse_decimal(bytes)?
+            };
+
+        Some(if negative { -magnitude } else { magnitude })
+    }
+
+    /// Parses unsigned digits with an optional decimal point and exponent.
+    fn parse_decimal(bytes: &[u8]) -> Option<Self> {
+        let mut mantissa = 0u64;
+        let mut digits = 0;
+        let mut exponent = 0i32;
+        let mut seen_digit = false;
+        let mut seen_point = false;
+        let mut rest = bytes;
+
+        while let Some((&byte, tail)) = rest.split_first() {
+            match byte {
+                b'0'..=b'9' => {
+                    seen_digit = true;
+
+                    // Skip leading zeros, and drop digits beyond the precision
+                    if mantissa == 0 && byte == b'0' {
+                        if seen_point {
+                            exponent -= 1;
+                        }
+                    } else if digits < MAX_DIGITS {
+                        mantissa = mantissa * 10 + u64::from(byte - b'0');
+                        digits += 1;
+
+                        if seen_point {
+                            exponent -= 1;
+                        }
+                    } else if !seen_point {
+                        exponent += 1;
+                    }
+                }
+                b'.' if !seen_point => seen_point = true,
+                b'e' | b'E' if seen_digit => {
+                    exponent = exponent
+                        .saturating_add(parse_exponent(tail)?)
+                        .clamp(-MAX_EXPONENT, MAX_EXPONENT);
+                    break;
+                }
+                _ => return None,
+            }
+
+            rest = tail;
+        }
+
+        if !seen_digit {
+            return None;
+        }
+
+        let mut result = mantissa as f32;
+
+        if result == 0.0 {
+            return Some(Self::ZERO);
+        }
+
+        // The value is `0.mantissa * 10^magnitude`. The scaling below is
+        // inexact, so compare against the threshold digits to decide overflow
+        let magnitude = exponent + digits as i32;
+
+        if magnitude > OVERFLOW_EXPONENT
+            || (magnitude == OVERFLOW_EXPONENT
+                && mantissa * 10u64.pow(MAX_DIGITS - digits) > OVERFLOW_DIGITS)
+        {
+            return Some(Self::INFINITY);
+        }
+
+        while exponent > 0 {
+            let step = exponent.min(10);
+            result *= POWERS_OF_10[step as usize];
+            exponent -= step;
+        }
+
+        // Anything rounded up past `f32::MAX` by the scaling is below the
+        // threshold, so it belongs at `f32::MAX`
+        result = result.min(f32::MAX);
+
+        // Divide rather than multiply by negative powers, which are inexact
+        while exponent < 0 && result != 0.0 {
+            let step = (-exponent).min(10);
+            result /= POWERS_OF_10[step as usize];
+            exponent += step;
+        }
+
+        Some(Self(result))
+    }
+}
+
+/// Parses the exponent after `e`, saturating on overflow.
+fn parse_exponent(bytes: &[u8]) -> Option<i32> {
+    let (negative, bytes) = match bytes.split_first() {
+        Some((b'-', rest)) => (true, rest),
+        Some((b'+', rest)) => (false, rest),
+        _ => (false, bytes),
+    };
+
+    if bytes.is_empty() {
+        return None;
+    }
+
+    let mut exponent = 0i32;
+
+    for &byte in bytes {
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+
+        exponent = exponent
+            .saturating_mul(10)
+            .saturating_add(i32::from(byte - b'0'));
+    }
+
+    Some(if negative { -exponent } else { exponent })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum error in ULPs
+    const MAX_ULPS: u64 = 4;
+
+    /// Parse test vectors - `(input, output)`
+    const TEST_VECTORS: &[(&[u8], f32)] = &[
+        (b"0", 0.0),
+        (b"-0", -0.0),
+        (b"1", 1.0),
+        (b"+1", 1.0),
+        (b"-12.5", -12.5),
+        (b".5", 0.5),
+        (b"5.", 5.0),
+        (b"0.001", 0.001),
+        (b"000123.4500", 123.45),
+        (b"9.87654321987654321987", 9.876_543),
+        (b"1e3", 1000.0),
+        (b"1E-3", 0.001),
+        (b"6.02e23", 6.02e23),
+        (b"-2.5e+2", -250.0),
+        (b"3.4028235e38", f32::MAX),
+        (b"3.4028235677e38", f32::MAX),
+        (b"340282356779733661637539395458142568447", f32::MAX),
+        (b"0.00034028235677e42", f32::MAX),
+        (b"3.40282357e38", f32::INFINITY),
+        (b"3.4028236e38", f32::INFINITY),
+        (b"3.4028237e38", f32::INFINITY),
+        (b"3402823568e29", f32::INFINITY),
+        (b"3.5e38", f32::INFINITY),
+        (b"1.17549435e-38", f32::MIN_POSITIVE),
+        (b"123456789012345678901234567890", 1.234_567_9e29),
+        (b"0.000000000000000000000000000001", 1e-30),
+        (b"1e1000", f32::INFINITY),
+        (b"1e-1000", 0.0),
+        (b"0.1e-99999999999", 0.0),
+        (b"10e99999999999", f32::INFINITY),
+        (b"-0.001e-2147483648", -0.0),
+        (b"1000e-2147483647", 0.0),
+        (b"inf", f32::INFINITY),
+        (b"-Infinity", f32::NEG_INFINITY),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(input, expected) in TEST_VECTORS {
+            let actual = F32::parse_bytes(input).unwrap().0;
+            let ulps = crate::approx::ulps_distance(actual, expected);
+            assert!(
+                actual == expected
+                    || (ulps <= MAX_ULPS && actual.is_finite() && expected.is_finite()),
+                "{} ULPs too many: {} vs {}",
+                ulps,
+                actual,
+                expected
+            );
+            assert_eq!(actual.is_sign_negative(), expected.is_sign_negative());
+        }
+
+        assert!(F32::parse_bytes(b"NaN").unwrap().is_nan());
+        assert!(F32::parse_bytes(b"-nan").unwrap().is_nan());
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut buf = [0u8; 64];
+
+        for i in -1000..=1000 {
+            let x = F32(i as f32 * 0.0137);
+            let len = x.write_fixed(&mut buf, 9).unwrap();
+            let parsed = F32::parse_bytes(&buf[..len]).unwrap();
+            assert!(
+                crate::approx::ulps_distance(parsed.0, x.0) <= MAX_ULPS,
+                "{} vs {}",
+                parsed.0,
+                x.0
+            );
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for &input in &[
+            &b""[..],
+            b"-",
+            b".",
+            b"1.2.3",
+            b"1e",
+            b"1e+",
+            b"e5",
+            b"1x",
+            b" 1",
+            b"1 ",
+            b"--1",
+            b"infinit",
+        ] {
+            assert_eq!(F32::parse_bytes(input), None);
+        }
+    }
+}
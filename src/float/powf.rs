@@ -206,4 +206,33 @@ mod tests {
             );
         }
     }
+
+    /// powf(x,n) test vectors with `x` near `1.0` - `(base_input, power_input, output)`
+    pub(crate) const TEST_VECTORS_NEAR_ONE: &[(f32, f32, f32)] = &[
+        (0.999, 1.0e3, 0.36770016),
+        (0.9999, -1.0e3, 1.1051948),
+        (1.0001, 1.0e3, 1.1051837),
+        (1.001, -1.0e2, 0.9048784),
+        (1.01, 0.5, 1.0049876),
+        (0.99, 3.5, 0.9654353),
+        (1.1, 2.5, 1.2690588),
+    ];
+
+    #[test]
+    fn near_one() {
+        for &(base_input, power_input, expected) in TEST_VECTORS_NEAR_ONE {
+            let exp_x = F32(base_input).powf(F32(power_input));
+            let relative_error = calc_relative_error(exp_x, expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}.powf({}) : {} vs {}",
+                relative_error,
+                base_input,
+                power_input,
+                exp_x,
+                expected
+            );
+        }
+    }
 }
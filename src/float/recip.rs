@@ -4,21 +4,19 @@ use super::F32;
 
 impl F32 {
     /// Returns the reciprocal (inverse) of a number, `1/x`.
-    pub fn recip(self) -> Self {
-        let mut x = self;
+    pub const fn recip(self) -> Self {
+        let sx = if self.0 < 0.0 { -1.0 } else { 1.0 };
+        let x = self.0 * sx;
 
-        let sx = if x < 0.0 { F32(-1.0) } else { F32(1.0) };
-        x *= sx;
-
-        let mut v = F32(f32::from_bits(0x7EF1_27EAu32.wrapping_sub(x.to_bits())));
+        let mut v = f32::from_bits(0x7EF1_27EAu32.wrapping_sub(x.to_bits()));
         let w = x * v;
 
         // v.0 *= 2.0 - w;
         // v.0 *= 4.0 + w * (-6.0 + w * (4.0 - w));
-        v.0 *=
+        v *=
             8.0 + w * (-28.0 + w * (56.0 + w * (-70.0 + w * (56.0 + w * (-28.0 + w * (8.0 - w))))));
 
-        v.0 * sx
+        Self(v * sx)
     }
 }
 
@@ -39,8 +37,8 @@ mod tests {
 
     #[test]
     fn sanity_check() {
-        assert_eq!(F32(0.0).recip(), F32(core::f32::INFINITY));
-        assert_eq!(F32(-0.0).recip(), F32(core::f32::NEG_INFINITY));
+        assert_eq!(F32(0.0).recip(), F32(f32::INFINITY));
+        assert_eq!(F32(-0.0).recip(), F32(f32::NEG_INFINITY));
 
         for &(x, expected) in TEST_VECTORS {
             let recip_x = F32(x).recip();
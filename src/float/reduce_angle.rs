@@ -0,0 +1,170 @@
+//! Range reduction of angles in radians to `[-π, π]`, shared by the
+//! trigonometric functions.
+//!
+//! Small and moderately large arguments use Cody-Waite reduction, where `2π`
+//! is split into three parts so `x - k·2π` can be computed without losing the
+//! low bits of `x`. Arguments too large for that use a simplified
+//! Payne-Hanek reduction, multiplying the integer mantissa of `x` by the
+//! relevant bits of `1/2π` to get the fraction of a turn.
+//!
+//! Method described at: <https://doi.org/10.1145/1057600.1057602>
+
+use super::{F32, MANTISSA_BITS, MANTISSA_MASK};
+use core::f32::consts::TAU;
+
+/// Upper bits of `2π`: 8 significant bits, so `k * TAU_1` is exact for
+/// `|k| < 2^16`.
+const TAU_1: f32 = 6.281_25;
+
+/// Middle bits of `2π`: 8 significant bits.
+const TAU_2: f32 = 0.001_937_866_2;
+
+/// Remaining bits of `2π`.
+const TAU_3: f32 = -2.559_031_4e-6;
+
+/// `1/2π`
+const FRAC_1_TAU: f32 = 0.159_154_94;
+
+/// Largest argument reduced using Cody-Waite reduction.
+const CODY_WAITE_MAX: f32 = 262_144.0;
+
+/// Bits of `1/2π` after the binary point, preceded by 7 zero bits.
+const FRAC_1_TAU_BITS: [u32; 7] = [
+    0x0051_7cc1,
+    0xb727_220a,
+    0x94fe_13ab,
+    0xe8fa_9a6e,
+    0xe06d_b14a,
+    0xcc9e_21c8,
+    0x20ff_28b1,
+];
+
+impl F32 {
+    /// Reduces an angle in radians to the equivalent angle in `[-π, π]`.
+    ///
+    /// Returns [`Self::NAN`] if `self` is infinite or `NaN`.
+    pub(crate) fn reduce_angle(self) -> Self {
+        if self.needs_large_reduction() {
+            self.reduce_angle_large()
+        } else {
+            self.reduce_angle_small()
+        }
+    }
+
+    /// Whether [`F32::reduce_angle`] takes the slow path for this angle,
+    /// rather than [`F32::reduce_angle_small`].
+    #[inline]
+    pub(crate) fn needs_large_reduction(self) -> bool {
+        self.abs().0 > CODY_WAITE_MAX && self.is_finite()
+    }
+
+    /// Cody-Waite reduction, which is exact enough for angles up to
+    /// `CODY_WAITE_MAX`.
+    ///
+    /// Returns [`Self::NAN`] if `self` is infinite or `NaN`. Branch-free, so
+    /// that loops over it can be vectorized.
+    #[inline]
+    pub(crate) fn reduce_angle_small(self) -> Self {
+        let k = (self * FRAC_1_TAU).round();
+        let reduced = ((self - k * TAU_1) - k * TAU_2) - k * TAU_3;
+        Self::select(self.is_finite(), reduced, Self::NAN)
+    }
+
+    /// Simplified Payne-Hanek reduction for arguments too large for
+    /// Cody-Waite reduction.
+    fn reduce_angle_large(self) -> Self {
+        // `|x| = m * 2^e`, where `m` is the integer mantissa
+        let bits = self.to_bits();
+        let m = u128::from((bits & MANTISSA_MASK) | (1 << MANTISSA_BITS));
+        let e = self.extract_exponent_value() - MANTISSA_BITS as i32;
+
+        // Bits of `1/2π` at positions `e + 1..=e + 64` after the binary point:
+        // the ones before only contribute whole turns, and the ones after are
+        // too small to affect the result.
+        let start = (e + 7) as usize;
+        let (word, offset) = (start / 32, start % 32);
+        let window = FRAC_1_TAU_BITS[word..word + 3]
+            .iter()
+            .fold(0_u128, |acc, &w| acc << 32 | u128::from(w));
+        let window = (window << (32 + offset)) >> 64;
+
+        // The low 64 bits of the product are the fraction of a turn, which
+        // is reinterpreted as signed to get a turn in `[-0.5, 0.5)`
+        let turn = (m * window) as u64 as i64;
+        let reduced = Self(turn as f32 * (TAU / 18_446_744_073_709_551_616.0));
+
+        if self.is_sign_negative() {
+            -reduced
+        } else {
+            reduced
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+    use core::f32::consts::PI;
+
+    /// Maximum error in radians, relative to reducing the exact value of the
+    /// `f32` input.
+    const MAX_ERROR: f64 = 1e-6;
+
+    /// Reduce `x` in double precision, which is exact enough for inputs
+    /// up to `2^20`.
+    fn reference(x: f32) -> f64 {
+        let x = f64::from(x);
+        let tau = core::f64::consts::TAU;
+        x - (x / tau).round() * tau
+    }
+
+    #[test]
+    fn small() {
+        for i in -1000..=1000 {
+            let x = i as f32 * (PI / 100.0);
+            let reduced = F32(x).reduce_angle().0;
+            let delta = (f64::from(reduced) - reference(x)).abs();
+            assert!(delta <= MAX_ERROR, "delta {} too large for {}", delta, x);
+        }
+    }
+
+    #[test]
+    fn large() {
+        let mut x = 10.0_f32;
+
+        while x < 1_000_000.0 {
+            for &x in &[x, -x] {
+                let reduced = F32(x).reduce_angle().0;
+                let delta = (f64::from(reduced) - reference(x)).abs();
+                assert!(delta <= MAX_ERROR, "delta {} too large for {}", delta, x);
+            }
+
+            x *= 1.001;
+        }
+    }
+
+    #[test]
+    fn huge() {
+        // Reduced angles computed with arbitrary precision
+        let vectors: &[(f32, f64)] = &[
+            (123_456_792.0, -1.853_112_664_404_764),
+            (1.0e10, -0.509_231_072_165_734_8),
+            (3.0e30, -1.845_269_842_624_042),
+            (f32::MAX, -0.549_049_329_957_454_3),
+        ];
+
+        for &(x, expected) in vectors {
+            for &(x, expected) in &[(x, expected), (-x, -expected)] {
+                let reduced = F32(x).reduce_angle().0;
+                let delta = (f64::from(reduced) - expected).abs();
+                assert!(delta <= MAX_ERROR, "delta {} too large for {}", delta, x);
+            }
+        }
+    }
+
+    #[test]
+    fn non_finite() {
+        assert!(F32::INFINITY.reduce_angle().is_nan());
+        assert!(F32::NAN.reduce_angle().is_nan());
+    }
+}
@@ -4,7 +4,7 @@ use super::F32;
 
 impl F32 {
     /// Returns the nearest integer to a number.
-    pub fn round(self) -> Self {
+    pub const fn round(self) -> Self {
         Self(((self.0 + Self(0.5).copysign(self).0) as i32) as f32)
     }
 }
@@ -0,0 +1,85 @@
+//! Multiply a single-precision float by an integer power of two.
+
+use super::{EXPONENT_BIAS, F32, MANTISSA_BITS};
+
+/// `2^127`
+const TWO_POW_127: f32 = 1.701_411_8e38;
+
+/// `2^-102`, i.e. `2^-126 * 2^24`, which scales subnormal results in two
+/// steps without rounding twice.
+const TWO_POW_NEG_102: f32 = 1.972_152_3e-31;
+
+impl F32 {
+    /// Computes `self * 2^n` exactly, other than overflowing to infinity or
+    /// rounding subnormal results.
+    pub const fn scalbn(self, n: i32) -> Self {
+        let mut x = self.0;
+        let mut n = n;
+
+        if n > 127 {
+            x *= TWO_POW_127;
+            n -= 127;
+
+            if n > 127 {
+                x *= TWO_POW_127;
+                n -= 127;
+
+                if n > 127 {
+                    n = 127;
+                }
+            }
+        } else if n < -126 {
+            x *= TWO_POW_NEG_102;
+            n += 102;
+
+            if n < -126 {
+                x *= TWO_POW_NEG_102;
+                n += 102;
+
+                if n < -126 {
+                    n = -126;
+                }
+            }
+        }
+
+        Self(x * Self::from_bits(((n + EXPONENT_BIAS as i32) as u32) << MANTISSA_BITS).0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// `scalbn` test vectors - `(input, n, output)`
+    const TEST_VECTORS: &[(f32, i32, f32)] = &[
+        (1.0, 0, 1.0),
+        (1.0, 1, 2.0),
+        (-3.0, 4, -48.0),
+        (1.0, -1, 0.5),
+        (1.0, 127, 1.701_411_8e38),
+        (1.0, 128, f32::INFINITY),
+        (-1.0, 1_000, f32::NEG_INFINITY),
+        (f32::MIN_POSITIVE, 253, 1.701_411_8e38),
+        (1.0, -149, 1e-45),
+        (1.0, -150, 0.0),
+        (1.5, -149, 3e-45),
+        (f32::MAX, -277, 1e-45),
+        (1.0, -1_000, 0.0),
+        (1e-45, 149, 1.0),
+        (1e-45, 276, 1.701_411_8e38),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, n, expected) in TEST_VECTORS {
+            assert_eq!(F32(x).scalbn(n).0, expected, "scalbn({}, {})", x, n);
+        }
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(F32(-0.0).scalbn(10).to_bits(), (-0.0f32).to_bits());
+        assert_eq!(F32::INFINITY.scalbn(-1_000), F32::INFINITY);
+        assert!(F32::NAN.scalbn(1).is_nan());
+    }
+}
@@ -8,7 +8,7 @@ impl F32 {
     /// * `1.0` if the number is positive, `+0.0` or `INFINITY`
     /// * `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
     /// * `NAN` if the number is `NAN`
-    pub fn signum(self) -> Self {
+    pub const fn signum(self) -> Self {
         if self.is_nan() {
             Self::NAN
         } else {
@@ -1,12 +1,52 @@
 //! Sine approximation, implemented in terms of `cos(x)`.
 
 use super::F32;
+use crate::precision::Precision;
+#[cfg(feature = "trig-table")]
+use crate::trig_table::TrigTable;
 use core::f32::consts::PI;
 
 impl F32 {
-    /// Approximates `sin(x)` in radians with a maximum error of `0.002`.
+    /// Approximates `sin(x)` in radians with a maximum error of `0.0011`, see
+    /// [`accuracy::SIN`].
+    ///
+    /// [`accuracy::SIN`]: crate::accuracy::SIN
+    #[cfg(not(feature = "trig-table"))]
     pub fn sin(self) -> Self {
-        (self - PI / 2.0).cos()
+        self.reduce_angle().sin_reduced()
+    }
+
+    /// Approximates `sin(x)` for an angle already reduced to `[-π, π]`.
+    #[cfg(not(feature = "trig-table"))]
+    #[inline]
+    pub(crate) fn sin_reduced(self) -> Self {
+        (self - PI / 2.0).cos_refined()
+    }
+
+    /// Approximates `sin(x)` in radians using a lookup table of
+    /// [`DEFAULT_SIZE`][`crate::trig_table::DEFAULT_SIZE`] entries, with a
+    /// maximum error of [`TrigTable::MAX_ERROR`] (see [`accuracy::SIN`]).
+    ///
+    /// [`accuracy::SIN`]: crate::accuracy::SIN
+    #[cfg(feature = "trig-table")]
+    pub fn sin(self) -> Self {
+        crate::trig_table::DEFAULT_TABLE.sin(self)
+    }
+
+    /// Approximates `sin(x)` in radians using the given lookup table, with a
+    /// maximum error of [`TrigTable::MAX_ERROR`].
+    #[cfg(feature = "trig-table")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "trig-table")))]
+    pub fn sin_with_table<const N: usize>(self, table: &TrigTable<N>) -> Self {
+        table.sin(self)
+    }
+
+    /// Approximates `sin(x)` in radians using the given [`Precision`] tier.
+    ///
+    /// See [`Precision::COS_MAX_ERROR`] for the maximum error of each tier.
+    #[inline]
+    pub fn sin_with<P: Precision>(self) -> Self {
+        P::cos(self.reduce_angle() - PI / 2.0)
     }
 }
 
@@ -14,8 +54,11 @@ impl F32 {
 mod tests {
     use super::F32;
     use crate::float::cos::tests::MAX_ERROR;
+    use crate::precision::{Balanced, Fast, Precise, Precision};
+    use core::f32::consts::TAU;
 
     /// Sine test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
     const TEST_VECTORS: &[(f32, f32)] = &[
         (0.000, 0.000),
         (0.140, 0.139),
@@ -80,4 +123,51 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn large_inputs() {
+        let mut x = 10.0_f32;
+
+        while x < 1.0e9 {
+            for &x in &[x, -x] {
+                let sin_x = F32(x).sin();
+                let expected = f64::from(x).sin() as f32;
+                let delta = (sin_x - expected).abs();
+
+                assert!(
+                    delta <= MAX_ERROR,
+                    "delta {} too large for input {}: {} vs {}",
+                    delta,
+                    x,
+                    sin_x,
+                    expected
+                );
+            }
+
+            x *= 1.01;
+        }
+
+        assert!(F32::NEG_INFINITY.sin().is_nan());
+    }
+
+    fn precision_tier<P: Precision>() {
+        for i in -10_000..=10_000 {
+            let x = i as f32 * (TAU / 10_000.0);
+            let delta = (F32(x).sin_with::<P>() - x.sin()).abs();
+
+            assert!(
+                delta <= P::COS_MAX_ERROR,
+                "delta {} too large for input {}",
+                delta,
+                x
+            );
+        }
+    }
+
+    #[test]
+    fn precision_tiers() {
+        precision_tier::<Fast>();
+        precision_tier::<Balanced>();
+        precision_tier::<Precise>();
+    }
 }
@@ -1,12 +1,39 @@
 //! Simultaneously computes the sine and cosine of the number, `x`.
 
 use super::F32;
+#[cfg(feature = "trig-table")]
+use crate::trig_table::TrigTable;
 
 impl F32 {
     /// Simultaneously computes the sine and cosine of the number, `x`.
     /// Returns `(sin(x), cos(x))`.
+    #[cfg(not(feature = "trig-table"))]
     pub fn sin_cos(self) -> (Self, Self) {
-        (self.sin(), self.cos())
+        // Reduce the argument once for both results
+        self.reduce_angle().sin_cos_reduced()
+    }
+
+    /// Computes `(sin(x), cos(x))` for an angle already reduced to `[-π, π]`.
+    #[cfg(not(feature = "trig-table"))]
+    #[inline]
+    pub(crate) fn sin_cos_reduced(self) -> (Self, Self) {
+        (self.sin_reduced(), self.cos_refined())
+    }
+
+    /// Simultaneously computes the sine and cosine of the number, `x`, using
+    /// a lookup table of [`DEFAULT_SIZE`][`crate::trig_table::DEFAULT_SIZE`]
+    /// entries. Returns `(sin(x), cos(x))`.
+    #[cfg(feature = "trig-table")]
+    pub fn sin_cos(self) -> (Self, Self) {
+        crate::trig_table::DEFAULT_TABLE.sin_cos(self)
+    }
+
+    /// Simultaneously computes the sine and cosine of the number, `x`, using
+    /// the given lookup table. Returns `(sin(x), cos(x))`.
+    #[cfg(feature = "trig-table")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "trig-table")))]
+    pub fn sin_cos_with_table<const N: usize>(self, table: &TrigTable<N>) -> (Self, Self) {
+        table.sin_cos(self)
     }
 }
 
@@ -14,6 +41,7 @@ impl F32 {
 mod tests {
     use super::F32;
 
+    #[allow(clippy::approx_constant)]
     const TEST_VECTORS: &[f32] = &[
         0.000, 0.140, 0.279, 0.419, 0.559, 0.698, 0.838, 0.977, 1.117, 1.257, 1.396, 1.536, 1.676,
         1.815, 1.955, 2.094, 2.234, 2.374, 2.513, 2.653, 2.793, 2.932, 3.072, 3.211, 3.351, 3.491,
@@ -30,4 +58,14 @@ mod tests {
             assert_eq!(F32(x).sin_cos(), (sin_x, cos_x));
         }
     }
+
+    #[test]
+    fn large_inputs() {
+        for &x in &[1.0e4, -2.5e5, 1.0e6, 1_048_575.9, 3.0e8] {
+            let sin_x = F32(x).sin();
+            let cos_x = F32(x).cos();
+
+            assert_eq!(F32(x).sin_cos(), (sin_x, cos_x));
+        }
+    }
 }
@@ -0,0 +1,65 @@
+//! Sine approximation for a single-precision float, where the input is in
+//! degrees.
+
+use super::F32;
+
+impl F32 {
+    /// Approximates `sin(x)` where `x` is in degrees with a maximum error of
+    /// `0.002`.
+    ///
+    /// The argument is reduced to `(-360, 360)` degrees exactly, so there's no
+    /// precision lost for large inputs.
+    pub fn sin_deg(self) -> Self {
+        (self.reduce_degrees() / 360.0).sin_turns()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+    use crate::float::cos::tests::MAX_ERROR;
+
+    /// Sine test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (0.0, 0.0),
+        (30.0, 0.5),
+        (45.0, 0.707),
+        (60.0, 0.866),
+        (90.0, 1.0),
+        (135.0, 0.707),
+        (180.0, 0.0),
+        (-90.0, -1.0),
+        (270.0, -1.0),
+        (360.0, 0.0),
+        (-480.0, -0.866),
+        (1_000_170.0, 1.0),
+        (3.6e12, 0.985),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let sin_x = F32(x).sin_deg();
+            let delta = (sin_x - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large: {} vs {}",
+                delta,
+                sin_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn sweep() {
+        for i in -100_000..=100_000 {
+            let x = i as f32 / 10.0;
+            let expected = f64::from(x).to_radians().sin() as f32;
+            let delta = (F32(x).sin_deg() - expected).abs();
+
+            assert!(delta <= MAX_ERROR, "delta {} too large for {}", delta, x);
+        }
+    }
+}
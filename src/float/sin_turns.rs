@@ -0,0 +1,64 @@
+//! Sine approximation for a single-precision float, where the input is in
+//! turns (`1.0` is a full circle).
+
+use super::F32;
+
+impl F32 {
+    /// Approximates `sin(x)` where `x` is in turns (`1.0` is a full circle)
+    /// with a maximum error of `0.002`.
+    ///
+    /// The argument is reduced to `[-0.5, 0.5]` turns exactly, so there's no
+    /// precision lost for large inputs.
+    pub fn sin_turns(self) -> Self {
+        (self.reduce_turns() - 0.25).cos_turns()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+    use crate::float::cos::tests::MAX_ERROR;
+    use core::f64::consts::TAU;
+
+    /// Sine test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (0.0, 0.0),
+        (0.125, 0.707),
+        (0.25, 1.0),
+        (0.375, 0.707),
+        (0.5, 0.0),
+        (-0.25, -1.0),
+        (1.0, 0.0),
+        (1.1, 0.588),
+        (-2.6, 0.588),
+        (1_000_000.25, 1.0),
+        (1.0e10, 0.0),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let sin_x = F32(x).sin_turns();
+            let delta = (sin_x - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large: {} vs {}",
+                delta,
+                sin_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn sweep() {
+        for i in -100_000..=100_000 {
+            let x = i as f32 / 1_000.0;
+            let expected = (f64::from(x) * TAU).sin() as f32;
+            let delta = (F32(x).sin_turns() - expected).abs();
+
+            assert!(delta <= MAX_ERROR, "delta {} too large for {}", delta, x);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Hyperbolic sine function
+
+use super::F32;
+use crate::poly;
+
+/// Coefficients of the Taylor series of `sinh(x) / x` in `x²`, i.e.
+/// `1 / (2n + 1)!`.
+const SINH_SERIES: [f32; 4] = [1.0, 1.0 / 6.0, 1.0 / 120.0, 1.0 / 5_040.0];
+
+impl F32 {
+    /// Approximates `sinh(x)` with a maximum relative error of `0.0018`, see
+    /// [`accuracy::SINH`].
+    ///
+    /// For `|x| < 0.5` the Taylor series is used to avoid the cancellation
+    /// in `(e^x - e^-x) / 2`.
+    ///
+    /// [`accuracy::SINH`]: crate::accuracy::SINH
+    pub fn sinh(self) -> Self {
+        if self.abs() < 0.5 {
+            return self * poly::horner(self * self, &SINH_SERIES);
+        }
+
+        let exp_x = self.exp();
+        let exp_neg_x = (-self).exp();
+        (exp_x - exp_neg_x) / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum relative error
+    const MAX_ERROR: f32 = 0.002;
+
+    /// Hyperbolic sine test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (-6.0, -201.71315),
+        (-5.75, -157.09373),
+        (-5.5, -122.343925),
+        (-5.25, -95.28051),
+        (-5.0, -74.20321),
+        (-4.75, -57.787815),
+        (-4.5, -45.00301),
+        (-4.25, -35.045574),
+        (-4.0, -27.289917),
+        (-3.75, -21.248781),
+        (-3.5, -16.542627),
+        (-3.25, -12.875783),
+        (-3.0, -10.017875),
+        (-2.75, -7.789352),
+        (-2.5, -6.0502043),
+        (-2.25, -4.6911683),
+        (-2.0, -3.6268604),
+        (-1.75, -2.7904143),
+        (-1.5, -2.1292794),
+        (-1.25, -1.601919),
+        (-1.0, -1.1752012),
+        (-0.75, -0.8223167),
+        (-0.5, -0.5210953),
+        (-0.25, -0.25261232),
+        (0.25, 0.25261232),
+        (0.5, 0.5210953),
+        (0.75, 0.8223167),
+        (1.0, 1.1752012),
+        (1.25, 1.601919),
+        (1.5, 2.1292794),
+        (1.75, 2.7904143),
+        (2.0, 3.6268604),
+        (2.25, 4.6911683),
+        (2.5, 6.0502043),
+        (2.75, 7.789352),
+        (3.0, 10.017875),
+        (3.25, 12.875783),
+        (3.5, 16.542627),
+        (3.75, 21.248781),
+        (4.0, 27.289917),
+        (4.25, 35.045574),
+        (4.5, 45.00301),
+        (4.75, 57.787815),
+        (5.0, 74.20321),
+        (5.25, 95.28051),
+        (5.5, 122.343925),
+        (5.75, 157.09373),
+        (6.0, 201.71315),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let sinh_x = F32(x).sinh();
+            let relative_error = ((sinh_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                sinh_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(F32::ZERO.sinh(), F32::ZERO);
+    }
+}
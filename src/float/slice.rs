@@ -0,0 +1,303 @@
+//! Batch versions of the [`F32`] functions which operate on whole slices.
+//!
+//! Each result is computed by the same kernel as the scalar function, so it's
+//! identical to calling that function on each value.
+//!
+//! [`F32::abs_slice`], [`F32::sqrt_slice`], [`F32::invsqrt_slice`],
+//! [`F32::exp_slice`], [`F32::ln_slice`], [`F32::atan_slice`] and
+//! [`F32::atan2_slice`] use branch-free kernels, which handle special cases
+//! with bit masks rather than branches, so they autovectorize on targets with
+//! SIMD such as Helium, NEON or SSE.
+//!
+//! The trigonometric functions reduce angles beyond `±262144` with a slower
+//! method, so [`F32::sin_slice`], [`F32::cos_slice`], [`F32::tan_slice`] and
+//! [`F32::sin_cos_slice`] process values in chunks, and vectorize the chunks
+//! where no value needs it. With the `trig-table` feature they use table
+//! lookups instead, which compile to a scalar loop, as does
+//! [`F32::tanh_slice`].
+
+use super::F32;
+
+/// Number of values processed per chunk, which covers the widest SIMD
+/// registers commonly available for `f32` (8 lanes of AVX).
+#[cfg(not(feature = "trig-table"))]
+const CHUNK_SIZE: usize = 8;
+
+macro_rules! impl_slice {
+    ($($name:ident => $func:ident),+ $(,)?) => {
+        impl F32 {
+            $(
+                #[doc = concat!("Replaces each value in the slice with [`F32::", stringify!($func), "`] of it.")]
+                pub fn $name(values: &mut [f32]) {
+                    for value in values.iter_mut() {
+                        *value = F32::$func(F32(*value)).0;
+                    }
+                }
+            )+
+        }
+    };
+}
+
+impl_slice! {
+    abs_slice => abs,
+    sqrt_slice => sqrt,
+    invsqrt_slice => invsqrt,
+    exp_slice => exp,
+    ln_slice => ln,
+    atan_slice => atan,
+    tanh_slice => tanh,
+}
+
+#[cfg(feature = "trig-table")]
+impl_slice! {
+    sin_slice => sin,
+    cos_slice => cos,
+    tan_slice => tan,
+}
+
+#[cfg(not(feature = "trig-table"))]
+impl F32 {
+    /// Replaces each value in the slice with [`F32::sin`] of it.
+    pub fn sin_slice(values: &mut [f32]) {
+        map_angles_in_place(values, F32::sin_reduced);
+    }
+
+    /// Replaces each value in the slice with [`F32::cos`] of it.
+    pub fn cos_slice(values: &mut [f32]) {
+        map_angles_in_place(values, F32::cos_refined);
+    }
+
+    /// Replaces each value in the slice with [`F32::tan`] of it.
+    pub fn tan_slice(values: &mut [f32]) {
+        map_angles_in_place(values, |x| {
+            let (sin, cos) = x.sin_cos_reduced();
+            sin / cos
+        });
+    }
+}
+
+impl F32 {
+    /// Computes [`F32::sin_cos`] of each value in `input`, storing the sines
+    /// in `sin` and the cosines in `cos`.
+    ///
+    /// # Panics
+    ///
+    /// If `sin` or `cos` have a different length than `input`.
+    pub fn sin_cos_slice(input: &[f32], sin: &mut [f32], cos: &mut [f32]) {
+        assert_eq!(input.len(), sin.len(), "sin length mismatch");
+        assert_eq!(input.len(), cos.len(), "cos length mismatch");
+
+        #[cfg(not(feature = "trig-table"))]
+        {
+            let mut input_chunks = input.chunks_exact(CHUNK_SIZE);
+            let mut sin_chunks = sin.chunks_exact_mut(CHUNK_SIZE);
+            let mut cos_chunks = cos.chunks_exact_mut(CHUNK_SIZE);
+
+            for ((x, s), c) in (&mut input_chunks)
+                .zip(&mut sin_chunks)
+                .zip(&mut cos_chunks)
+            {
+                if needs_large_reduction(x) {
+                    sin_cos_each(x, s, c, F32::reduce_angle);
+                } else {
+                    sin_cos_each(x, s, c, F32::reduce_angle_small);
+                }
+            }
+
+            sin_cos_each(
+                input_chunks.remainder(),
+                sin_chunks.into_remainder(),
+                cos_chunks.into_remainder(),
+                F32::reduce_angle,
+            );
+        }
+
+        #[cfg(feature = "trig-table")]
+        for ((x, s), c) in input.iter().zip(sin.iter_mut()).zip(cos.iter_mut()) {
+            let (sin_x, cos_x) = F32(*x).sin_cos();
+            *s = sin_x.0;
+            *c = cos_x.0;
+        }
+    }
+
+    /// Computes [`F32::atan2`] of each pair of values in `y` and `x`, storing
+    /// the angles in `output`.
+    ///
+    /// # Panics
+    ///
+    /// If `x` or `output` have a different length than `y`.
+    pub fn atan2_slice(y: &[f32], x: &[f32], output: &mut [f32]) {
+        assert_eq!(y.len(), x.len(), "x length mismatch");
+        assert_eq!(y.len(), output.len(), "output length mismatch");
+
+        for ((y, x), out) in y.iter().zip(x.iter()).zip(output.iter_mut()) {
+            *out = F32(*y).atan2(F32(*x)).0;
+        }
+    }
+}
+
+/// Replaces each angle in the slice with `f` of the angle reduced to
+/// `[-π, π]`, a chunk at a time.
+///
+/// Chunks where every angle can be reduced by [`F32::reduce_angle_small`] use
+/// it directly, so the loop over them is branch-free.
+#[cfg(not(feature = "trig-table"))]
+#[inline(always)]
+fn map_angles_in_place(values: &mut [f32], f: impl Fn(F32) -> F32) {
+    let mut chunks = values.chunks_exact_mut(CHUNK_SIZE);
+
+    for chunk in &mut chunks {
+        if needs_large_reduction(chunk) {
+            map_each(chunk, |x| f(x.reduce_angle()));
+        } else {
+            map_each(chunk, |x| f(x.reduce_angle_small()));
+        }
+    }
+
+    map_each(chunks.into_remainder(), |x| f(x.reduce_angle()));
+}
+
+/// Whether any of the angles needs the slow path of [`F32::reduce_angle`].
+#[cfg(not(feature = "trig-table"))]
+#[inline(always)]
+fn needs_large_reduction(angles: &[f32]) -> bool {
+    angles
+        .iter()
+        .fold(false, |any, &x| any | F32(x).needs_large_reduction())
+}
+
+/// Replaces each value in the slice with `f(value)`.
+#[cfg(not(feature = "trig-table"))]
+#[inline(always)]
+fn map_each(values: &mut [f32], f: impl Fn(F32) -> F32) {
+    for value in values.iter_mut() {
+        *value = f(F32(*value)).0;
+    }
+}
+
+/// Stores the sine and cosine of each angle in `input` after reducing it with
+/// `reduce`.
+#[cfg(not(feature = "trig-table"))]
+#[inline(always)]
+fn sin_cos_each(input: &[f32], sin: &mut [f32], cos: &mut [f32], reduce: fn(F32) -> F32) {
+    for ((x, s), c) in input.iter().zip(sin.iter_mut()).zip(cos.iter_mut()) {
+        let (sin_x, cos_x) = reduce(F32(*x)).sin_cos_reduced();
+        *s = sin_x.0;
+        *c = cos_x.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Number of inputs, which isn't a multiple of the chunk size so the
+    /// remainder is covered too
+    const LEN: usize = 101;
+
+    /// Inputs spanning negative and positive values
+    fn inputs() -> [f32; LEN] {
+        let mut inputs = [0.0; LEN];
+
+        for (i, x) in inputs.iter_mut().enumerate() {
+            *x = (i as f32 - 50.0) * 0.37;
+        }
+
+        inputs
+    }
+
+    /// Checks that `batch` gives bit-identical results to `scalar`
+    fn check(batch: fn(&mut [f32]), scalar: fn(F32) -> F32, inputs: [f32; LEN]) {
+        let mut values = inputs;
+        batch(&mut values);
+
+        for (actual, &x) in values.iter().zip(inputs.iter()) {
+            let expected = scalar(F32(x)).0;
+            assert!(
+                actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+                "{} vs {} for {}",
+                actual,
+                expected,
+                x
+            );
+        }
+    }
+
+    #[test]
+    fn unary_matches_scalar() {
+        check(F32::abs_slice, F32::abs, inputs());
+        check(F32::sqrt_slice, F32::sqrt, inputs());
+        check(F32::exp_slice, F32::exp, inputs());
+        check(F32::ln_slice, F32::ln, inputs());
+        check(F32::sin_slice, F32::sin, inputs());
+        check(F32::cos_slice, F32::cos, inputs());
+        check(F32::tan_slice, F32::tan, inputs());
+        check(F32::atan_slice, F32::atan, inputs());
+        check(F32::tanh_slice, F32::tanh, inputs());
+
+        // `invsqrt` is only defined for positive numbers
+        check(
+            F32::invsqrt_slice,
+            F32::invsqrt,
+            inputs().map(|x| x.abs() + 1.0),
+        );
+    }
+
+    #[test]
+    fn sin_cos_matches_scalar() {
+        let input = inputs();
+        let mut sin = [0.0; LEN];
+        let mut cos = [0.0; LEN];
+        F32::sin_cos_slice(&input, &mut sin, &mut cos);
+
+        for i in 0..LEN {
+            let (sin_x, cos_x) = F32(input[i]).sin_cos();
+            assert_eq!(sin[i], sin_x.0);
+            assert_eq!(cos[i], cos_x.0);
+        }
+    }
+
+    #[test]
+    fn atan2_matches_scalar() {
+        let y = inputs();
+        let mut x = inputs();
+        x.reverse();
+
+        let mut output = [0.0; LEN];
+        F32::atan2_slice(&y, &x, &mut output);
+
+        for i in 0..LEN {
+            let expected = F32(y[i]).atan2(F32(x[i])).0;
+            assert_eq!(output[i].to_bits(), expected.to_bits());
+        }
+    }
+
+    #[test]
+    fn large_angles_match_scalar() {
+        // Chunks of positive angles need the slow reduction, the others don't
+        let mut input = inputs().map(|x| if x > 0.0 { x * 1e6 } else { x });
+        input[0] = f32::INFINITY;
+        input[1] = f32::NAN;
+        check(F32::sin_slice, F32::sin, input);
+        check(F32::cos_slice, F32::cos, input);
+        check(F32::tan_slice, F32::tan, input);
+
+        let mut sin = [0.0; LEN];
+        let mut cos = [0.0; LEN];
+        F32::sin_cos_slice(&input, &mut sin, &mut cos);
+
+        for i in 0..LEN {
+            let (sin_x, cos_x) = F32(input[i]).sin_cos();
+            assert_eq!(sin[i].to_bits(), sin_x.0.to_bits());
+            assert_eq!(cos[i].to_bits(), cos_x.0.to_bits());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn length_mismatch() {
+        let mut sin = [0.0; 3];
+        let mut cos = [0.0; 4];
+        F32::sin_cos_slice(&[1.0, 2.0, 3.0], &mut sin, &mut cos);
+    }
+}
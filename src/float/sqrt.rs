@@ -3,23 +3,57 @@
 //! Method described at: <https://bits.stephan-brumme.com/squareRoot.html>
 
 use super::F32;
+use crate::precision::Precision;
 
 impl F32 {
     /// Approximates the square root of a number with an average deviation of ~5%.
     ///
+    /// The maximum relative error is `0.061`, see [`accuracy::SQRT`].
+    ///
     /// Returns [`Self::NAN`] if `self` is a negative number.
-    pub fn sqrt(self) -> Self {
-        if self >= Self::ZERO {
+    ///
+    /// [`accuracy::SQRT`]: crate::accuracy::SQRT
+    pub const fn sqrt(self) -> Self {
+        if self.0 >= 0.0 {
             Self::from_bits((self.to_bits() + 0x3f80_0000) >> 1)
         } else {
             Self::NAN
         }
     }
+
+    /// Approximates the square root of a number using the given [`Precision`]
+    /// tier, refining [`F32::sqrt`] with [`Precision::NEWTON_ITERATIONS`]
+    /// steps of Newton's method.
+    ///
+    /// See [`Precision::SQRT_MAX_ERROR`] for the maximum error of each tier.
+    ///
+    /// Returns [`Self::NAN`] if `self` is a negative number.
+    pub const fn sqrt_with<P: Precision>(self) -> Self {
+        if self.0 == 0.0 {
+            return self;
+        }
+
+        let mut y = self.sqrt();
+
+        if !y.is_finite() {
+            return y;
+        }
+
+        let mut i = 0;
+
+        while i < P::NEWTON_ITERATIONS {
+            y = Self(0.5 * (y.0 + self.0 / y.0));
+            i += 1;
+        }
+
+        y
+    }
 }
 
 #[cfg(test)]
 pub(crate) mod tests {
     use super::F32;
+    use crate::precision::{Balanced, Fast, Precise, Precision};
 
     /// Deviation from the actual value (5%)
     pub(crate) const MAX_ERROR: f32 = 0.05;
@@ -67,5 +101,36 @@ pub(crate) mod tests {
     #[test]
     fn negative_is_nan() {
         assert!(F32(-1.0).sqrt().is_nan());
+        assert!(F32(-1.0).sqrt_with::<Precise>().is_nan());
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(F32::ZERO.sqrt_with::<Precise>(), F32::ZERO);
+    }
+
+    fn precision_tier<P: Precision>() {
+        let mut x = 1e-30_f32;
+
+        while x < 1e30 {
+            let expected = x.sqrt();
+            let relative_error = ((F32(x).sqrt_with::<P>() - expected) / expected).abs();
+
+            assert!(
+                relative_error <= P::SQRT_MAX_ERROR,
+                "relative_error {} too large for input {}",
+                relative_error,
+                x
+            );
+
+            x *= 1.001;
+        }
+    }
+
+    #[test]
+    fn precision_tiers() {
+        precision_tier::<Fast>();
+        precision_tier::<Balanced>();
+        precision_tier::<Precise>();
     }
 }
@@ -3,9 +3,14 @@
 use super::F32;
 
 impl F32 {
-    /// Approximates `tan(x)` in radians with a maximum error of `0.6`.
+    /// Approximates `tan(x)` in radians with a maximum error of `0.038` in the
+    /// range `[-1.4, 1.4]`, see [`accuracy::TAN`]. The error grows towards the
+    /// poles.
+    ///
+    /// [`accuracy::TAN`]: crate::accuracy::TAN
     pub fn tan(self) -> Self {
-        self.sin() / self.cos()
+        let (sin, cos) = self.sin_cos();
+        sin / cos
     }
 }
 
@@ -18,6 +23,7 @@ mod tests {
     const MAX_ERROR: f32 = 0.6;
 
     /// Tangent test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
     const TEST_VECTORS: &[(f32, f32)] = &[
         (0.000, 0.000),
         (0.140, 0.141),
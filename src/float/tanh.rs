@@ -0,0 +1,103 @@
+//! Hyperbolic tangent function
+
+use super::F32;
+
+impl F32 {
+    /// Approximates `tanh(x)` with a maximum error of `3.4e-4`, see
+    /// [`accuracy::TANH`].
+    ///
+    /// Computed as `1 - 2 / (e^(2|x|) + 1)` with the sign of `x` restored,
+    /// saturating to `±1` for `|x| > 9`.
+    ///
+    /// [`accuracy::TANH`]: crate::accuracy::TANH
+    pub fn tanh(self) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+
+        let x = self.abs();
+
+        let tanh_x = if x > 9.0 {
+            Self::ONE
+        } else {
+            Self::ONE - 2.0 / ((2.0 * x).exp() + 1.0)
+        };
+
+        tanh_x.copysign(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// Maximum error
+    const MAX_ERROR: f32 = 0.001;
+
+    /// Hyperbolic tangent test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (-4.0, -0.9993293),
+        (-3.75, -0.99889445),
+        (-3.5, -0.9981779),
+        (-3.25, -0.99699765),
+        (-3.0, -0.9950548),
+        (-2.75, -0.99185973),
+        (-2.5, -0.9866143),
+        (-2.25, -0.9780261),
+        (-2.0, -0.9640276),
+        (-1.75, -0.94137555),
+        (-1.5, -0.90514827),
+        (-1.25, -0.84828365),
+        (-1.0, -0.7615942),
+        (-0.75, -0.63514894),
+        (-0.5, -0.46211717),
+        (-0.25, -0.24491866),
+        (0.0, 0.0),
+        (0.25, 0.24491866),
+        (0.5, 0.46211717),
+        (0.75, 0.63514894),
+        (1.0, 0.7615942),
+        (1.25, 0.84828365),
+        (1.5, 0.90514827),
+        (1.75, 0.94137555),
+        (2.0, 0.9640276),
+        (2.25, 0.9780261),
+        (2.5, 0.9866143),
+        (2.75, 0.99185973),
+        (3.0, 0.9950548),
+        (3.25, 0.99699765),
+        (3.5, 0.9981779),
+        (3.75, 0.99889445),
+        (4.0, 0.9993293),
+        (-2e+01, -1.0),
+        (2e+01, 1.0),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let tanh_x = F32(x).tanh();
+            let delta = (tanh_x - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large for input {}: {} vs {}",
+                delta,
+                x,
+                tanh_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn infinity() {
+        assert_eq!(F32::INFINITY.tanh(), F32::ONE);
+        assert_eq!(F32::NEG_INFINITY.tanh(), -F32::ONE);
+    }
+
+    #[test]
+    fn nan() {
+        assert!(F32::NAN.tanh().is_nan());
+    }
+}
@@ -0,0 +1,136 @@
+//! IEEE 754 total ordering of single-precision floats.
+
+use super::F32;
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+impl F32 {
+    /// Returns the ordering between `self` and `other` according to the
+    /// `totalOrder` predicate of IEEE 754:
+    ///
+    /// `-NaN < -∞ < negative numbers < -0.0 < +0.0 < positive numbers < +∞ < +NaN`
+    ///
+    /// Unlike [`PartialOrd`], this orders every value including NaN. See
+    /// [`TotalOrd`] for a wrapper which implements [`Ord`].
+    #[inline]
+    pub fn total_cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// Wrapper around [`F32`] which implements [`Eq`], [`Ord`] and [`Hash`]
+/// using [`F32::total_cmp`], so floats can be sorted or used as keys of
+/// ordered collections.
+///
+/// Two values are equal if and only if their bits are equal, so `-0.0` and
+/// `+0.0` are distinct and NaN is equal to itself.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct TotalOrd(pub F32);
+
+impl Eq for TotalOrd {}
+
+impl PartialEq for TotalOrd {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Ord for TotalOrd {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl PartialOrd for TotalOrd {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hash for TotalOrd {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl From<F32> for TotalOrd {
+    fn from(x: F32) -> Self {
+        Self(x)
+    }
+}
+
+impl From<f32> for TotalOrd {
+    fn from(x: f32) -> Self {
+        Self(F32(x))
+    }
+}
+
+impl From<TotalOrd> for F32 {
+    fn from(x: TotalOrd) -> F32 {
+        x.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TotalOrd, F32};
+    use core::cmp::Ordering;
+
+    /// Values in ascending total order
+    const ORDERED: &[f32] = &[
+        f32::NEG_INFINITY,
+        f32::MIN,
+        -1.0,
+        -1e-45,
+        -0.0,
+        0.0,
+        1e-45,
+        1.0,
+        f32::MAX,
+        f32::INFINITY,
+    ];
+
+    #[test]
+    fn total_cmp() {
+        let nan = F32::NAN;
+        let neg_nan = -F32::NAN;
+
+        for (i, &a) in ORDERED.iter().enumerate() {
+            for (j, &b) in ORDERED.iter().enumerate() {
+                assert_eq!(F32(a).total_cmp(&F32(b)), i.cmp(&j), "{} vs {}", a, b);
+            }
+
+            assert_eq!(F32(a).total_cmp(&nan), Ordering::Less);
+            assert_eq!(F32(a).total_cmp(&neg_nan), Ordering::Greater);
+        }
+
+        assert_eq!(nan.total_cmp(&nan), Ordering::Equal);
+    }
+
+    #[test]
+    fn sort() {
+        let mut values = [
+            TotalOrd::from(1.0),
+            TotalOrd::from(f32::NAN),
+            TotalOrd::from(-0.0),
+            TotalOrd::from(f32::NEG_INFINITY),
+            TotalOrd::from(0.0),
+            TotalOrd::from(-1.0),
+        ];
+
+        values.sort();
+
+        let bits = values.map(|x| x.0.to_bits());
+        let expected = [f32::NEG_INFINITY, -1.0, -0.0, 0.0, 1.0, f32::NAN].map(f32::to_bits);
+        assert_eq!(bits, expected);
+    }
+
+    #[test]
+    fn eq() {
+        assert_eq!(TotalOrd::from(f32::NAN), TotalOrd::from(f32::NAN));
+        assert_ne!(TotalOrd::from(0.0), TotalOrd::from(-0.0));
+        assert_eq!(F32::from(TotalOrd::from(2.0)), F32(2.0));
+    }
+}
@@ -4,7 +4,7 @@ use super::{F32, MANTISSA_MASK};
 
 impl F32 {
     /// Returns the integer part of a number.
-    pub fn trunc(self) -> Self {
+    pub const fn trunc(self) -> Self {
         let x_bits = self.to_bits();
         let exponent = self.extract_exponent_value();
 
@@ -13,7 +13,7 @@ impl F32 {
             return F32::ZERO.copysign(self);
         }
 
-        let exponent_clamped = i32::max(exponent, 0) as u32;
+        let exponent_clamped = exponent as u32;
 
         // find the part of the fraction that would be left over
         let fractional_part = x_bits.overflowing_shl(exponent_clamped).0 & MANTISSA_MASK;
@@ -0,0 +1,69 @@
+//! Unit in the last place of a single-precision float.
+
+use super::{F32, MANTISSA_BITS};
+
+impl F32 {
+    /// Returns the unit in the last place of `self`: the distance between
+    /// `|x|` and the next float of larger magnitude, as if the exponent range
+    /// were unbounded. This is the precision of values in the same binade.
+    ///
+    /// Returns the smallest positive subnormal for zero, [`Self::INFINITY`]
+    /// for infinity and NaN for NaN.
+    pub const fn ulp(self) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+
+        if self.is_infinite() {
+            return Self::INFINITY;
+        }
+
+        let exponent = self.extract_exponent_bits();
+
+        let bits = if exponent > MANTISSA_BITS {
+            (exponent - MANTISSA_BITS) << MANTISSA_BITS
+        } else if exponent > 0 {
+            1 << (exponent - 1)
+        } else {
+            1
+        };
+
+        Self::from_bits(bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+
+    /// `ulp` test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f32, f32)] = &[
+        (1.0, f32::EPSILON),
+        (-1.0, f32::EPSILON),
+        (1.5, f32::EPSILON),
+        (2.0, 2.0 * f32::EPSILON),
+        (16_777_216.0, 2.0),
+        (f32::MAX, 2.028_241e31),
+        (f32::MIN_POSITIVE, 1e-45),
+        (1e-40, 1e-45),
+        (0.0, 1e-45),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            assert_eq!(F32(x).ulp().0, expected, "ulp({})", x);
+        }
+
+        assert_eq!(F32::NEG_INFINITY.ulp(), F32::INFINITY);
+        assert!(F32::NAN.ulp().is_nan());
+    }
+
+    #[test]
+    fn matches_next_up() {
+        for &x in &[1e-30, 0.1, 1.0, 3.0, 1e10, 1e30] {
+            let x = F32(x);
+            assert_eq!(x.next_up() - x, x.ulp());
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! Fixed-point decimal formatting without `core::fmt`.
+
+use super::{EXPONENT_MASK, F32, MANTISSA_BITS, MANTISSA_MASK};
+
+/// Maximum number of decimal places written by [`F32::write_fixed`].
+const MAX_DECIMALS: u32 = 9;
+
+impl F32 {
+    /// Writes the number to `buf` as a decimal string with `decimals` digits
+    /// after the decimal point, returning the number of bytes written, or
+    /// `None` if `buf` is too small.
+    ///
+    /// The output is the same as the `{:.N}` format of `core::fmt`, i.e. the
+    /// exact value rounded half to even, but without the code size of the
+    /// full formatting machinery. At most `9` decimal places are written, and
+    /// larger values of `decimals` are treated as `9`. Infinities and NaN are
+    /// written as `inf`, `-inf` and `NaN`.
+    ///
+    /// ```
+    /// use micromath::F32;
+    ///
+    /// let mut buf = [0u8; 16];
+    /// let len = F32(-3.14159).write_fixed(&mut buf, 2).unwrap();
+    /// assert_eq!(&buf[..len], b"-3.14");
+    /// ```
+    pub fn write_fixed(self, buf: &mut [u8], decimals: u32) -> Option<usize> {
+        let mut writer = Writer { buf, len: 0 };
+
+        if self.is_nan() {
+            writer.push_bytes(b"NaN")?;
+            return Some(writer.len);
+        }
+
+        if self.is_sign_negative() {
+            writer.push(b'-')?;
+        }
+
+        if self.is_infinite() {
+            writer.push_bytes(b"inf")?;
+            return Some(writer.len);
+        }
+
+        let decimals = if decimals > MAX_DECIMALS {
+            MAX_DECIMALS
+        } else {
+            decimals
+        };
+
+        let (mut integer, mut fraction) = self.abs().split_fixed(decimals);
+        let scale = 10u64.pow(decimals);
+
+        if fraction == scale {
+            integer += 1;
+            fraction = 0;
+        }
+
+        // Integer digits are produced in reverse order
+        let mut digits = [0u8; 39];
+        let mut count = 0;
+
+        loop {
+            digits[count] = b'0' + (integer % 10) as u8;
+            integer /= 10;
+            count += 1;
+
+            if integer == 0 {
+                break;
+            }
+        }
+
+        while count > 0 {
+            count -= 1;
+            writer.push(digits[count])?;
+        }
+
+        if decimals > 0 {
+            writer.push(b'.')?;
+            let mut divisor = scale;
+
+            while divisor > 1 {
+                divisor /= 10;
+                writer.push(b'0' + (fraction / divisor % 10) as u8)?;
+            }
+        }
+
+        Some(writer.len)
+    }
+
+    /// Splits a finite, positive number into its integer part and its
+    /// fractional part scaled by `10^decimals`, rounded half to even.
+    ///
+    /// The fractional part may round up to `10^decimals`.
+    fn split_fixed(self, decimals: u32) -> (u128, u64) {
+        let bits = self.to_bits();
+        let biased_exponent = (bits & EXPONENT_MASK) >> MANTISSA_BITS;
+
+        // `self = mantissa * 2^exponent`
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (bits & MANTISSA_MASK, -149)
+        } else {
+            (
+                (bits & MANTISSA_MASK) | (1 << MANTISSA_BITS),
+                biased_exponent as i32 - 150,
+            )
+        };
+
+        let mantissa = u128::from(mantissa);
+
+        if exponent >= 0 {
+            return (mantissa << exponent, 0);
+        }
+
+        // Number of fractional bits
+        let shift = -exponent as u32;
+
+        if shift > 64 {
+            // `self < 2^-40`, which rounds to zero with at most 9 decimals
+            return (0, 0);
+        }
+
+        let integer = mantissa >> shift;
+        let scaled = (mantissa & ((1 << shift) - 1)) * u128::from(10u64.pow(decimals));
+        let mut fraction = (scaled >> shift) as u64;
+        let remainder = scaled & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+
+        let last_digit_odd = if decimals == 0 {
+            integer % 2 == 1
+        } else {
+            fraction % 2 == 1
+        };
+
+        if remainder > half || (remainder == half && last_digit_odd) {
+            fraction += 1;
+        }
+
+        (integer, fraction)
+    }
+}
+
+/// Appends bytes to a buffer, failing once it's full.
+struct Writer<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Writer<'_> {
+    fn push(&mut self, byte: u8) -> Option<()> {
+        *self.buf.get_mut(self.len)? = byte;
+        self.len += 1;
+        Some(())
+    }
+
+    fn push_bytes(&mut self, bytes: &[u8]) -> Option<()> {
+        for &byte in bytes {
+            self.push(byte)?;
+        }
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F32;
+    use core::fmt::{self, Write};
+
+    /// Buffer formatted into by `core::fmt` for comparison.
+    struct Buffer {
+        bytes: [u8; 64],
+        len: usize,
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    /// Checks `write_fixed` against the `{:.N}` format of `core::fmt`.
+    fn check(x: f32, decimals: u32) {
+        let mut expected = Buffer {
+            bytes: [0; 64],
+            len: 0,
+        };
+        write!(expected, "{:.*}", decimals as usize, x).unwrap();
+
+        let mut actual = [0u8; 64];
+        let len = F32(x).write_fixed(&mut actual, decimals).unwrap();
+
+        assert_eq!(
+            &actual[..len],
+            &expected.bytes[..expected.len],
+            "{} with {} decimals",
+            x,
+            decimals
+        );
+    }
+
+    #[test]
+    fn matches_core() {
+        const TEST_VECTORS: &[f32] = &[
+            0.0,
+            -0.0,
+            1.0,
+            -1.0,
+            0.5,
+            1.5,
+            2.5,
+            0.125,
+            0.375,
+            0.0625,
+            9.999_5,
+            99.95,
+            0.001,
+            -0.001,
+            123.456,
+            -7.389_056,
+            1e-10,
+            1e-45,
+            16_777_217.0,
+            4.294_967e9,
+            1e20,
+            -1e30,
+            f32::MAX,
+            f32::MIN,
+            f32::MIN_POSITIVE,
+            f32::EPSILON,
+        ];
+
+        for decimals in 0..=9 {
+            for &x in TEST_VECTORS {
+                check(x, decimals);
+            }
+
+            for i in -1000..=1000 {
+                check(i as f32 * 0.013_7, decimals);
+            }
+        }
+    }
+
+    #[test]
+    fn special_values() {
+        let mut buf = [0u8; 8];
+
+        let len = F32::NAN.write_fixed(&mut buf, 2).unwrap();
+        assert_eq!(&buf[..len], b"NaN");
+
+        let len = F32::INFINITY.write_fixed(&mut buf, 2).unwrap();
+        assert_eq!(&buf[..len], b"inf");
+
+        let len = F32::NEG_INFINITY.write_fixed(&mut buf, 2).unwrap();
+        assert_eq!(&buf[..len], b"-inf");
+    }
+
+    #[test]
+    fn max_decimals() {
+        let mut buf = [0u8; 16];
+        let len = F32(0.5).write_fixed(&mut buf, 20).unwrap();
+        assert_eq!(&buf[..len], b"0.500000000");
+    }
+
+    #[test]
+    fn buffer_too_small() {
+        let mut buf = [0u8; 4];
+        assert_eq!(F32(-1.25).write_fixed(&mut buf, 1), Some(4));
+        assert_eq!(F32(-1.25).write_fixed(&mut buf, 2), None);
+        assert_eq!(F32(12345.0).write_fixed(&mut buf, 0), None);
+    }
+}
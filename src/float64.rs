@@ -0,0 +1,744 @@
+//! Double-precision floating point operations
+
+pub(crate) mod abs;
+pub(crate) mod acos;
+pub(crate) mod acosh;
+pub(crate) mod asin;
+pub(crate) mod asinh;
+pub(crate) mod atan;
+pub(crate) mod atan2;
+pub(crate) mod atanh;
+pub(crate) mod cbrt;
+pub(crate) mod ceil;
+pub(crate) mod copysign;
+pub(crate) mod cos;
+pub(crate) mod cosh;
+pub(crate) mod div_euclid;
+pub(crate) mod exp;
+pub(crate) mod exp2;
+pub(crate) mod exp_m1;
+pub(crate) mod floor;
+pub(crate) mod fract;
+pub(crate) mod hypot;
+pub(crate) mod inv;
+pub(crate) mod invsqrt;
+pub(crate) mod ln;
+pub(crate) mod ln_1p;
+pub(crate) mod log;
+pub(crate) mod log10;
+pub(crate) mod log2;
+pub(crate) mod mul_add;
+pub(crate) mod nth_root;
+pub(crate) mod powf;
+pub(crate) mod powi;
+pub(crate) mod recip;
+pub(crate) mod rem_euclid;
+pub(crate) mod rem_pio2;
+pub(crate) mod round;
+pub(crate) mod scalbn;
+pub(crate) mod signum;
+pub(crate) mod sin;
+pub(crate) mod sin_cos;
+pub(crate) mod sinh;
+pub(crate) mod sqrt;
+pub(crate) mod tan;
+pub(crate) mod tanh;
+pub(crate) mod trunc;
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, LowerExp, UpperExp},
+    iter::{Product, Sum},
+    num::ParseFloatError,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::F32;
+
+#[cfg(feature = "num-traits")]
+use num_traits::{Inv, Num, One, Zero};
+
+/// Sign mask.
+pub(crate) const SIGN_MASK: u64 = 0x8000_0000_0000_0000;
+
+/// Exponent mask.
+pub(crate) const EXPONENT_MASK: u64 = 0x7ff0_0000_0000_0000;
+
+/// Mantissa mask.
+pub(crate) const MANTISSA_MASK: u64 = 0x000f_ffff_ffff_ffff;
+
+/// Exponent mask.
+pub(crate) const EXPONENT_BIAS: u32 = 1023;
+
+/// Mantissa bits.
+///
+/// Note: `MANTISSA_DIGITS` is available in `core::f64`, but the actual bits taken up are 53 - 1.
+pub(crate) const MANTISSA_BITS: u32 = 52;
+
+/// `2^54`, which scales subnormal numbers into the normal range.
+pub(crate) const TWO_POW_54: f64 = f64::from_bits(0x4350_0000_0000_0000);
+
+/// 64-bit floating point wrapper which implements fast approximation-based
+/// operations.
+///
+/// This mirrors the [`F32`][`crate::F32`] API, but with double-precision
+/// kernels: argument reduction and polynomial degrees are chosen for the
+/// `f64` mantissa, so results are generally within a couple of ULPs. The
+/// exceptions are [`F64::inv`], which is a deliberately coarse bit-level
+/// estimate, and [`F64::powf`], whose error grows with `|n * ln(x)|`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct F64(pub f64);
+
+impl F64 {
+    /// The value `0.0`.
+    pub const ZERO: Self = Self(0.0);
+
+    /// The value `1.0`.
+    pub const ONE: Self = Self(1.0);
+
+    /// The radix or base of the internal representation of `f64`.
+    pub const RADIX: u32 = f64::RADIX;
+
+    /// Number of significant digits in base 2.
+    pub const MANTISSA_DIGITS: u32 = f64::MANTISSA_DIGITS;
+
+    /// Approximate number of significant digits in base 10.
+    pub const DIGITS: u32 = f64::DIGITS;
+
+    /// [Machine epsilon] value for `f64`.
+    ///
+    /// This is the difference between `1.0` and the next larger representable number.
+    ///
+    /// [Machine epsilon]: https://en.wikipedia.org/wiki/Machine_epsilon
+    pub const EPSILON: Self = Self(f64::EPSILON);
+
+    /// Smallest finite `f64` value.
+    pub const MIN: Self = Self(f64::MIN);
+
+    /// Smallest positive normal `f64` value.
+    pub const MIN_POSITIVE: Self = Self(f64::MIN_POSITIVE);
+
+    /// Largest finite `f64` value.
+    pub const MAX: Self = Self(f64::MAX);
+
+    /// One greater than the minimum possible normal power of 2 exponent.
+    pub const MIN_EXP: i32 = f64::MIN_EXP;
+
+    /// Maximum possible power of 2 exponent.
+    pub const MAX_EXP: i32 = f64::MAX_EXP;
+
+    /// Minimum possible normal power of 10 exponent.
+    pub const MIN_10_EXP: i32 = f64::MIN_10_EXP;
+
+    /// Maximum possible power of 10 exponent.
+    pub const MAX_10_EXP: i32 = f64::MAX_10_EXP;
+
+    /// Not a Number (NaN).
+    pub const NAN: Self = Self(f64::NAN);
+
+    /// Infinity (∞).
+    pub const INFINITY: Self = Self(f64::INFINITY);
+
+    /// Negative infinity (−∞).
+    pub const NEG_INFINITY: Self = Self(f64::NEG_INFINITY);
+
+    /// Returns `true` if this value is `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.0.is_nan()
+    }
+
+    /// Returns `true` if this value is positive infinity or negative infinity, and
+    /// `false` otherwise.
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        self.0.is_infinite()
+    }
+
+    /// Returns `true` if this number is neither infinite nor `NaN`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// Returns `true` if `self` has a positive sign, including `+0.0`, `NaN`s with
+    /// positive sign bit and positive infinity.
+    #[inline]
+    pub fn is_sign_positive(self) -> bool {
+        self.0.is_sign_positive()
+    }
+
+    /// Returns `true` if `self` has a negative sign, including `-0.0`, `NaN`s with
+    /// negative sign bit and negative infinity.
+    #[inline]
+    pub fn is_sign_negative(self) -> bool {
+        self.0.is_sign_negative()
+    }
+
+    /// Raw transmutation to `u64`.
+    ///
+    /// This is currently identical to `transmute::<f64, u64>(self)` on all platforms.
+    ///
+    /// See [`F64::from_bits`] for some discussion of the portability of this operation
+    /// (there are almost no issues).
+    #[inline]
+    pub fn to_bits(self) -> u64 {
+        self.0.to_bits()
+    }
+
+    /// Raw transmutation from `u64`.
+    ///
+    /// This is currently identical to `transmute::<u64, f64>(v)` on all platforms.
+    /// It turns out this is incredibly portable, for two reasons:
+    ///
+    /// - Floats and Ints have the same endianness on all supported platforms.
+    /// - IEEE-754 very precisely specifies the bit layout of floats.
+    ///
+    /// See [`f64::from_bits`] for more information.
+    #[inline]
+    pub fn from_bits(v: u64) -> Self {
+        Self(f64::from_bits(v))
+    }
+
+    /// Extract exponent bits.
+    pub(crate) fn extract_exponent_bits(self) -> u32 {
+        (self.to_bits() & EXPONENT_MASK)
+            .overflowing_shr(MANTISSA_BITS)
+            .0 as u32
+    }
+
+    /// Extract the exponent of a float's value.
+    pub(crate) fn extract_exponent_value(self) -> i32 {
+        (self.extract_exponent_bits() as i32) - EXPONENT_BIAS as i32
+    }
+
+    /// Remove sign.
+    pub(crate) fn without_sign(self) -> Self {
+        Self::from_bits(self.to_bits() & !SIGN_MASK)
+    }
+
+    /// Set the exponent to the given value.
+    pub(crate) fn set_exponent(self, exponent: i32) -> Self {
+        debug_assert!((-1024..=1023).contains(&exponent));
+        let without_exponent: u64 = self.to_bits() & !EXPONENT_MASK;
+        let only_exponent: u64 = ((exponent + EXPONENT_BIAS as i32) as u64)
+            .overflowing_shl(MANTISSA_BITS)
+            .0;
+
+        Self::from_bits(without_exponent | only_exponent)
+    }
+
+    /// Is this floating point value equivalent to an integer?
+    pub(crate) fn is_integer(&self) -> bool {
+        let exponent = self.extract_exponent_value();
+        let self_bits = self.to_bits();
+
+        // all of the mantissa bits are whole number bits (this also covers NaN and infinity)
+        if exponent >= MANTISSA_BITS as i32 {
+            return true;
+        }
+
+        // if exponent is negative we shouldn't remove anything, this stops an opposite shift.
+        let exponent_clamped = i32::max(exponent, 0) as u32;
+
+        // find the part of the fraction that would be left over
+        let fractional_part = (self_bits).overflowing_shl(exponent_clamped).0 & MANTISSA_MASK;
+
+        // if fractional part contains anything, we know it *isn't* an integer.
+        // if zero there will be nothing in the fractional part
+        // if it is whole, there will be nothing in the fractional part
+        fractional_part == 0
+    }
+
+    /// Is this floating point value even?
+    fn is_even(&self) -> bool {
+        // any floating point value that doesn't fit in an i64 range is even,
+        // and will lose 1's digit precision at exp values of 52+
+        if self.extract_exponent_value() >= 63 {
+            true
+        } else {
+            (self.0 as i64) % 2 == 0
+        }
+    }
+}
+
+impl Add for F64 {
+    type Output = F64;
+
+    #[inline]
+    fn add(self, rhs: F64) -> F64 {
+        F64(self.0 + rhs.0)
+    }
+}
+
+impl Add<f64> for F64 {
+    type Output = F64;
+
+    #[inline]
+    fn add(self, rhs: f64) -> F64 {
+        F64(self.0 + rhs)
+    }
+}
+
+impl Add<F64> for f64 {
+    type Output = F64;
+
+    #[inline]
+    fn add(self, rhs: F64) -> F64 {
+        F64(self + rhs.0)
+    }
+}
+
+impl AddAssign for F64 {
+    #[inline]
+    fn add_assign(&mut self, rhs: F64) {
+        self.0 += rhs.0;
+    }
+}
+
+impl AddAssign<f64> for F64 {
+    #[inline]
+    fn add_assign(&mut self, rhs: f64) {
+        self.0 += rhs;
+    }
+}
+
+impl AddAssign<F64> for f64 {
+    #[inline]
+    fn add_assign(&mut self, rhs: F64) {
+        *self += rhs.0;
+    }
+}
+
+impl Display for F64 {
+    #[inline]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}", self.0)
+    }
+}
+
+impl Div for F64 {
+    type Output = F64;
+
+    #[inline]
+    fn div(self, rhs: F64) -> F64 {
+        F64(self.0 / rhs.0)
+    }
+}
+
+impl Div<f64> for F64 {
+    type Output = F64;
+
+    #[inline]
+    fn div(self, rhs: f64) -> F64 {
+        F64(self.0 / rhs)
+    }
+}
+
+impl Div<F64> for f64 {
+    type Output = F64;
+
+    #[inline]
+    fn div(self, rhs: F64) -> F64 {
+        F64(self / rhs.0)
+    }
+}
+
+impl DivAssign for F64 {
+    #[inline]
+    fn div_assign(&mut self, rhs: F64) {
+        self.0 /= rhs.0;
+    }
+}
+
+impl DivAssign<f64> for F64 {
+    #[inline]
+    fn div_assign(&mut self, rhs: f64) {
+        self.0 /= rhs;
+    }
+}
+
+impl DivAssign<F64> for f64 {
+    #[inline]
+    fn div_assign(&mut self, rhs: F64) {
+        *self /= rhs.0;
+    }
+}
+
+impl From<f64> for F64 {
+    #[inline]
+    fn from(n: f64) -> F64 {
+        F64(n)
+    }
+}
+
+impl From<F64> for f64 {
+    #[inline]
+    fn from(n: F64) -> f64 {
+        n.0
+    }
+}
+
+impl From<i8> for F64 {
+    #[inline]
+    fn from(n: i8) -> F64 {
+        F64(n.into())
+    }
+}
+
+impl From<i16> for F64 {
+    #[inline]
+    fn from(n: i16) -> F64 {
+        F64(n.into())
+    }
+}
+
+impl From<u8> for F64 {
+    #[inline]
+    fn from(n: u8) -> F64 {
+        F64(n.into())
+    }
+}
+
+impl From<u16> for F64 {
+    #[inline]
+    fn from(n: u16) -> F64 {
+        F64(n.into())
+    }
+}
+
+impl From<i32> for F64 {
+    #[inline]
+    fn from(n: i32) -> F64 {
+        F64(n.into())
+    }
+}
+
+impl From<u32> for F64 {
+    #[inline]
+    fn from(n: u32) -> F64 {
+        F64(n.into())
+    }
+}
+
+impl From<f32> for F64 {
+    #[inline]
+    fn from(n: f32) -> F64 {
+        F64(n.into())
+    }
+}
+
+impl From<F32> for F64 {
+    #[inline]
+    fn from(n: F32) -> F64 {
+        F64(n.0.into())
+    }
+}
+
+impl FromStr for F64 {
+    type Err = ParseFloatError;
+
+    #[inline]
+    fn from_str(src: &str) -> Result<F64, ParseFloatError> {
+        f64::from_str(src).map(F64)
+    }
+}
+
+impl LowerExp for F64 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:e}", self.0)
+    }
+}
+
+impl Mul for F64 {
+    type Output = F64;
+
+    #[inline]
+    fn mul(self, rhs: F64) -> F64 {
+        F64(self.0 * rhs.0)
+    }
+}
+
+impl Mul<f64> for F64 {
+    type Output = F64;
+
+    #[inline]
+    fn mul(self, rhs: f64) -> F64 {
+        F64(self.0 * rhs)
+    }
+}
+
+impl Mul<F64> for f64 {
+    type Output = F64;
+
+    #[inline]
+    fn mul(self, rhs: F64) -> F64 {
+        F64(self * rhs.0)
+    }
+}
+
+impl MulAssign for F64 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: F64) {
+        self.0 *= rhs.0;
+    }
+}
+
+impl MulAssign<f64> for F64 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        self.0 *= rhs;
+    }
+}
+
+impl MulAssign<F64> for f64 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: F64) {
+        *self *= rhs.0;
+    }
+}
+
+impl Neg for F64 {
+    type Output = F64;
+
+    #[inline]
+    fn neg(self) -> F64 {
+        F64(-self.0)
+    }
+}
+
+impl PartialEq<f64> for F64 {
+    fn eq(&self, other: &f64) -> bool {
+        self.0.eq(other)
+    }
+}
+
+impl PartialEq<F64> for f64 {
+    fn eq(&self, other: &F64) -> bool {
+        self.eq(&other.0)
+    }
+}
+
+impl PartialOrd<f64> for F64 {
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl PartialOrd<F64> for f64 {
+    fn partial_cmp(&self, other: &F64) -> Option<Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl Product for F64 {
+    #[inline]
+    fn product<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = F64>,
+    {
+        F64(f64::product(iter.map(f64::from)))
+    }
+}
+
+impl Rem for F64 {
+    type Output = F64;
+
+    #[inline]
+    fn rem(self, rhs: F64) -> F64 {
+        F64(self.0 % rhs.0)
+    }
+}
+
+impl Rem<f64> for F64 {
+    type Output = F64;
+
+    #[inline]
+    fn rem(self, rhs: f64) -> F64 {
+        F64(self.0 % rhs)
+    }
+}
+
+impl Rem<F64> for f64 {
+    type Output = F64;
+
+    #[inline]
+    fn rem(self, rhs: F64) -> F64 {
+        F64(self % rhs.0)
+    }
+}
+
+impl RemAssign for F64 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: F64) {
+        self.0 %= rhs.0;
+    }
+}
+
+impl RemAssign<f64> for F64 {
+    #[inline]
+    fn rem_assign(&mut self, rhs: f64) {
+        self.0 %= rhs;
+    }
+}
+
+impl Sub for F64 {
+    type Output = F64;
+
+    #[inline]
+    fn sub(self, rhs: F64) -> F64 {
+        F64(self.0 - rhs.0)
+    }
+}
+
+impl Sub<f64> for F64 {
+    type Output = F64;
+
+    #[inline]
+    fn sub(self, rhs: f64) -> F64 {
+        F64(self.0 - rhs)
+    }
+}
+
+impl Sub<F64> for f64 {
+    type Output = F64;
+
+    #[inline]
+    fn sub(self, rhs: F64) -> F64 {
+        F64(self - rhs.0)
+    }
+}
+
+impl SubAssign for F64 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: F64) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl SubAssign<f64> for F64 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: f64) {
+        self.0 -= rhs;
+    }
+}
+
+impl SubAssign<F64> for f64 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: F64) {
+        *self -= rhs.0;
+    }
+}
+
+impl Sum for F64 {
+    #[inline]
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = F64>,
+    {
+        F64(f64::sum(iter.map(f64::from)))
+    }
+}
+
+impl UpperExp for F64 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:E}", self.0)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+impl Zero for F64 {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        Self::ZERO == *self
+    }
+}
+
+#[cfg(feature = "num-traits")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+impl One for F64 {
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn is_one(&self) -> bool {
+        Self::ONE == *self
+    }
+}
+
+#[cfg(feature = "num-traits")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+impl Num for F64 {
+    type FromStrRadixErr = num_traits::ParseFloatError;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        f64::from_str_radix(str, radix).map(Self)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+impl Inv for F64 {
+    type Output = Self;
+
+    fn inv(self) -> Self {
+        self.inv()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+impl defmt::Format for F64 {
+    fn format(&self, fmt: defmt::Formatter<'_>) {
+        defmt::write!(fmt, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::F64;
+
+    /// Checks `f` against the `std` implementation `reference` at `count`
+    /// inputs spread evenly over `[min, max]`, asserting that the relative
+    /// error is at most `max_error`.
+    pub(crate) fn check_against_std(
+        f: fn(F64) -> F64,
+        reference: fn(f64) -> f64,
+        (min, max): (f64, f64),
+        count: u32,
+        max_error: f64,
+    ) {
+        for i in 0..=count {
+            let x = min + (max - min) * f64::from(i) / f64::from(count);
+            let actual = f(F64(x)).0;
+            let expected = reference(x);
+            let relative_error = if actual == expected {
+                0.0
+            } else {
+                ((actual - expected) / expected).abs()
+            };
+
+            assert!(
+                relative_error <= max_error,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn inv_trait() {
+        assert_eq!(num_traits::Inv::inv(F64(2.0)), F64(0.5));
+    }
+}
@@ -0,0 +1,31 @@
+//! Compute the absolute value of a double-precision float.
+//!
+//! Method described at: <https://bits.stephan-brumme.com/absFloat.html>
+
+use super::{F64, SIGN_MASK};
+
+impl F64 {
+    /// Computes the absolute value of `self`.
+    ///
+    /// Returns [`Self::NAN`] if the number is [`Self::NAN`].
+    pub fn abs(self) -> Self {
+        Self::from_bits(self.to_bits() & !SIGN_MASK)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F64::ONE.abs(), 1.0);
+        assert_eq!(F64::ZERO.abs(), 0.0);
+        assert_eq!(F64(-1.0).abs(), 1.0);
+    }
+
+    #[test]
+    fn nan() {
+        assert!(F64::NAN.abs().is_nan());
+    }
+}
@@ -0,0 +1,73 @@
+//! arccos approximation for a double-precision float.
+
+use super::F64;
+
+impl F64 {
+    /// Computes `acos(x)` approximation in radians in the range `[0, pi]`,
+    /// with a maximum error of `1e-15`.
+    ///
+    /// Computed as `atan2(sqrt(1 - x²), x)`, where `1 - x²` is factored as
+    /// `(1 - x) * (1 + x)` to avoid cancellation near `±1`.
+    pub(crate) fn acos(self) -> Self {
+        ((Self::ONE - self) * (Self::ONE + self)).sqrt().atan2(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use core::f64::consts;
+
+    const MAX_ERROR: f64 = 1e-15;
+
+    #[test]
+    fn sanity_check() {
+        // Arccosine test vectors - `(input, output)`
+        let test_vectors: &[(f64, f64)] = &[
+            (2.000, f64::NAN),
+            (1.000, 0.0),
+            (0.75f64.sqrt(), consts::FRAC_PI_6),
+            (consts::FRAC_1_SQRT_2, consts::FRAC_PI_4),
+            (0.500, consts::FRAC_PI_3),
+            (f64::EPSILON, consts::FRAC_PI_2),
+            (0.000, consts::FRAC_PI_2),
+            (-f64::EPSILON, consts::FRAC_PI_2),
+            (-0.500, 2.0 * consts::FRAC_PI_3),
+            (-consts::FRAC_1_SQRT_2, 3.0 * consts::FRAC_PI_4),
+            (-(0.75f64.sqrt()), 5.0 * consts::FRAC_PI_6),
+            (-1.000, consts::PI),
+            (-2.000, f64::NAN),
+        ];
+
+        for &(x, expected) in test_vectors {
+            let actual = F64(x).acos();
+            if expected.is_nan() {
+                assert!(
+                    actual.is_nan(),
+                    "acos({}) returned {}, should be NAN",
+                    x,
+                    actual
+                );
+            } else {
+                let delta = (actual - expected).abs();
+
+                assert!(
+                    delta <= MAX_ERROR,
+                    "delta {} too large: {} vs {}",
+                    delta,
+                    actual,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn matches_std() {
+        for i in -1000..=1000 {
+            let x = f64::from(i) / 1000.0;
+            let delta = (F64(x).acos().0 - x.acos()).abs();
+            assert!(delta <= MAX_ERROR, "delta {} too large for {}", delta, x);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Inverse hyperbolic cosine function
+
+use super::F64;
+use core::f64::consts::LN_2;
+
+/// `2^28`, above which `sqrt(x² - 1)` rounds to `x`.
+const TWO_POW_28: f64 = 268_435_456.0;
+
+impl F64 {
+    /// Approximates `acosh(x)` with a maximum relative error of `5e-16`.
+    ///
+    /// Computed as `ln(1 + t + sqrt(2t + t²))` where `t = x - 1`, which is
+    /// `ln(x + sqrt(x² - 1))` rearranged so that [`F64::ln_1p`] keeps the
+    /// relative error small near `1`.
+    ///
+    /// Returns [`Self::NAN`] if `self` is less than `1.0`.
+    pub fn acosh(self) -> Self {
+        if self.is_nan() || self < Self::ONE {
+            Self::NAN
+        } else if self > TWO_POW_28 {
+            // `x² - 1` could overflow, and `sqrt(x² - 1) ≈ x`
+            self.ln() + LN_2
+        } else {
+            let t = self - 1.0;
+            (t + (2.0 * t + t * t).sqrt()).ln_1p()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    /// Maximum relative error
+    const MAX_ERROR: f64 = 5e-16;
+
+    /// Inverse hyperbolic cosine test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f64, f64)] = &[
+        (1.0, 0.0),
+        (1.001, 0.044_717_633_608_306_84),
+        (1.01, 0.141_303_769_485_648_66),
+        (1.1, 0.443_568_254_385_115_4),
+        (1.5, 0.962_423_650_119_206_9),
+        (2.0, 1.316_957_896_924_816_6),
+        (3.0, 1.762_747_174_039_086),
+        (5.0, 2.292_431_669_561_177_7),
+        (1e+01, 2.993_222_846_126_381),
+        (1e+02, 5.298_292_365_610_484),
+        (1e+03, 7.600_902_209_541_989),
+        (1e+10, 23.718_998_110_500_4),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let acosh_x = F64(x).acosh();
+            let relative_error = if expected == 0.0 {
+                acosh_x.abs()
+            } else {
+                ((acosh_x - expected) / expected).abs()
+            };
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                acosh_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(F64(0.5).acosh().is_nan());
+        assert!(F64(-1.0).acosh().is_nan());
+        assert!(F64::NAN.acosh().is_nan());
+    }
+
+    #[test]
+    fn matches_std() {
+        // `std` computes `ln(x + sqrt(x² - 1))` directly, which loses precision
+        // close to `1`, so compare against the `ln_1p` form there.
+        let reference = |x: f64| (x - 1.0 + ((x - 1.0) * (x + 1.0)).sqrt()).ln_1p();
+        check_against_std(F64::acosh, reference, (1.0, 2.0), 100_000, MAX_ERROR);
+        check_against_std(F64::acosh, f64::acosh, (1.0, 1e10), 100_000, MAX_ERROR);
+        check_against_std(F64::acosh, f64::acosh, (1.0, 1e300), 100_000, MAX_ERROR);
+    }
+
+    #[test]
+    fn infinity() {
+        assert_eq!(F64::INFINITY.acosh(), F64::INFINITY);
+        assert!(F64::NEG_INFINITY.acosh().is_nan());
+    }
+}
@@ -0,0 +1,41 @@
+//! arcsin approximation for a double-precision float.
+
+use super::F64;
+
+impl F64 {
+    /// Computes `asin(x)` approximation in radians in the range `[-pi/2, pi/2]`,
+    /// with a maximum error of `7e-16`.
+    ///
+    /// Computed as `atan2(x, sqrt(1 - x²))`, where `1 - x²` is factored as
+    /// `(1 - x) * (1 + x)` to avoid cancellation near `±1`.
+    ///
+    /// Returns [`Self::NAN`] if `|self| > 1`.
+    pub fn asin(self) -> Self {
+        self.atan2(((Self::ONE - self) * (Self::ONE + self)).sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+    use core::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn sanity_check() {
+        let difference = F64(FRAC_PI_2).sin().asin() - FRAC_PI_2;
+        assert!(difference.abs() <= F64::EPSILON);
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::asin, f64::asin, (-1.0, 1.0), 100_000, 7e-16);
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(F64(1.001).asin().is_nan());
+        assert!(F64(-2.0).asin().is_nan());
+        assert!(F64::NAN.asin().is_nan());
+    }
+}
@@ -0,0 +1,101 @@
+//! Inverse hyperbolic sine function
+
+use super::F64;
+use core::f64::consts::LN_2;
+
+/// `2^28`, above which `sqrt(x² + 1)` rounds to `x`.
+const TWO_POW_28: f64 = 268_435_456.0;
+
+impl F64 {
+    /// Approximates `asinh(x)` with a maximum relative error of `5e-16`.
+    ///
+    /// Computed as `ln(1 + |x| + x² / (1 + sqrt(x² + 1)))` with the sign of
+    /// `x` restored, which is `ln(|x| + sqrt(x² + 1))` rearranged so that
+    /// [`F64::ln_1p`] keeps the relative error small near zero.
+    pub fn asinh(self) -> Self {
+        let x = self.abs();
+
+        let asinh_x = if x > TWO_POW_28 {
+            // `x² + 1` could overflow, and `sqrt(x² + 1) ≈ x`
+            x.ln() + LN_2
+        } else {
+            let x2 = x * x;
+            (x + x2 / (1.0 + (x2 + 1.0).sqrt())).ln_1p()
+        };
+
+        asinh_x.copysign(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    /// Maximum relative error
+    const MAX_ERROR: f64 = 5e-16;
+
+    /// Inverse hyperbolic sine test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f64, f64)] = &[
+        (-1e+10, -23.718_998_110_500_4),
+        (-1e+03, -7.600_902_709_541_988),
+        (-1e+02, -5.298_342_365_610_589),
+        (-1e+01, -2.998_222_950_297_97),
+        (-2.0, -1.443_635_475_178_810_3),
+        (-1.0, -0.881_373_587_019_543),
+        (-0.5, -0.481_211_825_059_603_47),
+        (-0.25, -0.247_466_461_547_263_46),
+        (-0.1, -0.099_834_078_899_207_58),
+        (-0.01, -0.009_999_833_340_832_886),
+        (0.0, 0.0),
+        (0.01, 0.009_999_833_340_832_886),
+        (0.1, 0.099_834_078_899_207_58),
+        (0.25, 0.247_466_461_547_263_46),
+        (0.5, 0.481_211_825_059_603_47),
+        (1.0, 0.881_373_587_019_543),
+        (2.0, 1.443_635_475_178_810_3),
+        (1e+01, 2.998_222_950_297_97),
+        (1e+02, 5.298_342_365_610_589),
+        (1e+03, 7.600_902_709_541_988),
+        (1e+10, 23.718_998_110_500_4),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let asinh_x = F64(x).asinh();
+            let relative_error = if expected == 0.0 {
+                asinh_x.abs()
+            } else {
+                ((asinh_x - expected) / expected).abs()
+            };
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                asinh_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::asinh, f64::asinh, (-1.0, 1.0), 100_000, MAX_ERROR);
+        check_against_std(F64::asinh, f64::asinh, (-1e10, 1e10), 100_000, MAX_ERROR);
+        check_against_std(F64::asinh, f64::asinh, (-1e300, 1e300), 100_000, MAX_ERROR);
+    }
+
+    #[test]
+    fn infinity() {
+        assert_eq!(F64::INFINITY.asinh(), F64::INFINITY);
+        assert_eq!(F64::NEG_INFINITY.asinh(), F64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn nan() {
+        assert!(F64::NAN.asinh().is_nan());
+    }
+}
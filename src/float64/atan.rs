@@ -0,0 +1,125 @@
+//! Arctangent approximation for a double-precision float.
+//!
+//! The argument is reduced to `[0, 1]` with `atan(x) = π/2 - atan(1/x)`, and
+//! then to `|t| <= tan(π/12)` with `atan(t) = π/6 + atan((√3·t - 1) / (√3 + t))`,
+//! where the Taylor series converges quickly.
+
+use super::F64;
+use crate::poly;
+use core::f64::consts::{FRAC_2_PI, FRAC_PI_2, FRAC_PI_6};
+
+/// `tan(π/12)`, i.e. `2 - √3`.
+const TAN_PI_12: f64 = 0.267_949_192_431_122_7;
+
+/// `√3`
+const SQRT_3: f64 = 1.732_050_807_568_877_2;
+
+/// Coefficients of the Taylor series of `atan(t) / t` in terms of `t²`, i.e.
+/// `(-1)^n / (2n + 1)`.
+const ATAN_SERIES: [f64; 15] = [
+    1.0,
+    -1.0 / 3.0,
+    1.0 / 5.0,
+    -1.0 / 7.0,
+    1.0 / 9.0,
+    -1.0 / 11.0,
+    1.0 / 13.0,
+    -1.0 / 15.0,
+    1.0 / 17.0,
+    -1.0 / 19.0,
+    1.0 / 21.0,
+    -1.0 / 23.0,
+    1.0 / 25.0,
+    -1.0 / 27.0,
+    1.0 / 29.0,
+];
+
+impl F64 {
+    /// Approximates `atan(x)` in radians with a maximum relative error of
+    /// `5e-16`.
+    ///
+    /// Returns [`Self::NAN`] if the number is [`Self::NAN`].
+    pub fn atan(self) -> Self {
+        let x = self.abs();
+
+        let (invert, t) = if x > 1.0 {
+            (true, Self::ONE / x)
+        } else {
+            (false, x)
+        };
+
+        let (offset, t) = if t > TAN_PI_12 {
+            (FRAC_PI_6, (SQRT_3 * t - 1.0) / (SQRT_3 + t))
+        } else {
+            (0.0, t)
+        };
+
+        let atan_t = offset + t * poly::horner_f64(t * t, &ATAN_SERIES);
+        let atan_x = if invert { FRAC_PI_2 - atan_t } else { atan_t };
+        atan_x.copysign(self)
+    }
+
+    /// Approximates `atan(x)` normalized to the `[−1,1]` range with a maximum
+    /// relative error of `6e-16`.
+    pub fn atan_norm(self) -> Self {
+        self.atan() * FRAC_2_PI
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+    use core::f64::consts;
+
+    /// Maximum error in radians
+    const MAX_ERROR: f64 = 5e-16;
+
+    #[test]
+    fn sanity_check() {
+        // Arctangent test vectors - `(input, output)`
+        let test_vectors: &[(f64, f64)] = &[
+            (3.0_f64.sqrt() / 3.0, consts::FRAC_PI_6),
+            (1.0, consts::FRAC_PI_4),
+            (3.0_f64.sqrt(), consts::FRAC_PI_3),
+            (-(3.0_f64.sqrt()) / 3.0, -consts::FRAC_PI_6),
+            (-1.0, -consts::FRAC_PI_4),
+            (-(3.0_f64.sqrt()), -consts::FRAC_PI_3),
+        ];
+
+        for &(x, expected) in test_vectors {
+            let actual = F64(x).atan().0;
+            let delta = (actual - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large: {} vs {}",
+                delta,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(F64::ZERO.atan(), F64::ZERO);
+    }
+
+    #[test]
+    fn nan() {
+        assert!(F64::NAN.atan().is_nan());
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::atan, f64::atan, (-2.0, 2.0), 100_000, MAX_ERROR);
+        check_against_std(F64::atan, f64::atan, (-1e300, 1e300), 100_000, MAX_ERROR);
+    }
+
+    #[test]
+    fn infinity() {
+        assert_eq!(F64::INFINITY.atan().0, consts::FRAC_PI_2);
+        assert_eq!(F64::NEG_INFINITY.atan().0, -consts::FRAC_PI_2);
+    }
+}
@@ -0,0 +1,117 @@
+//! Four quadrant arctangent approximation for a double-precision float.
+
+use super::F64;
+use core::f64::consts::{FRAC_2_PI, FRAC_PI_2, FRAC_PI_4, PI};
+
+impl F64 {
+    /// Approximates the four quadrant arctangent of `self` (`y`) and
+    /// `rhs` (`x`) in radians with a maximum error of `5e-16`.
+    ///
+    /// - `x = 0`, `y = 0`: `0`
+    /// - `x >= 0`: `arctan(y/x)` -> `[-pi/2, pi/2]`
+    /// - `y >= 0`: `arctan(y/x) + pi` -> `(pi/2, pi]`
+    /// - `y < 0`: `arctan(y/x) - pi` -> `(-pi, -pi/2)`
+    pub fn atan2(self, rhs: Self) -> Self {
+        let (y, x) = (self, rhs);
+
+        if y.is_infinite() && x.is_infinite() {
+            let angle = if x > Self::ZERO {
+                FRAC_PI_4
+            } else {
+                3.0 * FRAC_PI_4
+            };
+
+            Self(angle).copysign(y)
+        } else if y.is_nan() || x.is_nan() {
+            Self::NAN
+        } else if x > Self::ZERO {
+            (y / x).atan()
+        } else if x < Self::ZERO {
+            let offset = if y >= Self::ZERO { PI } else { -PI };
+            (y / x).atan() + offset
+        } else if y == Self::ZERO {
+            Self::ZERO
+        } else {
+            Self(FRAC_PI_2).copysign(y)
+        }
+    }
+
+    /// Approximates `atan2(y,x)` normalized to the `[0, 4)` range with a
+    /// maximum error of `4e-16`.
+    pub(crate) fn atan2_norm(self, rhs: Self) -> Self {
+        let n = self.atan2(rhs) * FRAC_2_PI;
+
+        if n < Self::ZERO {
+            n + 4.0
+        } else {
+            n
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    /// Maximum error in radians
+    const MAX_ERROR: f64 = 5e-16;
+
+    #[test]
+    fn sanity_check() {
+        let test_vectors: &[(f64, f64, f64)] = &[
+            (0.0, 1.0, 0.0),
+            (0.0, -1.0, PI),
+            (3.0, 2.0, (3.0f64 / 2.0).atan()),
+            (2.0, -1.0, (2.0f64 / -1.0).atan() + PI),
+            (-2.0, -1.0, (-2.0f64 / -1.0).atan() - PI),
+        ];
+
+        for &(y, x, expected) in test_vectors {
+            let actual = F64(y).atan2(F64(x)).0;
+            let delta = (actual - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large: {} vs {}",
+                delta,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn matches_std() {
+        for i in -100..=100 {
+            for j in -100..=100 {
+                let (y, x) = (f64::from(i) * 0.37, f64::from(j) * 1.13);
+
+                if x == 0.0 && y == 0.0 {
+                    continue;
+                }
+
+                let actual = F64(y).atan2(F64(x)).0;
+                let expected = y.atan2(x);
+                assert!(
+                    (actual - expected).abs() <= MAX_ERROR,
+                    "atan2({}, {}): {} vs {}",
+                    y,
+                    x,
+                    actual,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(F64::ZERO.atan2(F64::ZERO), F64::ZERO);
+        assert_eq!(F64(1.0).atan2(F64::ZERO).0, FRAC_PI_2);
+        assert_eq!(F64(-1.0).atan2(F64::ZERO).0, -FRAC_PI_2);
+        assert_eq!(F64::INFINITY.atan2(F64::INFINITY).0, FRAC_PI_2 / 2.0);
+        assert!(F64::NAN.atan2(F64::ONE).is_nan());
+        assert!(F64::ONE.atan2(F64::NAN).is_nan());
+    }
+}
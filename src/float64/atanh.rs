@@ -0,0 +1,93 @@
+//! Inverse hyperbolic tangent function
+
+use super::F64;
+
+impl F64 {
+    /// Approximates `atanh(x)` with a maximum relative error of `4e-16`.
+    ///
+    /// Computed as `ln(1 + 2|x| / (1 - |x|)) / 2` with the sign of `x`
+    /// restored, which is `ln((1 + x) / (1 - x)) / 2` rearranged so that
+    /// [`F64::ln_1p`] keeps the relative error small near zero.
+    ///
+    /// Returns [`Self::INFINITY`] (with the sign of `self`) for `±1.0`, and
+    /// [`Self::NAN`] if `|self| > 1.0`.
+    pub fn atanh(self) -> Self {
+        let x = self.abs();
+
+        if x.is_nan() || x > Self::ONE {
+            Self::NAN
+        } else if x == Self::ONE {
+            Self::INFINITY.copysign(self)
+        } else {
+            (0.5 * (2.0 * x / (1.0 - x)).ln_1p()).copysign(self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    /// Maximum relative error
+    const MAX_ERROR: f64 = 4e-16;
+
+    /// Inverse hyperbolic tangent test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f64, f64)] = &[
+        (-0.999, -3.800_201_167_250_199_4),
+        (-0.99, -2.646_652_412_362_245_7),
+        (-0.9, -1.472_219_489_583_220_4),
+        (-0.75, -0.972_955_074_527_656_6),
+        (-0.5, -0.549_306_144_334_054_8),
+        (-0.25, -0.255_412_811_882_995_36),
+        (-0.1, -0.100_335_347_731_075_58),
+        (-0.01, -0.010_000_333_353_334_763),
+        (0.0, 0.0),
+        (0.01, 0.010_000_333_353_334_763),
+        (0.1, 0.100_335_347_731_075_58),
+        (0.25, 0.255_412_811_882_995_36),
+        (0.5, 0.549_306_144_334_054_8),
+        (0.75, 0.972_955_074_527_656_6),
+        (0.9, 1.472_219_489_583_220_4),
+        (0.99, 2.646_652_412_362_245_7),
+        (0.999, 3.800_201_167_250_199_4),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let atanh_x = F64(x).atanh();
+            let relative_error = if expected == 0.0 {
+                atanh_x.abs()
+            } else {
+                ((atanh_x - expected) / expected).abs()
+            };
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                atanh_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(F64::ONE.atanh(), F64::INFINITY);
+        assert_eq!((-F64::ONE).atanh(), F64::NEG_INFINITY);
+        assert!(F64(1.5).atanh().is_nan());
+        assert!(F64(-1.5).atanh().is_nan());
+        assert!(F64::NAN.atanh().is_nan());
+    }
+
+    #[test]
+    fn matches_std() {
+        // `std` loses precision close to `-1`, so rely on `atanh` being odd
+        let reference = |x: f64| x.abs().atanh().copysign(x);
+        check_against_std(F64::atanh, reference, (-0.999, 0.999), 100_000, MAX_ERROR);
+        check_against_std(F64::atanh, reference, (-1e-3, 1e-3), 10_000, MAX_ERROR);
+    }
+}
@@ -0,0 +1,111 @@
+//! Cube root approximation function for a double-precision float.
+//!
+//! The initial estimate from the method described at
+//! <https://web.archive.org/web/20131227144655/http://metamerist.com/cbrt/cbrt.htm>
+//! is refined with four steps of Newton's method.
+
+use super::{F64, SIGN_MASK, TWO_POW_54};
+
+/// `2^-18`, the cube root of `2^-54`.
+const TWO_POW_NEG_18: f64 = f64::from_bits(0x3ed0_0000_0000_0000);
+
+impl F64 {
+    /// Approximates the cube root of a number with a maximum relative error
+    /// of `3e-16`.
+    ///
+    /// Unlike [`F64::sqrt`], negative numbers have a real cube root, so the
+    /// sign of `self` is preserved. `NaN` and infinities are returned
+    /// unchanged.
+    pub fn cbrt(self) -> Self {
+        if self == Self::ZERO || !self.is_finite() {
+            return self;
+        }
+
+        let (x, scale) = if self.abs() < Self::MIN_POSITIVE {
+            (self.abs() * TWO_POW_54, TWO_POW_NEG_18)
+        } else {
+            (self.abs(), 1.0)
+        };
+
+        // The initial estimate is within 3.3%, and each step squares the error
+        let mut y = Self::from_bits(x.to_bits() / 3 + 0x2a9f_7893_782d_a1ce);
+
+        for _ in 0..4 {
+            y = (2.0 * y + x / (y * y)) / 3.0;
+        }
+
+        Self::from_bits((y * scale).to_bits() | (self.to_bits() & SIGN_MASK))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    /// Maximum relative error
+    const MAX_ERROR: f64 = 3e-16;
+
+    /// Cube root test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f64, f64)] = &[
+        (-1.0e9, -1000.0),
+        (-27.0, -3.0),
+        (-8.0, -2.0),
+        (-2.0, -1.259_921_049_894_873_2),
+        (-1.0, -1.0),
+        (-0.125, -0.5),
+        (-1.0e-6, -0.01),
+        (1.0e-6, 0.01),
+        (0.001, 0.1),
+        (0.125, 0.5),
+        (0.5, 0.793_700_525_984_099_8),
+        (1.0, 1.0),
+        (2.0, 1.259_921_049_894_873_2),
+        (3.0, 1.442_249_570_307_408_3),
+        (8.0, 2.0),
+        (1.0e1, 2.154_434_690_031_884),
+        (27.0, 3.0),
+        (1.0e2, 4.641_588_833_612_779),
+        (1.0e3, 10.0),
+        (12345.0, 23.111_618_749_807_267),
+        (1.0e9, 1000.0),
+        (1.0e30, 10_000_000_000.0),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let cbrt_x = F64(x).cbrt();
+            let relative_error = ((cbrt_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                cbrt_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(F64::ZERO.cbrt(), F64::ZERO);
+        assert!(F64(-0.0).cbrt().is_sign_negative());
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::cbrt, f64::cbrt, (-10.0, 10.0), 100_000, MAX_ERROR);
+        check_against_std(F64::cbrt, f64::cbrt, (-1e300, 1e300), 100_000, MAX_ERROR);
+        check_against_std(F64::cbrt, f64::cbrt, (-1e-300, 1e-300), 100_000, MAX_ERROR);
+    }
+
+    #[test]
+    fn non_finite() {
+        assert!(F64::NAN.cbrt().is_nan());
+        assert_eq!(F64::INFINITY.cbrt(), F64::INFINITY);
+        assert_eq!(F64::NEG_INFINITY.cbrt(), F64::NEG_INFINITY);
+    }
+}
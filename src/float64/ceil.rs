@@ -0,0 +1,25 @@
+//! Floating point ceiling approximation for a double-precision float.
+
+use super::F64;
+
+impl F64 {
+    /// Returns the smallest integer greater than or equal to a number.
+    pub fn ceil(self) -> Self {
+        -(-self).floor()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F64(-1.1).ceil().0, -1.0);
+        assert_eq!(F64(-0.1).ceil().0, 0.0);
+        assert_eq!(F64(0.0).ceil().0, 0.0);
+        assert_eq!(F64(1.0).ceil().0, 1.0);
+        assert_eq!(F64(1.1).ceil().0, 2.0);
+        assert_eq!(F64(2.9).ceil().0, 3.0);
+    }
+}
@@ -0,0 +1,33 @@
+//! Copy the sign over from another number.
+
+use super::{F64, SIGN_MASK};
+
+impl F64 {
+    /// Returns a number composed of the magnitude of `self` and the sign of
+    /// `sign`.
+    pub fn copysign(self, sign: Self) -> Self {
+        let source_bits = sign.to_bits();
+        let source_sign = source_bits & SIGN_MASK;
+        let signless_destination_bits = self.to_bits() & !SIGN_MASK;
+        Self::from_bits(signless_destination_bits | source_sign)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F64(1.0).copysign(F64(-1.0)).0, -1.0);
+        assert_eq!(F64(-1.0).copysign(F64(1.0)).0, 1.0);
+        assert_eq!(F64(1.0).copysign(F64(1.0)).0, 1.0);
+        assert_eq!(F64(-1.0).copysign(F64(-1.0)).0, -1.0);
+
+        let large_float = F64(100_000_000.134_253_45);
+        assert_eq!(large_float.copysign(-large_float), -large_float);
+        assert_eq!((-large_float).copysign(large_float), large_float);
+        assert_eq!(large_float.copysign(large_float), large_float);
+        assert_eq!((-large_float).copysign(-large_float), -large_float);
+    }
+}
@@ -0,0 +1,132 @@
+//! Cosine approximation for a double-precision float.
+//!
+//! The angle is reduced to `[-π/4, π/4]` by [`F64::rem_pio2`], where the sine
+//! and cosine are evaluated with their Taylor series.
+
+use super::F64;
+use crate::poly;
+
+/// Coefficients of the Taylor series of `(cos(x) - 1 + x²/2) / x⁴` in terms
+/// of `x²`, i.e. `(-1)^n / (2n + 4)!`.
+const COS_SERIES: [f64; 8] = [
+    1.0 / 24.0,
+    -1.0 / 720.0,
+    1.0 / 40_320.0,
+    -1.0 / 3_628_800.0,
+    1.0 / 479_001_600.0,
+    -1.0 / 87_178_291_200.0,
+    1.0 / 20_922_789_888_000.0,
+    -1.0 / 6_402_373_705_728_000.0,
+];
+
+impl F64 {
+    /// Approximates `cos(x)` in radians with a maximum error of `4e-16`.
+    pub fn cos(self) -> Self {
+        let (quadrant, r) = self.rem_pio2();
+
+        match quadrant {
+            0 => r.cos_kernel(),
+            1 => -r.sin_kernel(),
+            2 => -r.cos_kernel(),
+            _ => r.sin_kernel(),
+        }
+    }
+
+    /// Approximates `cos(x)` for `x` in `[-π/4, π/4]`.
+    pub(crate) fn cos_kernel(self) -> Self {
+        let x2 = self * self;
+        let half_x2 = 0.5 * x2;
+
+        // `1 - x²/2` is rounded, so its rounding error is added back in
+        let w = Self::ONE - half_x2;
+        w + (((Self::ONE - w) - half_x2) + x2 * x2 * poly::horner_f64(x2, &COS_SERIES))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    /// Maximum error in radians
+    pub(crate) const MAX_ERROR: f64 = 4e-16;
+
+    /// Cosine test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
+    const TEST_VECTORS: &[(f64, f64)] = &[
+        (0.000, 1.0),
+        (0.140, 0.990_215_996_212_637_1),
+        (0.279, 0.961_331_313_385_596_6),
+        (0.419, 0.913_496_244_152_975_7),
+        (0.559, 0.847_785_873_495_285_8),
+        (0.698, 0.766_129_092_116_142_4),
+        (0.838, 0.668_950_776_163_185_9),
+        (0.977, 0.559_511_528_540_876_3),
+        (1.117, 0.438_380_782_983_253_7),
+        (1.257, 0.308_671_798_943_600_8),
+        (1.396, 0.173_907_571_573_409_28),
+        (1.536, 0.034_789_305_411_943_49),
+        (1.676, -0.105_009_718_141_342_43),
+        (1.815, -0.241_783_701_616_728_02),
+        (1.955, -0.374_820_983_322_251_4),
+        (2.094, -0.499_657_792_272_824_2),
+        (2.234, -0.615_644_578_221_978_8),
+        (2.374, -0.719_584_426_401_147),
+        (2.513, -0.808_855_838_599_462),
+        (2.653, -0.882_994_318_261_113),
+        (2.793, -0.939_854_358_414_590_9),
+        (2.932, -0.978_115_748_970_803_7),
+        (3.072, -0.997_579_408_457_909),
+        (3.211, -0.997_592_276_941_365_5),
+        (3.351, -0.978_154_287_463_945_8),
+        (3.491, -0.939_575_767_480_181_2),
+        (3.630, -0.883_081_283_265_026_1),
+        (3.770, -0.808_964_786_630_085_5),
+        (3.910, -0.719_018_460_922_681_2),
+        (4.049, -0.615_790_594_147_416_7),
+        (4.189, -0.499_818_301_013_247_1),
+        (4.328, -0.374_992_774_654_380_8),
+        (4.468, -0.241_963_506_611_763_55),
+        (4.608, -0.104_199_494_838_960_18),
+        (4.747, 0.034_604_109_808_304_744),
+        (4.887, 0.173_725_085_123_557_22),
+        (5.027, 0.309_446_606_657_192_9),
+        (5.166, 0.438_214_223_338_668_66),
+        (5.306, 0.559_357_932_162_815_4),
+        (5.445, 0.668_813_024_609_265),
+        (5.585, 0.766_009_984_500_268_7),
+        (5.725, 0.848_217_655_212_255_7),
+        (5.864, 0.913_420_836_769_432_3),
+        (6.004, 0.961_280_264_310_680_6),
+        (6.144, 0.990_329_352_358_463_4),
+        (6.283, 0.999_999_982_830_624_6),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let cos_x = F64(x).cos();
+            let delta = (cos_x - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large: {} vs {}",
+                delta,
+                cos_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::cos, f64::cos, (-10.0, 10.0), 100_000, 4e-16);
+        check_against_std(F64::cos, f64::cos, (-1e9, 1e9), 100_000, 4e-16);
+    }
+
+    #[test]
+    fn non_finite() {
+        assert!(F64::NAN.cos().is_nan());
+        assert!(F64::INFINITY.cos().is_nan());
+    }
+}
@@ -0,0 +1,114 @@
+//! Hyperbolic cosine function
+
+use super::{exp::MAX_INPUT, F64};
+
+impl F64 {
+    /// Approximates `cosh(x)` with a maximum relative error of `5e-16`.
+    ///
+    /// ## Arguments
+    /// * `self` - The angle in radians.
+    pub fn cosh(self) -> Self {
+        let x = self.abs();
+
+        if x <= MAX_INPUT {
+            let exp_x = x.exp();
+            (exp_x + 1.0 / exp_x) / 2.0
+        } else {
+            // `e^x` overflows before `e^x / 2` does
+            let exp_half_x = (x / 2.0).exp();
+            exp_half_x * (exp_half_x / 2.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    /// Maximum relative error
+    const MAX_ERROR: f64 = 5e-16;
+
+    /// Hyperbolic cosine test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
+    const TEST_VECTORS: &[(f64, f64)] = &[
+        (0.0, 1.0),
+        (0.14, 1.009_816_017_128_016_6),
+        (0.279, 1.039_173_623_542_293_3),
+        (0.419, 1.089_072_275_029_006_5),
+        (0.559, 1.160_351_630_726_385_3),
+        (0.698, 1.253_654_347_554_666_9),
+        (0.838, 1.372_156_840_473_712_1),
+        (0.977, 1.516_456_787_006_858_8),
+        (1.117, 1.691_466_761_240_438_4),
+        (1.257, 1.899_683_668_873_631_6),
+        (1.396, 2.143_298_446_285_434_5),
+        (1.536, 2.430_604_760_045_472_5),
+        (1.676, 2.765_628_789_717_6),
+        (1.815, 3.151_957_042_917_568),
+        (1.955, 3.602_741_750_197_672_4),
+        (2.094, 4.120_256_483_031_128),
+        (2.234, 4.722_119_608_762_437),
+        (2.374, 5.416_687_548_414_055),
+        (2.513, 6.211_462_539_077_883),
+        (2.653, 7.133_502_047_630_412),
+        (2.793, 8.195_586_712_747_5),
+        (2.932, 9.409_206_794_350_87),
+        (3.072, 10.815_678_999_111_42),
+        (3.211, 12.422_096_990_658_307),
+        (3.351, 14.283_149_960_406_293),
+        (3.491, 16.424_610_219_461_034),
+        (3.63, 18.869_666_400_795_317),
+        (3.77, 21.701_558_449_569_422),
+        (3.91, 24.959_496_237_234_777),
+        (4.049, 28.678_764_041_646_833),
+        (4.189, 32.985_981_304_288_764),
+        (4.328, 37.902_871_846_175_7),
+        (4.468, 43.596_827_198_917_42),
+        (4.608, 50.146_676_956_682_65),
+        (4.747, 57.623_364_486_203_684),
+        (4.887, 66.281_427_521_496_08),
+        (5.027, 76.240_729_812_429_34),
+        (5.166, 87.609_145_465_455_36),
+        (5.306, 100.773_702_927_094_53),
+        (5.445, 115.800_798_776_066_1),
+        (5.585, 133.202_018_244_617_53),
+        (5.725, 153.218_264_298_320_04),
+        (5.864, 176.066_344_997_717_15),
+        (6.004, 202.524_104_630_786_27),
+        (6.144, 232.957_824_423_639_58),
+        (6.283, 267.697_151_029_345_66),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let cosh_x = F64(x).cosh();
+            let relative_error = ((cosh_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                cosh_x,
+                expected
+            );
+
+            assert_eq!(F64(-x).cosh(), cosh_x);
+        }
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::cosh, f64::cosh, (-1.0, 1.0), 100_000, MAX_ERROR);
+        check_against_std(F64::cosh, f64::cosh, (-710.0, 710.0), 100_000, MAX_ERROR);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(F64(711.0).cosh(), F64::INFINITY);
+        assert_eq!(F64::NEG_INFINITY.cosh(), F64::INFINITY);
+        assert!(F64::NAN.cosh().is_nan());
+    }
+}
@@ -0,0 +1,34 @@
+//! Calculates Euclidian division for a double-precision float.
+
+use super::F64;
+
+impl F64 {
+    /// Calculates Euclidean division, the matching method for `rem_euclid`.
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        let q = (self / rhs).trunc();
+
+        if self % rhs >= Self::ZERO {
+            q
+        } else if rhs > Self::ZERO {
+            q - Self::ONE
+        } else {
+            q + Self::ONE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    #[test]
+    fn sanity_check() {
+        let a = F64(7.0);
+        let b = F64(4.0);
+
+        assert_eq!(a.div_euclid(b), F64(1.0));
+        assert_eq!((-a).div_euclid(b), F64(-2.0));
+        assert_eq!(a.div_euclid(-b), F64(-1.0));
+        assert_eq!((-a).div_euclid(-b), F64(2.0));
+    }
+}
@@ -0,0 +1,120 @@
+//! Exp approximation for a double-precision float.
+//!
+//! The argument is reduced to `x = k * ln(2) + r` with `|r| <= ln(2) / 2`, so
+//! that `e^x = 2^k * e^r`, and `e^r` is evaluated with its Taylor series.
+
+use super::F64;
+use core::f64::consts::LOG2_E;
+
+/// Upper bits of `ln(2)`, so that `k * LN2_HI` is exact for any exponent `k`.
+pub(crate) const LN2_HI: f64 = f64::from_bits(0x3fe6_2e42_fee0_0000);
+
+/// Remaining bits of `ln(2)`.
+pub(crate) const LN2_LO: f64 = f64::from_bits(0x3dea_39ef_3579_3c76);
+
+/// Largest input for which `e^x` is finite.
+pub(crate) const MAX_INPUT: f64 = 709.782_712_893_384;
+
+/// Smallest input for which `e^x` doesn't round to zero.
+const MIN_INPUT: f64 = -745.133_219_101_941_1;
+
+impl F64 {
+    /// Returns `e^(self)`, (the exponential function), with a maximum
+    /// relative error of `3e-16`.
+    pub fn exp(self) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+
+        if self > MAX_INPUT {
+            return Self::INFINITY;
+        }
+
+        if self < MIN_INPUT {
+            return Self::ZERO;
+        }
+
+        let (k, r) = self.reduce_ln2();
+        (1.0 + r.exp_m1_series()).scalbn(k)
+    }
+
+    /// Reduces a finite number in the range of [`F64::exp`] to `r` with
+    /// `|r| <= ln(2) / 2`, where `self = k * ln(2) + r`, returning `(k, r)`.
+    pub(crate) fn reduce_ln2(self) -> (i32, Self) {
+        let k = (self * LOG2_E + Self(0.5).copysign(self)).0 as i32;
+        let k_f64 = f64::from(k);
+
+        // `self - k * LN2_HI` is exact, so only the low bits are rounded
+        (k, (self - k_f64 * LN2_HI) - k_f64 * LN2_LO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    pub(crate) const MAX_ERROR: f64 = 3e-16;
+
+    /// exp test vectors - `(input, output)`
+    pub(crate) const TEST_VECTORS: &[(f64, f64)] = &[
+        (1e-07, 1.000_000_100_000_005),
+        (1e-06, 1.000_001_000_000_5),
+        (1e-05, 1.000_010_000_05),
+        (1e-04, 1.000_100_005_000_166_7),
+        (0.001, 1.001_000_500_166_708_4),
+        (0.01, 1.010_050_167_084_168),
+        (0.1, 1.105_170_918_075_647_7),
+        (1.0, core::f64::consts::E),
+        (10.0, 22_026.465_794_806_718),
+        (-1e-08, 0.999_999_990_000_000_1),
+        (-1e-07, 0.999_999_900_000_005),
+        (-1e-06, 0.999_999_000_000_5),
+        (-1e-05, 0.999_990_000_049_999_8),
+        (-1e-04, 0.999_900_004_999_833_3),
+        (-0.001, 0.999_000_499_833_375),
+        (-0.01, 0.990_049_833_749_168_1),
+        (-0.1, 0.904_837_418_035_959_5),
+        (-1.0, 0.367_879_441_171_442_33),
+        (-10.0, 4.539_992_976_248_485_4e-5),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F64(-1000000.0).exp(), F64::ZERO);
+        assert_eq!(F64::ZERO.exp(), F64::ONE);
+
+        for &(x, expected) in TEST_VECTORS {
+            let exp_x = F64(x).exp();
+            let relative_error = (exp_x - expected).abs() / expected;
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {} : {} vs {}",
+                relative_error,
+                x,
+                exp_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::exp, f64::exp, (-700.0, 700.0), 100_000, MAX_ERROR);
+    }
+
+    #[test]
+    fn subnormal_results() {
+        let expected = (-740.0f64).exp();
+        assert!((F64(-740.0).exp().0 - expected).abs() <= 5e-324);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(F64(710.0).exp(), F64::INFINITY);
+        assert_eq!(F64::INFINITY.exp(), F64::INFINITY);
+        assert_eq!(F64::NEG_INFINITY.exp(), F64::ZERO);
+        assert!(F64::NAN.exp().is_nan());
+    }
+}
@@ -0,0 +1,89 @@
+//! `2^x` approximation for a double-precision float.
+
+use super::F64;
+use core::f64::consts::LN_2;
+
+impl F64 {
+    /// Returns `2^(self)` with a maximum relative error of `3e-16`.
+    ///
+    /// Exact for integer values of `self`.
+    pub fn exp2(self) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+
+        if self >= 1024.0 {
+            return Self::INFINITY;
+        }
+
+        if self < -1075.0 {
+            return Self::ZERO;
+        }
+
+        // `2^x = 2^k * e^(r * ln(2))` with `|r| <= 0.5`, where `r` is exact
+        let k = self.round();
+        (1.0 + ((self - k) * LN_2).exp_m1_series()).scalbn(k.0 as i32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    /// Maximum relative error
+    const MAX_ERROR: f64 = 3e-16;
+
+    /// `2^x` test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
+    const TEST_VECTORS: &[(f64, f64)] = &[
+        (-1.0e2, 7.888_609_052_210_118e-31),
+        (-10.5, 0.000_690_533_966_002_487_9),
+        (-3.0, 0.125),
+        (-1.0, 0.5),
+        (-0.5, 0.707_106_781_186_547_6),
+        (-0.1, 0.933_032_991_536_807_4),
+        (0.1, 1.071_773_462_536_293_1),
+        (0.5, 1.414_213_562_373_095_1),
+        (1.0, 2.0),
+        (1.5, 2.828_427_124_746_190_3),
+        (3.0, 8.0),
+        (10.5, 1_448.154_687_870_049_4),
+        (1.0e2, 1.267_650_600_228_229_4e30),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let exp2_x = F64(x).exp2();
+            let relative_error = ((exp2_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                exp2_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn integers() {
+        for n in -1022..=1023 {
+            assert_eq!(F64(n as f64).exp2(), F64(2.0).powi(n));
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(F64(1024.0).exp2(), F64::INFINITY);
+        assert_eq!(F64(-2000.0).exp2(), F64::ZERO);
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::exp2, f64::exp2, (-1020.0, 1020.0), 100_000, MAX_ERROR);
+    }
+}
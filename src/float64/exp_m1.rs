@@ -0,0 +1,122 @@
+//! `e^x - 1` approximation for a double-precision float.
+
+use super::{exp::MAX_INPUT, F64};
+use crate::poly;
+use core::f64::consts::LN_2;
+
+/// Coefficients of the Taylor series of `(e^x - 1) / x`, i.e. `1 / (n + 1)!`.
+const EXP_M1_SERIES: [f64; 14] = [
+    1.0,
+    1.0 / 2.0,
+    1.0 / 6.0,
+    1.0 / 24.0,
+    1.0 / 120.0,
+    1.0 / 720.0,
+    1.0 / 5_040.0,
+    1.0 / 40_320.0,
+    1.0 / 362_880.0,
+    1.0 / 3_628_800.0,
+    1.0 / 39_916_800.0,
+    1.0 / 479_001_600.0,
+    1.0 / 6_227_020_800.0,
+    1.0 / 87_178_291_200.0,
+];
+
+impl F64 {
+    /// Approximates `e^x - 1` more accurately than `x.exp() - 1.0` when `x` is
+    /// close to zero, with a maximum relative error of `5e-16`.
+    pub fn exp_m1(self) -> Self {
+        if self.abs() <= LN_2 / 2.0 {
+            return self.exp_m1_series();
+        }
+
+        if self.is_nan() || self > MAX_INPUT {
+            return self.exp();
+        }
+
+        // `e^x` is too small to change `-1`
+        if self < -40.0 {
+            return -Self::ONE;
+        }
+
+        let (k, r) = self.reduce_ln2();
+
+        // `2^k - 1` is exact, which avoids cancellation when `k` is small
+        if k < 53 {
+            let pow2 = Self::ONE.scalbn(k);
+            pow2 * r.exp_m1_series() + (pow2 - 1.0)
+        } else {
+            self.exp() - 1.0
+        }
+    }
+
+    /// Approximates `e^x - 1` for `|x| <= ln(2) / 2` using its Taylor series,
+    /// which has no constant term to cancel.
+    pub(crate) fn exp_m1_series(self) -> Self {
+        self * poly::horner_f64(self, &EXP_M1_SERIES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    /// Maximum relative error
+    const MAX_ERROR: f64 = 5e-16;
+
+    /// `e^x - 1` test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f64, f64)] = &[
+        (-1.0e1, -0.999_954_600_070_237_5),
+        (-1.0, -0.632_120_558_828_557_7),
+        (-0.5, -0.393_469_340_287_366_6),
+        (-0.1, -0.095_162_581_964_040_43),
+        (-0.01, -0.009_950_166_250_831_947),
+        (-0.001, -0.000_999_500_166_625_008_5),
+        (-1.0e-5, -9.999_950_000_166_666e-6),
+        (-1.0e-7, -9.999_999_500_000_016e-8),
+        (1.0e-7, 1.000_000_050_000_001_6e-7),
+        (1.0e-5, 1.000_005_000_016_666_8e-5),
+        (0.001, 0.001_000_500_166_708_341_7),
+        (0.01, 0.010_050_167_084_168_058),
+        (0.1, 0.105_170_918_075_647_63),
+        (0.5, 0.648_721_270_700_128_2),
+        (1.0, 1.718_281_828_459_045),
+        (1.0e1, 22_025.465_794_806_718),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let exp_m1_x = F64(x).exp_m1();
+            let relative_error = ((exp_m1_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                exp_m1_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(F64::ZERO.exp_m1(), F64::ZERO);
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::exp_m1, f64::exp_m1, (-50.0, 50.0), 100_000, MAX_ERROR);
+        check_against_std(F64::exp_m1, f64::exp_m1, (-1e-3, 1e-3), 10_000, MAX_ERROR);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(F64(-100.0).exp_m1(), -F64::ONE);
+        assert_eq!(F64(710.0).exp_m1(), F64::INFINITY);
+        assert!(F64::NAN.exp_m1().is_nan());
+    }
+}
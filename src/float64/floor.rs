@@ -0,0 +1,31 @@
+//! Floating point floor approximation for a double-precision float.
+
+use super::F64;
+
+impl F64 {
+    /// Returns the largest integer less than or equal to a number.
+    pub fn floor(self) -> Self {
+        let mut res = (self.0 as i64) as f64;
+
+        if self.0 < res {
+            res -= 1.0;
+        }
+
+        Self(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F64(-1.1).floor().0, -2.0);
+        assert_eq!(F64(-0.1).floor().0, -1.0);
+        assert_eq!(F64(0.0).floor().0, 0.0);
+        assert_eq!(F64(1.0).floor().0, 1.0);
+        assert_eq!(F64(1.1).floor().0, 1.0);
+        assert_eq!(F64(2.9).floor().0, 2.0);
+    }
+}
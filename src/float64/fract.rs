@@ -0,0 +1,68 @@
+//! Floating point fractional number for a double-precision float.
+
+use super::F64;
+use super::{EXPONENT_BIAS, MANTISSA_BITS, MANTISSA_MASK};
+
+impl F64 {
+    /// Returns the fractional part of a number with sign.
+    pub fn fract(self) -> Self {
+        let x_bits = self.to_bits();
+        let exponent = self.extract_exponent_value();
+
+        // we know it is *only* fraction
+        if exponent < 0 {
+            return self;
+        }
+
+        // all of the mantissa bits are whole number bits
+        if exponent >= MANTISSA_BITS as i32 {
+            return Self(0.0).copysign(self);
+        }
+
+        // find the part of the fraction that would be left over
+        let fractional_part = x_bits.overflowing_shl(exponent as u32).0 & MANTISSA_MASK;
+
+        // if there isn't a fraction we can just return 0
+        if fractional_part == 0 {
+            // TODO: most people don't actually care about -0.0,
+            // so would it be better to just not copysign?
+            return Self(0.0).copysign(self);
+        }
+
+        // Note: alternatively this could use -1.0, but it's assumed subtraction would be more costly
+        // example: 'let new_exponent_bits = 1023_u64.overflowing_shl(52_u32).0)) - 1.0'
+        let exponent_shift: u32 = (fractional_part.leading_zeros() - (64 - MANTISSA_BITS)) + 1;
+
+        let fractional_normalized: u64 =
+            fractional_part.overflowing_shl(exponent_shift).0 & MANTISSA_MASK;
+
+        let new_exponent_bits = ((EXPONENT_BIAS - exponent_shift) as u64)
+            .overflowing_shl(MANTISSA_BITS)
+            .0;
+
+        Self::from_bits(fractional_normalized | new_exponent_bits).copysign(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    #[test]
+    fn sanity_check() {
+        // fraction check actually won't be the same, though technically exactly accurate
+        // so we test by adding back the number removed.
+        assert_eq!(F64(2.9).fract().0 + 2.0, 2.9);
+        assert_eq!(F64(-1.1).fract().0 - 1.0, -1.1);
+        assert_eq!(F64(-0.1).fract().0, -0.1);
+        assert_eq!(F64(0.0).fract().0, 0.0);
+        assert_eq!(F64(1.0).fract().0 + 1.0, 1.0);
+        assert_eq!(F64(1.1).fract().0 + 1.0, 1.1);
+
+        assert_eq!(F64(-100_000_000.25).fract().0, -0.25);
+        assert_eq!(F64(100_000_000.25).fract().0, 0.25);
+
+        assert_eq!(F64(-1.0e17).fract().0, -0.0);
+        assert_eq!(F64(1.0e17).fract().0, 0.0);
+    }
+}
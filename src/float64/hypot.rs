@@ -0,0 +1,57 @@
+//! Calculate length of the hypotenuse of a right triangle.
+
+use super::F64;
+
+impl F64 {
+    /// Calculate the length of the hypotenuse of a right-angle triangle.
+    ///
+    /// The inputs are scaled by the larger of the two before squaring, so
+    /// the result only overflows or underflows if the hypotenuse itself does.
+    pub fn hypot(self, rhs: Self) -> Self {
+        let x = self.abs();
+        let y = rhs.abs();
+
+        if x.is_infinite() || y.is_infinite() {
+            return Self::INFINITY;
+        }
+
+        if x.is_nan() || y.is_nan() {
+            return Self::NAN;
+        }
+
+        let (big, small) = if x > y { (x, y) } else { (y, x) };
+
+        if big == Self::ZERO {
+            return Self::ZERO;
+        }
+
+        let ratio = small / big;
+        big * (1.0 + ratio * ratio).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    #[test]
+    fn sanity_check() {
+        let x = F64(3.0);
+        let y = F64(4.0);
+        let difference = x.hypot(y) - F64(25.0).sqrt();
+        assert!(difference.abs() <= F64::EPSILON);
+    }
+
+    #[test]
+    fn no_overflow() {
+        assert_eq!(F64(3e300).hypot(F64(-4e300)), F64(5e300));
+        assert_eq!(F64(3e-300).hypot(F64(4e-300)), F64(5e-300));
+        assert_eq!(F64(0.0).hypot(F64(-0.0)), F64(0.0));
+    }
+
+    #[test]
+    fn non_finite() {
+        assert_eq!(F64::NAN.hypot(F64::NEG_INFINITY), F64::INFINITY);
+        assert!(F64::NAN.hypot(F64(1.0)).is_nan());
+    }
+}
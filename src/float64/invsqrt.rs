@@ -0,0 +1,94 @@
+//! Inverse square root approximation function for a double-precision float.
+//!
+//! The initial estimate from the method described at
+//! <https://bits.stephan-brumme.com/invSquareRoot.html> is refined with four
+//! steps of Newton's method.
+
+use super::{F64, TWO_POW_54};
+
+/// `2^27`, the inverse square root of `2^-54`.
+const TWO_POW_27: f64 = f64::from_bits(0x41a0_0000_0000_0000);
+
+impl F64 {
+    /// Approximates the inverse square root, `1/√x`, with a maximum relative
+    /// error of `5e-16`.
+    ///
+    /// Returns [`Self::NAN`] if `self` is a negative number, and infinity
+    /// with the sign of `self` for zero.
+    pub fn invsqrt(self) -> Self {
+        if self.is_nan() || self < Self::ZERO {
+            return Self::NAN;
+        }
+
+        if self == Self::ZERO {
+            return Self::INFINITY.copysign(self);
+        }
+
+        if self.is_infinite() {
+            return Self::ZERO;
+        }
+
+        let (x, scale) = if self < Self::MIN_POSITIVE {
+            (self * TWO_POW_54, TWO_POW_27)
+        } else {
+            (self, 1.0)
+        };
+
+        // The initial estimate is within 3.5%, and each step squares the error
+        let half_x = 0.5 * x;
+        let mut y = Self::from_bits(0x5fe6_eb50_c7b5_37a9 - (x.to_bits() >> 1));
+
+        for _ in 0..4 {
+            y *= 1.5 - half_x * y * y;
+        }
+
+        y * scale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::sqrt::tests::TEST_VECTORS;
+    use crate::float64::tests::check_against_std;
+
+    /// Maximum relative error
+    const MAX_ERROR: f64 = 5e-16;
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            // The tests vectors are for sqrt(x), so invert the expected value
+            let expected = 1.0 / expected;
+
+            let invsqrt_x = F64(x).invsqrt();
+            let relative_error = ((invsqrt_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                invsqrt_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn matches_std() {
+        let invsqrt = |x: f64| 1.0 / x.sqrt();
+        check_against_std(F64::invsqrt, invsqrt, (0.0, 2.0), 100_000, MAX_ERROR);
+        check_against_std(F64::invsqrt, invsqrt, (0.0, 1e300), 100_000, MAX_ERROR);
+        check_against_std(F64::invsqrt, invsqrt, (0.0, 1e-300), 100_000, MAX_ERROR);
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(F64(-1.0).invsqrt().is_nan());
+        assert!(F64::NAN.invsqrt().is_nan());
+        assert_eq!(F64::ZERO.invsqrt(), F64::INFINITY);
+        assert_eq!(F64(-0.0).invsqrt(), F64::NEG_INFINITY);
+        assert_eq!(F64::INFINITY.invsqrt(), F64::ZERO);
+    }
+}
@@ -0,0 +1,172 @@
+//! Natural log (ln) approximation for a double-precision float.
+//!
+//! The number is split into `x = 2^k * m` with `m` in `[√2/2, √2)`, so that
+//! `ln(x) = k * ln(2) + ln(m)`, and `ln(m)` is evaluated with the series used
+//! by [`F64::ln_1p`] around `1`.
+
+use super::{
+    exp::{LN2_HI, LN2_LO},
+    F64, TWO_POW_54,
+};
+use core::f64::consts::SQRT_2;
+
+impl F64 {
+    /// Approximates the natural logarithm of the number with a maximum
+    /// relative error of `3e-16`.
+    ///
+    /// Returns [`Self::NEG_INFINITY`] for zero, and [`Self::NAN`] for
+    /// negative numbers.
+    pub fn ln(self) -> Self {
+        if self.is_nan() || self < Self::ZERO {
+            return Self::NAN;
+        }
+
+        if self == Self::ZERO {
+            return Self::NEG_INFINITY;
+        }
+
+        if self.is_infinite() {
+            return self;
+        }
+
+        let (x, mut k) = if self < Self::MIN_POSITIVE {
+            (self * TWO_POW_54, -54)
+        } else {
+            (self, 0)
+        };
+
+        k += x.extract_exponent_value();
+        let mut m = x.set_exponent(0);
+
+        if m > SQRT_2 {
+            m = m.set_exponent(-1);
+            k += 1;
+        }
+
+        // `m - 1` is exact, and `k * LN2_HI` is exact
+        let k = f64::from(k);
+        k * LN2_HI + ((m - 1.0).ln_1p_series() + k * LN2_LO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    pub(crate) const MAX_ERROR: f64 = 3e-16;
+
+    /// ln(x) test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
+    pub(crate) const TEST_VECTORS: &[(f64, f64)] = &[
+        (1e-20, -46.051_701_859_880_914),
+        (1e-19, -43.749_116_766_886_87),
+        (1e-18, -41.446_531_673_892_82),
+        (1e-17, -39.143_946_580_898_78),
+        (1e-16, -36.841_361_487_904_734),
+        (1e-15, -34.538_776_394_910_684),
+        (1e-14, -32.236_191_301_916_64),
+        (1e-13, -29.933_606_208_922_594),
+        (1e-12, -27.631_021_115_928_547),
+        (1e-11, -25.328_436_022_934_504),
+        (1e-10, -23.025_850_929_940_457),
+        (1e-09, -20.723_265_836_946_41),
+        (1e-08, -18.420_680_743_952_367),
+        (1e-07, -16.118_095_650_958_32),
+        (1e-06, -13.815_510_557_964_274),
+        (1e-05, -11.512_925_464_970_229),
+        (1e-04, -9.210_340_371_976_182),
+        (0.001, -6.907_755_278_982_137),
+        (0.01, -4.605_170_185_988_091),
+        (0.1, -2.302_585_092_994_045_5),
+        (10.0, 2.302_585_092_994_046),
+        (100.0, 4.605_170_185_988_092),
+        (1000.0, 6.907_755_278_982_137),
+        (10000.0, 9.210_340_371_976_184),
+        (100000.0, 11.512_925_464_970_229),
+        (1000000.0, 13.815_510_557_964_274),
+        (10000000.0, 16.118_095_650_958_32),
+        (100000000.0, 18.420_680_743_952_367),
+        (1000000000.0, 20.723_265_836_946_41),
+        (10000000000.0, 23.025_850_929_940_457),
+        (100000000000.0, 25.328_436_022_934_504),
+        (1000000000000.0, 27.631_021_115_928_547),
+        (10000000000000.0, 29.933_606_208_922_594),
+        (100000000000000.0, 32.236_191_301_916_64),
+        (1000000000000000.0, 34.538_776_394_910_684),
+        (1e+16, 36.841_361_487_904_734),
+        (1e+17, 39.143_946_580_898_78),
+        (1e+18, 41.446_531_673_892_82),
+        (1e+19, 43.749_116_766_886_87),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F64::ONE.ln(), F64::ZERO);
+        for &(x, expected) in TEST_VECTORS {
+            let ln_x = F64(x).ln();
+            let relative_error = ((ln_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large: {} vs {}",
+                relative_error,
+                ln_x,
+                expected
+            );
+        }
+    }
+
+    /// ln(x) test vectors near `1.0` - `(input, output)`
+    #[allow(clippy::approx_constant)]
+    const TEST_VECTORS_NEAR_ONE: &[(f64, f64)] = &[
+        (0.5, -0.693_147_180_559_945_3),
+        (0.75, -0.287_682_072_451_780_9),
+        (0.9, -0.105_360_515_657_826_28),
+        (0.99, -0.010_050_335_853_501_45),
+        (0.999, -0.001_000_500_333_583_534_4),
+        (1.001, 0.000_999_500_333_083_423_2),
+        (1.01, 0.009_950_330_853_168_092),
+        (1.1, 0.095_310_179_804_324_93),
+        (1.25, 0.223_143_551_314_209_76),
+        (1.5, 0.405_465_108_108_164_4),
+        (1.99, 0.688_134_638_736_401),
+    ];
+
+    #[test]
+    fn near_one() {
+        for &(x, expected) in TEST_VECTORS_NEAR_ONE {
+            let ln_x = F64(x).ln();
+            let relative_error = ((ln_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                ln_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::ln, f64::ln, (1e-300, 1e300), 100_000, MAX_ERROR);
+        check_against_std(F64::ln, f64::ln, (0.5, 2.0), 100_000, MAX_ERROR);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(F64::ZERO.ln(), F64::NEG_INFINITY);
+        assert_eq!(F64::INFINITY.ln(), F64::INFINITY);
+        assert!(F64(-1.0).ln().is_nan());
+        assert!(F64::NAN.ln().is_nan());
+    }
+
+    #[test]
+    fn subnormal() {
+        let x = 5e-324f64;
+        assert!(((F64(x).ln().0 - x.ln()) / x.ln()).abs() <= MAX_ERROR);
+    }
+}
@@ -0,0 +1,125 @@
+//! `ln(1 + x)` approximation for a double-precision float.
+
+use super::F64;
+use crate::poly;
+use core::f64::consts::{FRAC_1_SQRT_2, SQRT_2};
+
+/// Coefficients of the series `(atanh(s) - s) / s^3` in terms of `s^2`,
+/// i.e. `1 / (2n + 3)`.
+const ATANH_SERIES: [f64; 11] = [
+    1.0 / 3.0,
+    1.0 / 5.0,
+    1.0 / 7.0,
+    1.0 / 9.0,
+    1.0 / 11.0,
+    1.0 / 13.0,
+    1.0 / 15.0,
+    1.0 / 17.0,
+    1.0 / 19.0,
+    1.0 / 21.0,
+    1.0 / 23.0,
+];
+
+impl F64 {
+    /// Approximates `ln(1 + x)` more accurately than `(1 + x).ln()` when `x`
+    /// is close to zero, with a maximum relative error of `3e-16`.
+    ///
+    /// Returns [`Self::NEG_INFINITY`] for `-1.0`, and [`Self::NAN`] if `self`
+    /// is less than `-1.0`.
+    pub fn ln_1p(self) -> Self {
+        if self.is_nan() || self < -Self::ONE {
+            Self::NAN
+        } else if self == -Self::ONE {
+            Self::NEG_INFINITY
+        } else if (FRAC_1_SQRT_2 - 1.0..SQRT_2 - 1.0).contains(&self.0) {
+            self.ln_1p_series()
+        } else if self.is_infinite() {
+            self
+        } else {
+            // Correct for the rounding error of `1 + x`
+            let u = Self::ONE + self;
+            u.ln() + (self - (u - 1.0)) / u
+        }
+    }
+
+    /// Approximates `ln(1 + x)` for `x` in the range `[√2/2 - 1, √2 - 1]`
+    /// using the series `ln(1 + x) = 2 * atanh(s)` where `s = x / (2 + x)`.
+    ///
+    /// Since `2s = x - s * x`, this is evaluated as a small correction to `x`
+    /// so that the rounding of `s` barely affects the result.
+    pub(crate) fn ln_1p_series(self) -> Self {
+        let s = self / (2.0 + self);
+        let s2 = s * s;
+        let r = 2.0 * s2 * poly::horner_f64(s2, &ATANH_SERIES);
+        let half_x2 = 0.5 * self * self;
+        self - (half_x2 - s * (half_x2 + r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    /// Maximum relative error
+    const MAX_ERROR: f64 = 3e-16;
+
+    /// `ln(1 + x)` test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
+    const TEST_VECTORS: &[(f64, f64)] = &[
+        (-0.999, -6.907_755_278_982_136),
+        (-0.9, -2.302_585_092_994_046),
+        (-0.5, -0.693_147_180_559_945_3),
+        (-0.1, -0.105_360_515_657_826_31),
+        (-0.01, -0.010_050_335_853_501_442),
+        (-0.001, -0.001_000_500_333_583_533_5),
+        (-1.0e-5, -1.000_005_000_033_333_7e-5),
+        (-1.0e-7, -1.000_000_050_000_003_3e-7),
+        (1.0e-7, 9.999_999_500_000_033e-8),
+        (1.0e-5, 9.999_950_000_333_332e-6),
+        (0.001, 0.000_999_500_333_083_533_1),
+        (0.01, 0.009_950_330_853_168_083),
+        (0.1, 0.095_310_179_804_324_87),
+        (0.5, 0.405_465_108_108_164_4),
+        (1.0, 0.693_147_180_559_945_3),
+        (1.0e1, 2.397_895_272_798_370_7),
+        (1.0e3, 6.908_754_779_315_22),
+        (1.0e10, 23.025_850_930_040_455),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let ln_1p_x = F64(x).ln_1p();
+            let relative_error = ((ln_1p_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                ln_1p_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(F64::ZERO.ln_1p(), F64::ZERO);
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!((-F64::ONE).ln_1p(), F64::NEG_INFINITY);
+        assert!(F64(-2.0).ln_1p().is_nan());
+        assert!(F64::NAN.ln_1p().is_nan());
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::ln_1p, f64::ln_1p, (-0.999, 10.0), 100_000, MAX_ERROR);
+        check_against_std(F64::ln_1p, f64::ln_1p, (-1e-3, 1e-3), 10_000, MAX_ERROR);
+        check_against_std(F64::ln_1p, f64::ln_1p, (1e3, 1e300), 10_000, MAX_ERROR);
+    }
+}
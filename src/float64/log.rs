@@ -0,0 +1,192 @@
+//! log_b(a) approximation for a double-precision float.
+
+use super::F64;
+
+impl F64 {
+    /// Approximates the logarithm of the number with respect to an arbitrary base.
+    pub fn log(self, base: Self) -> Self {
+        (Self::ONE / base.ln()) * self.ln()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    pub(crate) const MAX_ERROR: f64 = 4e-16;
+
+    /// log3(x) test vectors - `(input, output)`
+    pub(crate) const TEST_VECTORS_BASE3: &[(f64, f64)] = &[
+        (1e-20, -41.918_065_485_787_69),
+        (1e-19, -39.822_162_211_498_31),
+        (1e-18, -37.726_258_937_208_925),
+        (1e-17, -35.630_355_662_919_54),
+        (1e-16, -33.534_452_388_630_15),
+        (1e-15, -31.438_549_114_340_77),
+        (1e-14, -29.342_645_840_051_386),
+        (1e-13, -27.246_742_565_762),
+        (1e-12, -25.150_839_291_472_614),
+        (1e-11, -23.054_936_017_183_23),
+        (1e-10, -20.959_032_742_893_847),
+        (1e-09, -18.863_129_468_604_463),
+        (1e-08, -16.767_226_194_315_075),
+        (1e-07, -14.671_322_920_025_693),
+        (1e-06, -12.575_419_645_736_307),
+        (1e-05, -10.479_516_371_446_923),
+        (1e-04, -8.383_613_097_157_538),
+        (0.001, -6.287_709_822_868_154),
+        (0.01, -4.191_806_548_578_769),
+        (0.1, -2.095_903_274_289_384_4),
+        (10.0, 2.095_903_274_289_384_4),
+        (100.0, 4.191_806_548_578_769),
+        (1000.0, 6.287_709_822_868_154),
+        (10000.0, 8.383_613_097_157_538),
+        (100000.0, 10.479_516_371_446_923),
+        (1000000.0, 12.575_419_645_736_307),
+        (10000000.0, 14.671_322_920_025_693),
+        (100000000.0, 16.767_226_194_315_075),
+        (1000000000.0, 18.863_129_468_604_463),
+        (10000000000.0, 20.959_032_742_893_847),
+        (100000000000.0, 23.054_936_017_183_23),
+        (1000000000000.0, 25.150_839_291_472_614),
+        (10000000000000.0, 27.246_742_565_762),
+        (100000000000000.0, 29.342_645_840_051_386),
+        (1000000000000000.0, 31.438_549_114_340_77),
+        (1e+16, 33.534_452_388_630_15),
+        (1e+17, 35.630_355_662_919_54),
+        (1e+18, 37.726_258_937_208_925),
+        (1e+19, 39.822_162_211_498_31),
+    ];
+
+    /// log5.5(x) test vectors - `(input, output)`
+    pub(crate) const TEST_VECTORS_BASE5_5: &[(f64, f64)] = &[
+        (1e-20, -27.013_787_004_396_978),
+        (1e-19, -25.663_097_654_177_13),
+        (1e-18, -24.312_408_303_957_28),
+        (1e-17, -22.961_718_953_737_43),
+        (1e-16, -21.611_029_603_517_583),
+        (1e-15, -20.260_340_253_297_734),
+        (1e-14, -18.909_650_903_077_885),
+        (1e-13, -17.558_961_552_858_037),
+        (1e-12, -16.208_272_202_638_188),
+        (1e-11, -14.857_582_852_418_338),
+        (1e-10, -13.506_893_502_198_489),
+        (1e-09, -12.156_204_151_978_64),
+        (1e-08, -10.805_514_801_758_791),
+        (1e-07, -9.454_825_451_538_943),
+        (1e-06, -8.104_136_101_319_094),
+        (1e-05, -6.753_446_751_099_244),
+        (1e-04, -5.402_757_400_879_396),
+        (0.001, -4.052_068_050_659_547),
+        (0.01, -2.701_378_700_439_698),
+        (0.1, -1.350_689_350_219_849),
+        (10.0, 1.350_689_350_219_849),
+        (100.0, 2.701_378_700_439_698),
+        (1000.0, 4.052_068_050_659_547),
+        (10000.0, 5.402_757_400_879_396),
+        (100000.0, 6.753_446_751_099_244),
+        (1000000.0, 8.104_136_101_319_094),
+        (10000000.0, 9.454_825_451_538_943),
+        (100000000.0, 10.805_514_801_758_791),
+        (1000000000.0, 12.156_204_151_978_64),
+        (10000000000.0, 13.506_893_502_198_489),
+        (100000000000.0, 14.857_582_852_418_338),
+        (1000000000000.0, 16.208_272_202_638_188),
+        (10000000000000.0, 17.558_961_552_858_037),
+        (100000000000000.0, 18.909_650_903_077_885),
+        (1000000000000000.0, 20.260_340_253_297_734),
+        (1e+16, 21.611_029_603_517_583),
+        (1e+17, 22.961_718_953_737_43),
+        (1e+18, 24.312_408_303_957_28),
+        (1e+19, 25.663_097_654_177_13),
+    ];
+
+    /// log12.7(x) test vectors - `(input, output)`
+    pub(crate) const TEST_VECTORS_BASE12_7: &[(f64, f64)] = &[
+        (1e-20, -18.119_163_416_733_965),
+        (1e-19, -17.213_205_245_897_267),
+        (1e-18, -16.307_247_075_060_57),
+        (1e-17, -15.401_288_904_223_87),
+        (1e-16, -14.495_330_733_387_172),
+        (1e-15, -13.589_372_562_550_475),
+        (1e-14, -12.683_414_391_713_777),
+        (1e-13, -11.777_456_220_877_077),
+        (1e-12, -10.871_498_050_040_38),
+        (1e-11, -9.965_539_879_203_682),
+        (1e-10, -9.059_581_708_366_983),
+        (1e-09, -8.153_623_537_530_285),
+        (1e-08, -7.247_665_366_693_586),
+        (1e-07, -6.341_707_195_856_888_5),
+        (1e-06, -5.435_749_025_020_19),
+        (1e-05, -4.529_790_854_183_491),
+        (1e-04, -3.623_832_683_346_793),
+        (0.001, -2.717_874_512_510_095),
+        (0.01, -1.811_916_341_673_396_5),
+        (0.1, -0.905_958_170_836_698_3),
+        (10.0, 0.905_958_170_836_698_3),
+        (100.0, 1.811_916_341_673_396_5),
+        (1000.0, 2.717_874_512_510_095),
+        (10000.0, 3.623_832_683_346_793),
+        (100000.0, 4.529_790_854_183_491),
+        (1000000.0, 5.435_749_025_020_19),
+        (10000000.0, 6.341_707_195_856_888_5),
+        (100000000.0, 7.247_665_366_693_586),
+        (1000000000.0, 8.153_623_537_530_285),
+        (10000000000.0, 9.059_581_708_366_983),
+        (100000000000.0, 9.965_539_879_203_682),
+        (1000000000000.0, 10.871_498_050_040_38),
+        (10000000000000.0, 11.777_456_220_877_077),
+        (100000000000000.0, 12.683_414_391_713_777),
+        (1000000000000000.0, 13.589_372_562_550_475),
+        (1e+16, 14.495_330_733_387_172),
+        (1e+17, 15.401_288_904_223_872),
+        (1e+18, 16.307_247_075_060_57),
+        (1e+19, 17.213_205_245_897_267),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F64::ONE.log(F64(3.0)), F64::ZERO);
+        assert_eq!(F64::ONE.log(F64(5.5)), F64::ZERO);
+        assert_eq!(F64::ONE.log(F64(12.7)), F64::ZERO);
+
+        for &(x, expected) in TEST_VECTORS_BASE3 {
+            let log_x = F64(x).log(F64(3.0));
+            let relative_error = ((log_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large: {} vs {}",
+                relative_error,
+                log_x,
+                expected
+            );
+        }
+
+        for &(x, expected) in TEST_VECTORS_BASE5_5 {
+            let log_x = F64(x).log(F64(5.5));
+            let relative_error = ((log_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large: {} vs {}",
+                relative_error,
+                log_x,
+                expected
+            );
+        }
+
+        for &(x, expected) in TEST_VECTORS_BASE12_7 {
+            let log_x = F64(x).log(F64(12.7));
+            let relative_error = ((log_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large: {} vs {}",
+                relative_error,
+                log_x,
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! log base 2 approximation for a double-precision float.
+
+use super::F64;
+use core::f64::consts::LOG2_E;
+
+impl F64 {
+    /// Approximates the base 2 logarithm of the number.
+    pub fn log2(self) -> Self {
+        self.ln() * LOG2_E
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    pub(crate) const MAX_ERROR: f64 = 4e-16;
+
+    /// log2(x) test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
+    pub(crate) const TEST_VECTORS: &[(f64, f64)] = &[
+        (1e-20, -66.438_561_897_747_25),
+        (1e-19, -63.116_633_802_859_89),
+        (1e-18, -59.794_705_707_972_525),
+        (1e-17, -56.472_777_613_085_16),
+        (1e-16, -53.150_849_518_197_795),
+        (1e-15, -49.828_921_423_310_43),
+        (1e-14, -46.506_993_328_423_07),
+        (1e-13, -43.185_065_233_535_71),
+        (1e-12, -39.863_137_138_648_35),
+        (1e-11, -36.541_209_043_760_986),
+        (1e-10, -33.219_280_948_873_624),
+        (1e-09, -29.897_352_853_986_263),
+        (1e-08, -26.575_424_759_098_897),
+        (1e-07, -23.253_496_664_211_536),
+        (1e-06, -19.931_568_569_324_174),
+        (1e-05, -16.609_640_474_436_812),
+        (1e-04, -13.287_712_379_549_449),
+        (0.001, -9.965_784_284_662_087),
+        (0.01, -6.643_856_189_774_724),
+        (0.1, -3.321_928_094_887_362),
+        (10.0, 3.321_928_094_887_362),
+        (100.0, 6.643_856_189_774_724),
+        (1000.0, 9.965_784_284_662_087),
+        (10000.0, 13.287_712_379_549_449),
+        (100000.0, 16.609_640_474_436_812),
+        (1000000.0, 19.931_568_569_324_174),
+        (10000000.0, 23.253_496_664_211_536),
+        (100000000.0, 26.575_424_759_098_897),
+        (1000000000.0, 29.897_352_853_986_263),
+        (10000000000.0, 33.219_280_948_873_624),
+        (100000000000.0, 36.541_209_043_760_986),
+        (1000000000000.0, 39.863_137_138_648_35),
+        (10000000000000.0, 43.185_065_233_535_71),
+        (100000000000000.0, 46.506_993_328_423_07),
+        (1000000000000000.0, 49.828_921_423_310_43),
+        (1e+16, 53.150_849_518_197_795),
+        (1e+17, 56.472_777_613_085_16),
+        (1e+18, 59.794_705_707_972_525),
+        (1e+19, 63.116_633_802_859_89),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F64::ONE.log2(), F64::ZERO);
+
+        for &(x, expected) in TEST_VECTORS {
+            let ln_x = F64(x).log2().0;
+            let relative_error = ((ln_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large: {} vs {}",
+                relative_error,
+                ln_x,
+                expected
+            );
+        }
+    }
+}
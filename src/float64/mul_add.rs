@@ -0,0 +1,22 @@
+//! Fused multiply-add. Computes `(self * a) + b`
+
+use super::F64;
+
+impl F64 {
+    /// Computes `(self * a) + b`.
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F64(0.0).mul_add(F64(0.0), F64(1.0)), F64(1.0));
+        assert_eq!(F64(1.0).mul_add(F64(2.0), F64(3.5)), F64(5.5));
+        assert_eq!(F64(1.0).mul_add(F64(-1.0), F64(0.0)), F64(-1.0));
+    }
+}
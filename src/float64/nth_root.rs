@@ -0,0 +1,109 @@
+//! `n`th root approximation for a double-precision float.
+
+use super::F64;
+
+impl F64 {
+    /// Approximates the `n`th root of a number, i.e. `self^(1/n)`.
+    ///
+    /// The initial estimate `e^(ln(|x|) / n)` is refined with one step of
+    /// Newton's method, with a maximum relative error of `4e-16`.
+    ///
+    /// Negative numbers have a real root when `n` is odd, in which case the
+    /// sign of `self` is preserved. Returns [`Self::NAN`] if `self` is
+    /// negative and `n` is even, or if `n` is zero.
+    pub fn nth_root(self, n: i32) -> Self {
+        if n == 0 || self.is_nan() || (self < Self::ZERO && n % 2 == 0) {
+            return Self::NAN;
+        }
+
+        if n < 0 {
+            return Self::ONE / self.nth_root_abs(n.unsigned_abs());
+        }
+
+        self.nth_root_abs(n as u32)
+    }
+
+    /// Approximate the `n`th root for a positive `n`, preserving the sign.
+    fn nth_root_abs(self, n: u32) -> Self {
+        let x = self.abs();
+
+        if n == 1 || x == Self::ZERO || x.is_infinite() {
+            return self;
+        }
+
+        let n_f64 = n as f64;
+        let mut y = (x.ln() / n_f64).exp();
+
+        // Newton's method: y = ((n - 1) * y + x / y^(n - 1)) / n
+        let y_pow = y.powi((n - 1) as i32);
+
+        if y_pow.is_finite() && y_pow != Self::ZERO {
+            y = ((n_f64 - 1.0) * y + x / y_pow) / n_f64;
+        }
+
+        y.copysign(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    /// Maximum relative error
+    const MAX_ERROR: f64 = 4e-16;
+
+    /// `n`th root test vectors - `(input, n, output)`
+    #[allow(clippy::approx_constant)]
+    const TEST_VECTORS: &[(f64, i32, f64)] = &[
+        (2.0, 2, 1.414_213_562_373_095_1),
+        (1.0e1, 2, 3.162_277_660_168_379_5),
+        (27.0, 3, 3.0),
+        (-27.0, 3, -3.0),
+        (1.0e2, 3, 4.641_588_833_612_779),
+        (16.0, 4, 2.0),
+        (0.001, 4, 0.177_827_941_003_892_3),
+        (-32.0, 5, -2.0),
+        (1.0e10, 5, 100.0),
+        (128.0, 7, 2.0),
+        (-2.0, 7, -1.104_089_513_673_812_3),
+        (1.0e3, 10, 1.995_262_314_968_879_5),
+        (2.0, -2, 0.707_106_781_186_547_6),
+        (8.0, -3, 0.5),
+        (-8.0, -3, -0.5),
+        (5.0, 1, 5.0),
+        (5.0, -1, 0.2),
+        (1.0e30, 31, 9.284_145_445_194_744),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, n, expected) in TEST_VECTORS {
+            let root = F64(x).nth_root(n);
+            let relative_error = ((root - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}.nth_root({}): {} vs {}",
+                relative_error,
+                x,
+                n,
+                root,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(F64::ZERO.nth_root(3), F64::ZERO);
+        assert_eq!(F64::ZERO.nth_root(-2), F64::INFINITY);
+    }
+
+    #[test]
+    fn nan() {
+        assert!(F64(-4.0).nth_root(2).is_nan());
+        assert!(F64(-4.0).nth_root(-4).is_nan());
+        assert!(F64(4.0).nth_root(0).is_nan());
+        assert!(F64::NAN.nth_root(3).is_nan());
+    }
+}
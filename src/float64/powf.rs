@@ -0,0 +1,246 @@
+//! `x^n` with fractional `n` approximation for a double-precision float.
+
+use super::F64;
+
+impl F64 {
+    /// Approximates a number raised to a floating point power.
+    ///
+    /// Computed as `exp(n * ln(x))`, so the relative error of `ln(x)` is
+    /// scaled by `|n * ln(x)|`: the result is within about `|n * ln(x)| * 2^-52`
+    /// of the exact value, which stays below `2e-13` for any finite result.
+    pub fn powf(self, n: Self) -> Self {
+        if n == Self::ZERO || self == Self::ONE {
+            return Self::ONE;
+        }
+
+        // using x^n = exp(ln(x^n)) = exp(n*ln(x))
+        if self >= Self::ZERO {
+            (n * self.ln()).exp()
+        } else if !n.is_integer() {
+            Self::NAN
+        } else if n.is_even() {
+            // if n is even, then we know that the result will have no sign, so we can remove it
+            (n * self.without_sign().ln()).exp()
+        } else {
+            // if n isn't even, we need to multiply by -1.0 at the end.
+            -(n * self.without_sign().ln()).exp()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    /// Maximum relative error, dominated by `ln(x)` being scaled by `n`.
+    pub(crate) const MAX_ERROR: f64 = 2e-13;
+
+    ///  powf(3,x) test vectors - `(input, output)`
+    pub(crate) const TEST_VECTORS_POW3: &[(f64, f64)] = &[
+        (-1e-20, 1.0),
+        (-1e-19, 1.0),
+        (-1e-18, 1.0),
+        (-1e-17, 1.0),
+        (-1e-16, 0.999_999_999_999_999_9),
+        (-1e-15, 0.999_999_999_999_998_9),
+        (-1e-14, 0.999_999_999_999_989),
+        (-1e-13, 0.999_999_999_999_890_1),
+        (-1e-12, 0.999_999_999_998_901_4),
+        (-1e-11, 0.999_999_999_989_013_9),
+        (-1e-10, 0.999_999_999_890_138_8),
+        (-1e-09, 0.999_999_998_901_387_7),
+        (-1e-08, 0.999_999_989_013_877_2),
+        (-1e-07, 0.999_999_890_138_777_2),
+        (-1e-06, 0.999_998_901_388_314_8),
+        (-1e-05, 0.999_989_013_937_460_5),
+        (-1e-04, 0.999_890_144_805_657),
+        (-0.001, 0.998_901_990_964_878_2),
+        (-0.01, 0.989_074_004_172_170_7),
+        (-0.1, 0.895_958_459_840_762_2),
+        (-1.0, 0.333_333_333_333_333_3),
+        (-10.0, 1.693_508_780_843_028_6e-5),
+        (-100.0, 1.940_325_217_482_632_8e-48),
+        (-1000.0, 0.0),
+        (1e-20, 1.0),
+        (1e-19, 1.0),
+        (1e-18, 1.0),
+        (1e-17, 1.0),
+        (1e-16, 1.0),
+        (1e-15, 1.000_000_000_000_001),
+        (1e-14, 1.000_000_000_000_010_9),
+        (1e-13, 1.000_000_000_000_11),
+        (1e-12, 1.000_000_000_001_098_7),
+        (1e-11, 1.000_000_000_010_986),
+        (1e-10, 1.000_000_000_109_861_2),
+        (1e-09, 1.000_000_001_098_612_3),
+        (1e-08, 1.000_000_010_986_123),
+        (1e-07, 1.000_000_109_861_235),
+        (1e-06, 1.000_001_098_612_892_2),
+        (1e-05, 1.000_010_986_183_234_3),
+        (1e-04, 1.000_109_867_263_832_7),
+        (0.001, 1.001_099_215_984_204),
+        (0.01, 1.011_046_691_937_853_6),
+        (0.1, 1.116_123_174_033_904_4),
+        (1.0, 3.0),
+        (10.0, 59_049.0),
+    ];
+
+    ///  powf(150,x) test vectors - `(input, output)`
+    pub(crate) const TEST_VECTORS_POW150: &[(f64, f64)] = &[
+        (-1e-20, 1.0),
+        (-1e-19, 1.0),
+        (-1e-18, 1.0),
+        (-1e-17, 1.0),
+        (-1e-16, 0.999_999_999_999_999_4),
+        (-1e-15, 0.999_999_999_999_995),
+        (-1e-14, 0.999_999_999_999_949_9),
+        (-1e-13, 0.999_999_999_999_499),
+        (-1e-12, 0.999_999_999_994_989_3),
+        (-1e-11, 0.999_999_999_949_893_6),
+        (-1e-10, 0.999_999_999_498_936_5),
+        (-1e-09, 0.999_999_994_989_364_8),
+        (-1e-08, 0.999_999_949_893_648_3),
+        (-1e-07, 0.999_999_498_936_596_1),
+        (-1e-06, 0.999_994_989_377_259_2),
+        (-1e-05, 0.999_949_894_902_361_4),
+        (-1e-04, 0.999_499_061_981_956_7),
+        (-0.001, 0.995_001_896_998_609_1),
+        (-0.01, 0.951_128_263_833_350_8),
+        (-0.1, 0.605_885_939_418_456_4),
+        (-1.0, 0.006_666_666_666_666_667),
+        (-10.0, 1.734_152_991_583_261_4e-22),
+        (-100.0, 2.459_654_426_579_829_4e-218),
+        (-1000.0, 0.0),
+        (-10000.0, 0.0),
+        (-100000.0, 0.0),
+        (-1000000.0, 0.0),
+        (-10000000.0, 0.0),
+        (-100000000.0, 0.0),
+        (-1000000000.0, 0.0),
+        (-10000000000.0, 0.0),
+        (-100000000000.0, 0.0),
+        (-1000000000000.0, 0.0),
+        (-10000000000000.0, 0.0),
+        (-100000000000000.0, 0.0),
+        (-1000000000000000.0, 0.0),
+        (-1e+16, 0.0),
+        (-1e+17, 0.0),
+        (-1e+18, 0.0),
+        (-1e+19, 0.0),
+        (1e-20, 1.0),
+        (1e-19, 1.0),
+        (1e-18, 1.0),
+        (1e-17, 1.0),
+        (1e-16, 1.000_000_000_000_000_4),
+        (1e-15, 1.000_000_000_000_005),
+        (1e-14, 1.000_000_000_000_050_2),
+        (1e-13, 1.000_000_000_000_501_2),
+        (1e-12, 1.000_000_000_005_010_7),
+        (1e-11, 1.000_000_000_050_106_4),
+        (1e-10, 1.000_000_000_501_063_6),
+        (1e-09, 1.000_000_005_010_635_2),
+        (1e-08, 1.000_000_050_106_354_2),
+        (1e-07, 1.000_000_501_063_655),
+        (1e-06, 1.000_005_010_647_847_3),
+        (1e-05, 1.000_050_107_608_285_3),
+        (1e-04, 1.000_501_189_082_709_1),
+        (0.001, 1.005_023_209_519_969_2),
+        (0.01, 1.051_382_908_094_519_6),
+        (0.1, 1.650_475_667_020_468_8),
+        (1.0, 150.0),
+        (10.0, 5.766_503_906_25e21),
+    ];
+
+    /// misc powf(x,n) test vectors - `(base_input, power_input, output)`
+    pub(crate) const TEST_VECTORS_MISC: &[(f64, f64, f64)] = &[
+        (-0.5881598, 2.0, 0.345_931_950_336_04),
+        (-0.5881598, 3.2, f64::NAN),
+        (-0.5881598, 3.0, -0.203_463_266_723_255_24),
+        (-1000000.0, 4.0, 1.0e24),
+    ];
+
+    fn calc_relative_error(experimental: F64, expected: f64) -> F64 {
+        if experimental.is_nan() && expected.is_nan() {
+            F64::ZERO
+        } else if expected != 0.0 {
+            (experimental - expected) / expected
+        } else {
+            (experimental - expected) / (expected + 1.0e-20)
+        }
+    }
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS_POW3 {
+            let exp_x = F64(3.0).powf(F64(x));
+            let relative_error = calc_relative_error(exp_x, expected);
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {} : {} vs {}",
+                relative_error,
+                x,
+                exp_x,
+                expected
+            );
+        }
+
+        for &(x, expected) in TEST_VECTORS_POW150 {
+            let exp_x = F64(150.0).powf(F64(x));
+            let relative_error = calc_relative_error(exp_x, expected);
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {} : {} vs {}",
+                relative_error,
+                x,
+                exp_x,
+                expected
+            );
+        }
+
+        for &(base_input, power_input, expected) in TEST_VECTORS_MISC {
+            let exp_x = F64(base_input).powf(F64(power_input));
+            let relative_error = calc_relative_error(exp_x, expected);
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}.powf({}) : {} vs {}",
+                relative_error,
+                base_input,
+                power_input,
+                exp_x,
+                expected
+            );
+        }
+    }
+
+    /// powf(x,n) test vectors with `x` near `1.0` - `(base_input, power_input, output)`
+    pub(crate) const TEST_VECTORS_NEAR_ONE: &[(f64, f64, f64)] = &[
+        (0.999, 1.0e3, 0.367_695_424_770_963_73),
+        (0.9999, -1.0e3, 1.105_176_444_312_460_3),
+        (1.0001, 1.0e3, 1.105_165_392_603_220_6),
+        (1.001, -1.0e2, 0.904_882_630_897_786_1),
+        (1.01, 0.5, 1.004_987_562_112_089),
+        (0.99, 3.5, 0.965_435_315_237_116_2),
+        (1.1, 2.5, 1.269_058_706_285_883_6),
+    ];
+
+    #[test]
+    fn near_one() {
+        for &(base_input, power_input, expected) in TEST_VECTORS_NEAR_ONE {
+            let exp_x = F64(base_input).powf(F64(power_input));
+            let relative_error = calc_relative_error(exp_x, expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}.powf({}) : {} vs {}",
+                relative_error,
+                base_input,
+                power_input,
+                exp_x,
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! x^n where n is an integer.
+//!
+//! Method described at: <https://stackoverflow.com/a/101613>
+//!
+//! Note: not optimal for all powers, is NP complete
+//! <https://en.wikipedia.org/wiki/Addition-chain_exponentiation>
+//! will result in an addition multiplication for x^15 for example
+
+use super::{F64, MANTISSA_BITS};
+
+impl F64 {
+    /// Approximates a number raised to an integer power.
+    pub fn powi(self, n: i32) -> Self {
+        let mut base = self;
+        let mut abs_n = i32::abs(n);
+        let mut result = 1.0;
+
+        if n < 0 {
+            base = 1.0 / self;
+        }
+
+        if n == 0 {
+            return Self::ONE;
+        }
+
+        // 0.0 == 0.0 and -0.0 according to IEEE standards.
+        if self == Self::ZERO && n > 0 {
+            return self;
+        }
+
+        // For values less than 2.0, but greater than 0.5 (1.0/2.0), you can multiply longer without
+        // going over exponent, i.e. 1.1 multiplied against itself will grow slowly.
+        if !(0.5..2.0).contains(&self.abs().0) {
+            // Approximation if we end up outside of the range of floating point values,
+            // then we end early
+            let approx_final_exponent = self.extract_exponent_value() * n;
+            let max_representable_exponent = 1023;
+            let min_representable_exponent = -1022 - (MANTISSA_BITS as i32);
+            if approx_final_exponent > max_representable_exponent
+                || (self == Self::ZERO && approx_final_exponent < 0)
+            {
+                if self.is_sign_positive() || n & 1 == 0 {
+                    return Self::INFINITY;
+                } else {
+                    return Self::NEG_INFINITY;
+                }
+            } else if approx_final_exponent < min_representable_exponent {
+                // We may want to copy the sign and do the same thing as above,
+                // but that seems like an awful amount of work when 99.99999% of people only care
+                // about bare zero
+                return Self::ZERO;
+            }
+        }
+
+        loop {
+            if (abs_n & 1) == 1 {
+                result *= base;
+            }
+
+            abs_n >>= 1;
+
+            if abs_n == 0 {
+                return Self(result);
+            }
+
+            base *= base;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    /// Error builds up from both exp and ln approximation, so we double the error allowed.
+    pub(crate) const MAX_ERROR: f64 = 0.002;
+
+    /// `powi` test vectors - `(input_base, input_power, output)`
+    pub(crate) const TEST_VECTORS: &[(f64, f64, f64)] = &[
+        (-100000.0, -1075.0, -0.0),
+        (-10.0, -1075.0, -0.0),
+        (-3.0, -1075.0, -0.0),
+        (-2.0, -1075.0, -0.0),
+        (-1.0, -1075.0, -1.0),
+        (1.0, -1075.0, 1.0),
+        (2.0, -1075.0, 0.0),
+        (3.0, -1075.0, 0.0),
+        (10.0, -1075.0, 0.0),
+        (100000.0, -1075.0, 0.0),
+        (-100000.0, -1074.0, 0.0),
+        (-10.0, -1074.0, 0.0),
+        (-3.0, -1074.0, 0.0),
+        (-2.0, -1074.0, 5e-324),
+        (-1.0, -1074.0, 1.0),
+        (1.0, -1074.0, 1.0),
+        (2.0, -1074.0, 5e-324),
+        (3.0, -1074.0, 0.0),
+        (10.0, -1074.0, 0.0),
+        (100000.0, -1074.0, 0.0),
+        (-100000.0, -1022.0, 0.0),
+        (-10.0, -1022.0, 0.0),
+        (-3.0, -1022.0, 0.0),
+        (-2.0, -1022.0, 2.2250738585072014e-308),
+        (-1.0, -1022.0, 1.0),
+        (1.0, -1022.0, 1.0),
+        (2.0, -1022.0, 2.2250738585072014e-308),
+        (3.0, -1022.0, 0.0),
+        (10.0, -1022.0, 0.0),
+        (100000.0, -1022.0, 0.0),
+        (-100000.0, -10.0, 1e-50),
+        (-10.0, -10.0, 1e-10),
+        (-3.0, -10.0, 1.6935087808430286e-05),
+        (-2.0, -10.0, 0.0009765625),
+        (-1.0, -10.0, 1.0),
+        (1.0, -10.0, 1.0),
+        (2.0, -10.0, 0.0009765625),
+        (3.0, -10.0, 1.6935087808430286e-05),
+        (10.0, -10.0, 1e-10),
+        (100000.0, -10.0, 1e-50),
+        (-100000.0, -3.0, -1e-15),
+        (-10.0, -3.0, -0.001),
+        (-3.0, -3.0, -0.037037037037037035),
+        (-2.0, -3.0, -0.125),
+        (-1.0, -3.0, -1.0),
+        (1.0, -3.0, 1.0),
+        (2.0, -3.0, 0.125),
+        (3.0, -3.0, 0.037037037037037035),
+        (10.0, -3.0, 0.001),
+        (100000.0, -3.0, 1e-15),
+        (-100000.0, -1.0, -1e-05),
+        (-10.0, -1.0, -0.1),
+        (-3.0, -1.0, -0.3333333333333333),
+        (-2.0, -1.0, -0.5),
+        (-1.0, -1.0, -1.0),
+        (1.0, -1.0, 1.0),
+        (2.0, -1.0, 0.5),
+        (3.0, -1.0, 0.3333333333333333),
+        (10.0, -1.0, 0.1),
+        (100000.0, -1.0, 1e-05),
+        (-100000.0, 1.0, -100000.0),
+        (-10.0, 1.0, -10.0),
+        (-3.0, 1.0, -3.0),
+        (-2.0, 1.0, -2.0),
+        (-1.0, 1.0, -1.0),
+        (1.0, 1.0, 1.0),
+        (2.0, 1.0, 2.0),
+        (3.0, 1.0, 3.0),
+        (10.0, 1.0, 10.0),
+        (100000.0, 1.0, 100000.0),
+        (-100000.0, 3.0, -1000000000000000.0),
+        (-10.0, 3.0, -1000.0),
+        (-3.0, 3.0, -27.0),
+        (-2.0, 3.0, -8.0),
+        (-1.0, 3.0, -1.0),
+        (1.0, 3.0, 1.0),
+        (2.0, 3.0, 8.0),
+        (3.0, 3.0, 27.0),
+        (10.0, 3.0, 1000.0),
+        (100000.0, 3.0, 1000000000000000.0),
+        (-100000.0, 10.0, 1e+50),
+        (-10.0, 10.0, 10000000000.0),
+        (-3.0, 10.0, 59049.0),
+        (-2.0, 10.0, 1024.0),
+        (-1.0, 10.0, 1.0),
+        (1.0, 10.0, 1.0),
+        (2.0, 10.0, 1024.0),
+        (3.0, 10.0, 59049.0),
+        (10.0, 10.0, 10000000000.0),
+        (100000.0, 10.0, 1e+50),
+        (-100000.0, 1023.0, -f64::INFINITY),
+        (-10.0, 1023.0, -f64::INFINITY),
+        (-3.0, 1023.0, -f64::INFINITY),
+        (-2.0, 1023.0, -8.98846567431158e+307),
+        (-1.0, 1023.0, -1.0),
+        (1.0, 1023.0, 1.0),
+        (2.0, 1023.0, 8.98846567431158e+307),
+        (3.0, 1023.0, f64::INFINITY),
+        (10.0, 1023.0, f64::INFINITY),
+        (100000.0, 1023.0, f64::INFINITY),
+        (-100000.0, 1024.0, f64::INFINITY),
+        (-10.0, 1024.0, f64::INFINITY),
+        (-3.0, 1024.0, f64::INFINITY),
+        (-2.0, 1024.0, f64::INFINITY),
+        (-1.0, 1024.0, 1.0),
+        (1.0, 1024.0, 1.0),
+        (2.0, 1024.0, f64::INFINITY),
+        (3.0, 1024.0, f64::INFINITY),
+        (10.0, 1024.0, f64::INFINITY),
+        (100000.0, 1024.0, f64::INFINITY),
+        (0.0, 3.0, 0.0),
+        (-0.0, 3.0, 0.0),
+        (0.0, -3.0, f64::INFINITY),
+        (-0.0, -3.0, -f64::INFINITY),
+        (-0.0, -2.0, f64::INFINITY),
+        (0.75, -150.0, 5.505673983721651e+18),
+        (-0.75, -150.0, 5.505673983721651e+18),
+        (1.1, -150.0, 6.18154772040442e-07),
+        (1.1, 150.0, 1617717.8357762096),
+    ];
+
+    fn calc_relative_error(experimental: F64, expected: f64) -> F64 {
+        if experimental.is_nan() && expected.is_nan() || experimental.0 == expected {
+            F64::ZERO
+        } else if expected != 0.0 {
+            (experimental - expected).abs() / expected
+        } else {
+            (experimental - expected).abs() / (expected + 1.0e-20)
+        }
+    }
+
+    #[test]
+    fn sanity_check() {
+        for &(base_input, power_input, expected) in TEST_VECTORS {
+            let exp_x = F64(base_input).powi(power_input as i32);
+            let relative_error = calc_relative_error(exp_x, expected);
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}.powi({}) : {} vs {}",
+                relative_error,
+                base_input,
+                power_input,
+                exp_x,
+                expected
+            );
+        }
+    }
+}
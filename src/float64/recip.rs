@@ -0,0 +1,46 @@
+//! Takes the reciprocal (inverse) of a number, `1/x`.
+
+use super::F64;
+
+impl F64 {
+    /// Returns the reciprocal (inverse) of a number, `1/x`.
+    pub fn recip(self) -> Self {
+        Self(1.0 / self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    pub(crate) const MAX_ERROR: f64 = f64::EPSILON;
+
+    pub(crate) const TEST_VECTORS: &[(f64, f64)] = &[
+        (0.00001, 100000.0),
+        (1.0, 1.0),
+        (2.0, 0.5),
+        (0.25, 4.0),
+        (-0.5, -2.0),
+        (core::f64::consts::PI, 1.0 / core::f64::consts::PI),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F64(0.0).recip(), F64(f64::INFINITY));
+        assert_eq!(F64(-0.0).recip(), F64(f64::NEG_INFINITY));
+
+        for &(x, expected) in TEST_VECTORS {
+            let recip_x = F64(x).recip();
+            let relative_error = (recip_x - expected).abs() / expected;
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {} : {} vs {}",
+                relative_error,
+                x,
+                recip_x,
+                expected
+            );
+        }
+    }
+}
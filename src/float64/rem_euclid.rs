@@ -0,0 +1,32 @@
+//! Calculate Euclidian remainder for a double-precision float.
+
+use super::F64;
+
+impl F64 {
+    /// Calculates the least non-negative remainder of `self (mod rhs)`.
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        let r = self % rhs;
+
+        if r >= Self::ZERO {
+            r
+        } else {
+            r + rhs.abs()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    #[test]
+    fn sanity_check() {
+        let a = F64(7.0);
+        let b = F64(4.0);
+
+        assert_eq!(a.rem_euclid(b), F64(3.0));
+        assert_eq!((-a).rem_euclid(b), F64(1.0));
+        assert_eq!(a.rem_euclid(-b), F64(3.0));
+        assert_eq!((-a).rem_euclid(-b), F64(1.0));
+    }
+}
@@ -0,0 +1,198 @@
+//! Range reduction of angles in radians to `[-π/4, π/4]`, shared by the
+//! trigonometric functions.
+//!
+//! Small and moderately large arguments use Cody-Waite reduction, where `π/2`
+//! is split into parts of 33 significant bits so `x - k·π/2` can be computed
+//! without losing the low bits of `x`. Arguments too large for that use
+//! Payne-Hanek reduction, multiplying the integer mantissa of `x` by the
+//! relevant 128 bits of `2/π` to get the fraction of a quarter turn.
+//!
+//! Method described at: <https://doi.org/10.1145/1057600.1057602>
+
+use super::{F64, MANTISSA_BITS, MANTISSA_MASK};
+use core::f64::consts::{FRAC_2_PI, FRAC_PI_2, FRAC_PI_4};
+
+/// Upper 33 bits of `π/2`, so `k * PIO2_1` is exact for `|k| < 2^20`.
+const PIO2_1: f64 = f64::from_bits(0x3ff9_21fb_5440_0000);
+
+/// Next 33 bits of `π/2`.
+const PIO2_2: f64 = f64::from_bits(0x3dd0_b461_1a60_0000);
+
+/// Next 33 bits of `π/2`.
+const PIO2_3: f64 = f64::from_bits(0x3ba3_198a_2e00_0000);
+
+/// Remaining bits of `π/2`.
+const PIO2_4: f64 = f64::from_bits(0x397b_839a_2520_49c1);
+
+/// Largest argument reduced using Cody-Waite reduction, `2^20 · π/2`.
+const CODY_WAITE_MAX: f64 = 1_048_576.0 * FRAC_PI_2;
+
+/// `2^-128`, which converts the fixed-point fraction of a quarter turn.
+const TWO_POW_NEG_128: f64 = f64::from_bits(0x37f0_0000_0000_0000);
+
+/// Bits of `2/π` after the binary point, preceded by 64 zero bits.
+const FRAC_2_PI_BITS: [u32; 40] = [
+    0x0000_0000,
+    0x0000_0000,
+    0xa2f9_836e,
+    0x4e44_1529,
+    0xfc27_57d1,
+    0xf534_ddc0,
+    0xdb62_9599,
+    0x3c43_9041,
+    0xfe51_63ab,
+    0xdebb_c561,
+    0xb724_6e3a,
+    0x424d_d2e0,
+    0x0649_2eea,
+    0x09d1_921c,
+    0xfe1d_eb1c,
+    0xb129_a73e,
+    0xe882_35f5,
+    0x2ebb_4484,
+    0xe99c_7026,
+    0xb45f_7e41,
+    0x3991_d639,
+    0x8353_39f4,
+    0x9c84_5f8b,
+    0xbdf9_283b,
+    0x1ff8_97ff,
+    0xde05_980f,
+    0xef2f_118b,
+    0x5a0a_6d1f,
+    0x6d36_7ecf,
+    0x27cb_09b7,
+    0x4f46_3f66,
+    0x9e5f_ea2d,
+    0x7527_bac7,
+    0xebe5_f17b,
+    0x3d07_39f7,
+    0x8a52_92ea,
+    0x6bfb_5fb1,
+    0x1f8d_5d08,
+    0x5603_3046,
+    0xfc7b_6bab,
+];
+
+impl F64 {
+    /// Reduces an angle in radians to `r` in `[-π/4, π/4]`, where
+    /// `self = r + k·π/2`, returning `(k mod 4, r)`.
+    ///
+    /// Returns [`Self::NAN`] for `r` if `self` is infinite or `NaN`.
+    pub(crate) fn rem_pio2(self) -> (u32, Self) {
+        if !self.is_finite() {
+            return (0, Self::NAN);
+        }
+
+        if self.abs() <= FRAC_PI_4 {
+            return (0, self);
+        }
+
+        if self.abs() <= CODY_WAITE_MAX {
+            let k = (self * FRAC_2_PI).round();
+            let r = (((self - k * PIO2_1) - k * PIO2_2) - k * PIO2_3) - k * PIO2_4;
+            return (k.0 as i32 as u32 & 3, r);
+        }
+
+        let (quadrant, r) = self.rem_pio2_large();
+
+        if self.is_sign_negative() {
+            (quadrant.wrapping_neg() & 3, -r)
+        } else {
+            (quadrant, r)
+        }
+    }
+
+    /// Payne-Hanek reduction of `|self|` for arguments too large for
+    /// Cody-Waite reduction.
+    fn rem_pio2_large(self) -> (u32, Self) {
+        // `|x| = m * 2^e`, where `m` is the integer mantissa
+        let bits = self.to_bits();
+        let m = u128::from((bits & MANTISSA_MASK) | (1 << MANTISSA_BITS));
+        let e = self.extract_exponent_value() - MANTISSA_BITS as i32;
+
+        // Bits of `2/π` at positions `e - 1..=e + 126` after the binary point:
+        // the ones before only contribute multiples of 4 quarter turns, and
+        // the ones after are too small to affect the result.
+        let start = (e + 62) as usize;
+        let (word, offset) = (start / 32, start % 32);
+        let high = FRAC_2_PI_BITS[word..word + 4]
+            .iter()
+            .fold(0_u128, |acc, &w| acc << 32 | u128::from(w));
+        let window = high << offset | u128::from(FRAC_2_PI_BITS[word + 4]) >> (32 - offset);
+
+        // `|x| · 2/π mod 4` as a fixed-point number with 126 fractional bits
+        let product =
+            (m * (window & u128::from(u64::MAX))).wrapping_add((m * (window >> 64)) << 64);
+
+        // The fraction of a quarter turn is reinterpreted as signed to get a
+        // fraction in `[-0.5, 0.5)`, rounding the quadrant to nearest
+        let fraction = (product << 2) as i128;
+        let quadrant = ((product >> 126) as u32 + u32::from(fraction < 0)) & 3;
+        let r = Self(fraction as f64 * TWO_POW_NEG_128) * FRAC_PI_2;
+
+        (quadrant, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use core::f64::consts::FRAC_PI_2;
+
+    /// Checks that `x = r + k·π/2` using the `std` sine and cosine.
+    fn check(x: f64) {
+        let (quadrant, r) = F64(x).rem_pio2();
+        let (sin_r, cos_r) = (r.0.sin(), r.0.cos());
+        let (sin_x, cos_x) = match quadrant {
+            0 => (sin_r, cos_r),
+            1 => (cos_r, -sin_r),
+            2 => (-sin_r, -cos_r),
+            _ => (-cos_r, sin_r),
+        };
+
+        assert!(r.abs() <= FRAC_PI_2 / 2.0 + 1e-15, "{} reduced to {}", x, r);
+        assert!(
+            (sin_x - x.sin()).abs() <= 2e-16,
+            "sin({}): {} vs {}",
+            x,
+            sin_x,
+            x.sin()
+        );
+        assert!(
+            (cos_x - x.cos()).abs() <= 2e-16,
+            "cos({}): {} vs {}",
+            x,
+            cos_x,
+            x.cos()
+        );
+    }
+
+    #[test]
+    fn cody_waite() {
+        for i in -10_000..=10_000 {
+            check(i as f64 * 0.123_456_7);
+            check(i as f64 * 101.234_567);
+        }
+    }
+
+    #[test]
+    fn payne_hanek() {
+        let mut x = 2e6;
+
+        while x < f64::MAX / 1.1 {
+            check(x);
+            check(-x);
+            x *= 1.1;
+        }
+
+        check(f64::MAX);
+        check(f64::MIN);
+    }
+
+    #[test]
+    fn non_finite() {
+        assert!(F64::NAN.rem_pio2().1.is_nan());
+        assert!(F64::INFINITY.rem_pio2().1.is_nan());
+    }
+}
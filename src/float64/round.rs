@@ -0,0 +1,33 @@
+//! Round a double-precision float.
+
+use super::F64;
+
+impl F64 {
+    /// Returns the nearest integer to a number.
+    pub fn round(self) -> Self {
+        Self(((self.0 + Self(0.5).copysign(self).0) as i64) as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F64(0.0).round(), F64(0.0));
+        assert_eq!(F64(-0.0).round(), F64(-0.0));
+
+        assert_eq!(F64(0.49999).round(), F64(0.0));
+        assert_eq!(F64(-0.49999).round(), F64(-0.0));
+
+        assert_eq!(F64(0.5).round(), F64(1.0));
+        assert_eq!(F64(-0.5).round(), F64(-1.0));
+
+        assert_eq!(F64(9999.499).round(), F64(9999.0));
+        assert_eq!(F64(-9999.499).round(), F64(-9999.0));
+
+        assert_eq!(F64(9999.5).round(), F64(10000.0));
+        assert_eq!(F64(-9999.5).round(), F64(-10000.0));
+    }
+}
@@ -0,0 +1,78 @@
+//! Multiply a double-precision float by an integer power of two.
+
+use super::{EXPONENT_BIAS, F64, MANTISSA_BITS};
+
+/// `2^1023`
+const TWO_POW_1023: f64 = f64::from_bits(0x7fe0_0000_0000_0000);
+
+/// `2^-969`, i.e. `2^-1022 * 2^53`, which scales subnormal results in two
+/// steps without rounding twice.
+const TWO_POW_NEG_969: f64 = f64::from_bits(0x0360_0000_0000_0000);
+
+impl F64 {
+    /// Computes `self * 2^n` exactly, other than overflowing to infinity or
+    /// rounding subnormal results.
+    pub(crate) fn scalbn(self, n: i32) -> Self {
+        let mut x = self.0;
+        let mut n = n;
+
+        if n > 1023 {
+            x *= TWO_POW_1023;
+            n -= 1023;
+
+            if n > 1023 {
+                x *= TWO_POW_1023;
+                n -= 1023;
+
+                if n > 1023 {
+                    n = 1023;
+                }
+            }
+        } else if n < -1022 {
+            x *= TWO_POW_NEG_969;
+            n += 969;
+
+            if n < -1022 {
+                x *= TWO_POW_NEG_969;
+                n += 969;
+
+                if n < -1022 {
+                    n = -1022;
+                }
+            }
+        }
+
+        Self(x * f64::from_bits(((n + EXPONENT_BIAS as i32) as u64) << MANTISSA_BITS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    /// `scalbn` test vectors - `(input, n, output)`
+    const TEST_VECTORS: &[(f64, i32, f64)] = &[
+        (1.0, 0, 1.0),
+        (1.0, 1, 2.0),
+        (-3.0, 4, -48.0),
+        (1.0, -1, 0.5),
+        (1.0, 1023, 8.988_465_674_311_58e307),
+        (1.0, 1024, f64::INFINITY),
+        (-1.0, 10_000, f64::NEG_INFINITY),
+        (f64::MIN_POSITIVE, 2045, 8.988_465_674_311_58e307),
+        (1.0, -1074, 5e-324),
+        (1.0, -1075, 0.0),
+        (1.5, -1074, 1e-323),
+        (f64::MAX, -2097, 1e-323),
+        (1.0, -10_000, 0.0),
+        (5e-324, 1074, 1.0),
+        (5e-324, 2097, 8.988_465_674_311_58e307),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, n, expected) in TEST_VECTORS {
+            assert_eq!(F64(x).scalbn(n).0, expected, "scalbn({}, {})", x, n);
+        }
+    }
+}
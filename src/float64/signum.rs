@@ -0,0 +1,33 @@
+//! Returns a number that represents the sign of `self`.
+
+use super::F64;
+
+impl F64 {
+    /// Returns a number that represents the sign of `self`.
+    ///
+    /// * `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// * `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    /// * `NAN` if the number is `NAN`
+    pub fn signum(self) -> Self {
+        if self.is_nan() {
+            Self::NAN
+        } else {
+            F64(1.0).copysign(self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F64::INFINITY.signum(), F64(1.0));
+        assert_eq!(F64(0.0).signum(), F64(1.0));
+        assert_eq!(F64(1.0).signum(), F64(1.0));
+        assert_eq!(F64::NEG_INFINITY.signum(), F64(-1.0));
+        assert_eq!(F64(-0.0).signum(), F64(-1.0));
+        assert_eq!(F64(-1.0).signum(), F64(-1.0));
+    }
+}
@@ -0,0 +1,126 @@
+//! Sine approximation for a double-precision float.
+//!
+//! The angle is reduced to `[-π/4, π/4]` by [`F64::rem_pio2`], where the sine
+//! and cosine are evaluated with their Taylor series.
+
+use super::F64;
+use crate::poly;
+
+/// Coefficients of the Taylor series of `(sin(x) - x) / x³` in terms of `x²`,
+/// i.e. `(-1)^(n + 1) / (2n + 3)!`.
+const SIN_SERIES: [f64; 8] = [
+    -1.0 / 6.0,
+    1.0 / 120.0,
+    -1.0 / 5_040.0,
+    1.0 / 362_880.0,
+    -1.0 / 39_916_800.0,
+    1.0 / 6_227_020_800.0,
+    -1.0 / 1_307_674_368_000.0,
+    1.0 / 355_687_428_096_000.0,
+];
+
+impl F64 {
+    /// Approximates `sin(x)` in radians with a maximum error of `4e-16`.
+    pub fn sin(self) -> Self {
+        let (quadrant, r) = self.rem_pio2();
+
+        match quadrant {
+            0 => r.sin_kernel(),
+            1 => r.cos_kernel(),
+            2 => -r.sin_kernel(),
+            _ => -r.cos_kernel(),
+        }
+    }
+
+    /// Approximates `sin(x)` for `x` in `[-π/4, π/4]`.
+    pub(crate) fn sin_kernel(self) -> Self {
+        let x2 = self * self;
+        self + self * x2 * poly::horner_f64(x2, &SIN_SERIES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::cos::tests::MAX_ERROR;
+    use crate::float64::tests::check_against_std;
+
+    /// Sine test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
+    const TEST_VECTORS: &[(f64, f64)] = &[
+        (0.000, 0.0),
+        (0.140, 0.139_543_114_644_236_5),
+        (0.279, 0.275_394_455_108_166_1),
+        (0.419, 0.406_847_160_391_229_8),
+        (0.559, 0.530_338_677_358_002_3),
+        (0.698, 0.642_686_715_447_966_4),
+        (0.838, 0.743_306_705_923_383_4),
+        (0.977, 0.828_822_568_122_907_9),
+        (1.117, 0.898_789_346_349_293),
+        (1.257, 0.951_168_607_838_232_4),
+        (1.396, 0.984_761_979_642_512_1),
+        (1.536, 0.999_394_668_901_607_9),
+        (1.676, 0.994_471_195_709_496_6),
+        (1.815, 0.970_330_171_453_260_1),
+        (1.955, 0.927_097_206_586_957_9),
+        (2.094, 0.866_222_887_380_059_5),
+        (2.234, 0.788_023_954_779_219_7),
+        (2.374, 0.694_404_963_462_194_9),
+        (2.513, 0.588_007_000_267_480_9),
+        (2.653, 0.469_383_674_533_523_1),
+        (2.793, 0.341_575_445_500_840_8),
+        (2.932, 0.208_061_485_179_943_47),
+        (3.072, 0.069_536_492_726_972_36),
+        (3.211, -0.069_351_632_907_537_24),
+        (3.351, -0.207_880_229_737_944_1),
+        (3.491, -0.342_341_024_658_203_15),
+        (3.630, -0.469_220_041_288_727_13),
+        (3.770, -0.587_857_103_378_482_7),
+        (3.910, -0.694_990_973_216_472),
+        (4.049, -0.787_909_857_889_575_8),
+        (4.189, -0.866_130_282_331_839),
+        (4.328, -0.927_027_733_650_406_4),
+        (4.468, -0.970_285_350_537_736_9),
+        (4.608, -0.994_556_416_336_099_8),
+        (4.747, -0.999_401_098_450_654_5),
+        (4.887, -0.984_794_189_056_176_8),
+        (5.027, -0.950_916_819_510_701_7),
+        (5.166, -0.898_870_566_023_655_8),
+        (5.306, -0.828_926_235_395_248_7),
+        (5.445, -0.743_430_654_542_174_9),
+        (5.585, -0.642_828_673_633_883_7),
+        (5.725, -0.529_647_816_370_673_8),
+        (5.864, -0.407_016_430_817_516_4),
+        (6.004, -0.275_572_591_973_127_9),
+        (6.144, -0.138_736_346_561_621_6),
+        (6.283, -0.000_185_307_178_525_578_36),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let sin_x = F64(x).sin();
+            let delta = (sin_x - expected).abs();
+
+            assert!(
+                delta <= MAX_ERROR,
+                "delta {} too large: {} vs {}",
+                delta,
+                sin_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::sin, f64::sin, (-10.0, 10.0), 100_000, 4e-16);
+        check_against_std(F64::sin, f64::sin, (-1e9, 1e9), 100_000, 4e-16);
+    }
+
+    #[test]
+    fn non_finite() {
+        assert!(F64::NAN.sin().is_nan());
+        assert!(F64::INFINITY.sin().is_nan());
+    }
+}
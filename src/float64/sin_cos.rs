@@ -0,0 +1,45 @@
+//! Simultaneously computes the sine and cosine of the number, `x`.
+
+use super::F64;
+
+impl F64 {
+    /// Simultaneously computes the sine and cosine of the number, `x`.
+    /// Returns `(sin(x), cos(x))`.
+    ///
+    /// The angle is only reduced once, so this is faster than calling
+    /// [`F64::sin`] and [`F64::cos`].
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (quadrant, r) = self.rem_pio2();
+        let (sin_r, cos_r) = (r.sin_kernel(), r.cos_kernel());
+
+        match quadrant {
+            0 => (sin_r, cos_r),
+            1 => (cos_r, -sin_r),
+            2 => (-sin_r, -cos_r),
+            _ => (-cos_r, sin_r),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    #[allow(clippy::approx_constant)]
+    const TEST_VECTORS: &[f64] = &[
+        0.000, 0.140, 0.279, 0.419, 0.559, 0.698, 0.838, 0.977, 1.117, 1.257, 1.396, 1.536, 1.676,
+        1.815, 1.955, 2.094, 2.234, 2.374, 2.513, 2.653, 2.793, 2.932, 3.072, 3.211, 3.351, 3.491,
+        3.630, 3.770, 3.910, 4.049, 4.189, 4.328, 4.468, 4.608, 4.747, 4.887, 5.027, 5.166, 5.306,
+        5.445, 5.585, 5.725, 5.864, 6.004, 6.144, 6.283,
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &x in TEST_VECTORS {
+            let sin_x = F64(x).sin();
+            let cos_x = F64(x).cos();
+
+            assert_eq!(F64(x).sin_cos(), (sin_x, cos_x));
+        }
+    }
+}
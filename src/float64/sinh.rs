@@ -0,0 +1,123 @@
+//! Hyperbolic sine function
+
+use super::{exp::MAX_INPUT, F64};
+
+impl F64 {
+    /// Approximates `sinh(x)` with a maximum relative error of `5e-16`.
+    ///
+    /// Computed from `t = e^|x| - 1` as `(t + t / (t + 1)) / 2`, which avoids
+    /// the cancellation in `(e^x - e^-x) / 2` for small `x`.
+    pub fn sinh(self) -> Self {
+        let x = self.abs();
+
+        let sinh_x = if x < 22.0 {
+            let t = x.exp_m1();
+            (t + t / (t + 1.0)) / 2.0
+        } else if x <= MAX_INPUT {
+            // `e^-x` is too small to affect the result
+            x.exp() / 2.0
+        } else {
+            // `e^x` overflows before `e^x / 2` does
+            let exp_half_x = (x / 2.0).exp();
+            exp_half_x * (exp_half_x / 2.0)
+        };
+
+        sinh_x.copysign(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    /// Maximum relative error
+    const MAX_ERROR: f64 = 5e-16;
+
+    /// Hyperbolic sine test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f64, f64)] = &[
+        (-6.0, -201.713_157_370_279_22),
+        (-5.75, -157.093_738_752_448_84),
+        (-5.5, -122.343_922_746_390_96),
+        (-5.25, -95.280_510_470_115_4),
+        (-5.0, -74.203_210_577_788_75),
+        (-4.75, -57.787_816_415_992_27),
+        (-4.5, -45.003_011_151_991_785),
+        (-4.25, -35.045_574_056_389_43),
+        (-4.0, -27.289_917_197_127_75),
+        (-3.75, -21.248_782_127_103_386),
+        (-3.5, -16.542_627_287_634_996),
+        (-3.25, -12.875_782_854_680_67),
+        (-3.0, -10.017_874_927_409_903),
+        (-2.75, -7.789_352_011_490_732),
+        (-2.5, -6.050_204_481_039_787_5),
+        (-2.25, -4.691_168_305_898_331),
+        (-2.0, -3.626_860_407_847_019),
+        (-1.75, -2.790_414_366_277_642),
+        (-1.5, -2.129_279_455_094_817_3),
+        (-1.25, -1.601_919_080_300_825_6),
+        (-1.0, -1.175_201_193_643_801_4),
+        (-0.75, -0.822_316_731_935_83),
+        (-0.5, -0.521_095_305_493_747_4),
+        (-0.25, -0.252_612_316_808_168_3),
+        (0.25, 0.252_612_316_808_168_3),
+        (0.5, 0.521_095_305_493_747_4),
+        (0.75, 0.822_316_731_935_83),
+        (1.0, 1.175_201_193_643_801_4),
+        (1.25, 1.601_919_080_300_825_6),
+        (1.5, 2.129_279_455_094_817_3),
+        (1.75, 2.790_414_366_277_642),
+        (2.0, 3.626_860_407_847_019),
+        (2.25, 4.691_168_305_898_331),
+        (2.5, 6.050_204_481_039_787_5),
+        (2.75, 7.789_352_011_490_732),
+        (3.0, 10.017_874_927_409_903),
+        (3.25, 12.875_782_854_680_67),
+        (3.5, 16.542_627_287_634_996),
+        (3.75, 21.248_782_127_103_386),
+        (4.0, 27.289_917_197_127_75),
+        (4.25, 35.045_574_056_389_43),
+        (4.5, 45.003_011_151_991_785),
+        (4.75, 57.787_816_415_992_27),
+        (5.0, 74.203_210_577_788_75),
+        (5.25, 95.280_510_470_115_4),
+        (5.5, 122.343_922_746_390_96),
+        (5.75, 157.093_738_752_448_84),
+        (6.0, 201.713_157_370_279_22),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let sinh_x = F64(x).sinh();
+            let relative_error = ((sinh_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                sinh_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(F64::ZERO.sinh(), F64::ZERO);
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::sinh, f64::sinh, (-1.0, 1.0), 100_000, MAX_ERROR);
+        check_against_std(F64::sinh, f64::sinh, (-710.0, 710.0), 100_000, MAX_ERROR);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(F64(711.0).sinh(), F64::INFINITY);
+        assert_eq!(F64::NEG_INFINITY.sinh(), F64::NEG_INFINITY);
+        assert!(F64::NAN.sinh().is_nan());
+    }
+}
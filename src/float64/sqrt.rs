@@ -0,0 +1,91 @@
+//! Square root approximation function for a double-precision float.
+//!
+//! The inverse square root from [`F64::invsqrt`] is multiplied by `x`, and
+//! refined with one step of Newton's method.
+
+use super::F64;
+
+impl F64 {
+    /// Approximates the square root of a number with a maximum relative error
+    /// of `3e-16`.
+    ///
+    /// Returns [`Self::NAN`] if `self` is a negative number.
+    pub fn sqrt(self) -> Self {
+        if self.is_nan() || self < Self::ZERO {
+            return Self::NAN;
+        }
+
+        if self == Self::ZERO || self.is_infinite() {
+            return self;
+        }
+
+        let y = self * self.invsqrt();
+        0.5 * (y + self / y)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    /// Maximum relative error
+    pub(crate) const MAX_ERROR: f64 = 3e-16;
+
+    /// Square root test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
+    pub(crate) const TEST_VECTORS: &[(f64, f64)] = &[
+        (1.0, 1.0),
+        (2.0, 1.414_213_562_373_095_1),
+        (3.0, 1.732_050_807_568_877_2),
+        (4.0, 2.0),
+        (5.0, 2.236_067_977_499_79),
+        (10.0, 3.162_277_660_168_379_5),
+        (100.0, 10.0),
+        (250.0, 15.811_388_300_841_896),
+        (500.0, 22.360_679_774_997_898),
+        (1000.0, 31.622_776_601_683_793),
+        (2500.0, 50.0),
+        (5000.0, 70.710_678_118_654_76),
+        (1000000.0, 1000.0),
+        (2500000.0, 1_581.138_830_084_189_7),
+        (5000000.0, 2_236.067_977_499_79),
+        (10000000.0, 3_162.277_660_168_379_5),
+        (25000000.0, 5000.0),
+        (50000000.0, 7_071.067_811_865_475),
+        (100000000.0, 10_000.0),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let sqrt_x = F64(x).sqrt();
+            let relative_error = ((sqrt_x - expected) / expected).abs();
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                sqrt_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::sqrt, f64::sqrt, (0.0, 2.0), 100_000, MAX_ERROR);
+        check_against_std(F64::sqrt, f64::sqrt, (0.0, 1e300), 100_000, MAX_ERROR);
+        check_against_std(F64::sqrt, f64::sqrt, (0.0, 1e-300), 100_000, MAX_ERROR);
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(F64(-1.0).sqrt().is_nan());
+        assert!(F64::NAN.sqrt().is_nan());
+        assert_eq!(F64::ZERO.sqrt(), F64::ZERO);
+        assert!(F64(-0.0).sqrt().is_sign_negative());
+        assert_eq!(F64::INFINITY.sqrt(), F64::INFINITY);
+    }
+}
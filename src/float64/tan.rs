@@ -0,0 +1,115 @@
+//! Tangent approximation for a double-precision float.
+
+use super::F64;
+
+impl F64 {
+    /// Approximates `tan(x)` in radians with a maximum relative error of
+    /// `5e-16`.
+    pub fn tan(self) -> Self {
+        let (quadrant, r) = self.rem_pio2();
+        let (sin_r, cos_r) = (r.sin_kernel(), r.cos_kernel());
+
+        if quadrant % 2 == 0 {
+            sin_r / cos_r
+        } else {
+            -cos_r / sin_r
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    /// Maximum relative error
+    const MAX_ERROR: f64 = 5e-16;
+
+    /// Tangent test vectors - `(input, output)`
+    #[allow(clippy::approx_constant)]
+    const TEST_VECTORS: &[(f64, f64)] = &[
+        (0.000, 0.0),
+        (0.140, 0.140_921_894_998_625_4),
+        (0.279, 0.286_471_949_133_007_66),
+        (0.419, 0.445_373_654_238_143_2),
+        (0.559, 0.625_557_341_704_103_6),
+        (0.698, 0.838_875_226_200_831_2),
+        (0.838, 1.111_153_065_979_938_5),
+        (0.977, 1.481_332_422_737_303),
+        (1.117, 2.050_248_052_008_308_6),
+        (1.257, 3.081_488_529_543_4),
+        (1.396, 5.662_559_546_620_015),
+        (1.536, 28.727_065_891_879_13),
+        (1.676, -9.470_277_735_351_548),
+        (1.815, -4.013_215_799_762_274),
+        (1.955, -2.473_439_982_920_829_6),
+        (2.094, -1.733_632_299_498_058_6),
+        (2.234, -1.279_998_204_572_975_6),
+        (2.374, -0.965_008_327_007_739_9),
+        (2.513, -0.726_961_433_925_751_2),
+        (2.653, -0.531_581_760_863_290_3),
+        (2.793, -0.363_434_443_265_266_2),
+        (2.932, -0.212_716_629_293_486_64),
+        (3.072, -0.069_705_220_594_382_72),
+        (3.211, 0.069_519_015_444_035_4),
+        (3.351, 0.212_522_944_899_535_02),
+        (3.491, 0.364_357_017_823_391_5),
+        (3.630, 0.531_344_113_141_968_9),
+        (3.770, 0.726_678_235_065_491_9),
+        (3.910, 0.966_582_933_523_882_1),
+        (4.049, 1.279_509_406_895_804),
+        (4.189, 1.732_890_293_484_638_2),
+        (4.328, 2.472_121_588_222_117_2),
+        (4.468, 4.010_048_309_039_362),
+        (4.608, 9.544_733_569_708_587),
+        (4.747, -28.880_994_309_259_91),
+        (4.887, -5.668_693_086_872_105),
+        (5.027, -3.072_959_273_274_998),
+        (5.166, -2.051_212_667_574_631_6),
+        (5.306, -1.481_924_520_476_753_7),
+        (5.445, -1.111_567_250_019_545),
+        (5.585, -0.839_190_985_288_858_6),
+        (5.725, -0.624_424_418_798_658_7),
+        (5.864, -0.445_595_736_853_391_2),
+        (6.004, -0.286_672_474_411_754_14),
+        (6.144, -0.140_091_118_405_429_26),
+        (6.283, -0.000_185_307_181_707_186_93),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let tan_x = F64(x).tan();
+            let relative_error = if expected == 0.0 {
+                tan_x.abs()
+            } else {
+                ((tan_x - expected) / expected).abs()
+            };
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                tan_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(F64::ZERO.tan(), F64::ZERO);
+    }
+
+    #[test]
+    fn nan() {
+        assert!(F64::NAN.tan().is_nan());
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::tan, f64::tan, (-10.0, 10.0), 100_000, MAX_ERROR);
+        check_against_std(F64::tan, f64::tan, (-1e9, 1e9), 100_000, MAX_ERROR);
+    }
+}
@@ -0,0 +1,112 @@
+//! Hyperbolic tangent function
+
+use super::F64;
+
+impl F64 {
+    /// Approximates `tanh(x)` with a maximum relative error of `6e-16`.
+    ///
+    /// Computed from `t = e^(2|x|) - 1` as `t / (t + 2)` with the sign of `x`
+    /// restored, saturating to `±1` for `|x| > 22`.
+    pub fn tanh(self) -> Self {
+        if self.is_nan() {
+            return self;
+        }
+
+        let x = self.abs();
+
+        let tanh_x = if x > 22.0 {
+            Self::ONE
+        } else {
+            let t = (2.0 * x).exp_m1();
+            t / (t + 2.0)
+        };
+
+        tanh_x.copysign(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+    use crate::float64::tests::check_against_std;
+
+    /// Maximum relative error
+    const MAX_ERROR: f64 = 6e-16;
+
+    /// Hyperbolic tangent test vectors - `(input, output)`
+    const TEST_VECTORS: &[(f64, f64)] = &[
+        (-4.0, -0.999_329_299_739_067),
+        (-3.75, -0.998_894_442_726_152_8),
+        (-3.5, -0.998_177_897_611_198_7),
+        (-3.25, -0.996_997_635_486_526),
+        (-3.0, -0.995_054_753_686_730_5),
+        (-2.75, -0.991_859_724_568_207_7),
+        (-2.5, -0.986_614_298_151_430_3),
+        (-2.25, -0.978_026_114_738_813_6),
+        (-2.0, -0.964_027_580_075_816_9),
+        (-1.75, -0.941_375_538_497_287_4),
+        (-1.5, -0.905_148_253_644_866_4),
+        (-1.25, -0.848_283_639_957_512_9),
+        (-1.0, -0.761_594_155_955_764_9),
+        (-0.75, -0.635_148_952_387_287_3),
+        (-0.5, -0.462_117_157_260_009_74),
+        (-0.25, -0.244_918_662_403_709_13),
+        (0.0, 0.0),
+        (0.25, 0.244_918_662_403_709_13),
+        (0.5, 0.462_117_157_260_009_74),
+        (0.75, 0.635_148_952_387_287_3),
+        (1.0, 0.761_594_155_955_764_9),
+        (1.25, 0.848_283_639_957_512_9),
+        (1.5, 0.905_148_253_644_866_4),
+        (1.75, 0.941_375_538_497_287_4),
+        (2.0, 0.964_027_580_075_816_9),
+        (2.25, 0.978_026_114_738_813_6),
+        (2.5, 0.986_614_298_151_430_3),
+        (2.75, 0.991_859_724_568_207_7),
+        (3.0, 0.995_054_753_686_730_5),
+        (3.25, 0.996_997_635_486_526),
+        (3.5, 0.998_177_897_611_198_7),
+        (3.75, 0.998_894_442_726_152_8),
+        (4.0, 0.999_329_299_739_067),
+        (-2e+01, -1.0),
+        (2e+01, 1.0),
+    ];
+
+    #[test]
+    fn sanity_check() {
+        for &(x, expected) in TEST_VECTORS {
+            let tanh_x = F64(x).tanh();
+            let relative_error = if expected == 0.0 {
+                tanh_x.abs()
+            } else {
+                ((tanh_x - expected) / expected).abs()
+            };
+
+            assert!(
+                relative_error <= MAX_ERROR,
+                "relative_error {} too large for input {}: {} vs {}",
+                relative_error,
+                x,
+                tanh_x,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn infinity() {
+        assert_eq!(F64::INFINITY.tanh(), F64::ONE);
+        assert_eq!(F64::NEG_INFINITY.tanh(), -F64::ONE);
+    }
+
+    #[test]
+    fn nan() {
+        assert!(F64::NAN.tanh().is_nan());
+    }
+
+    #[test]
+    fn matches_std() {
+        check_against_std(F64::tanh, f64::tanh, (-1.0, 1.0), 100_000, MAX_ERROR);
+        check_against_std(F64::tanh, f64::tanh, (-30.0, 30.0), 100_000, MAX_ERROR);
+    }
+}
@@ -0,0 +1,53 @@
+//! Floating point whole number for a double-precision float.
+
+use super::{F64, MANTISSA_BITS, MANTISSA_MASK};
+
+impl F64 {
+    /// Returns the integer part of a number.
+    pub fn trunc(self) -> Self {
+        let x_bits = self.to_bits();
+        let exponent = self.extract_exponent_value();
+
+        // exponent is negative, there is no whole number, just return zero
+        if exponent < 0 {
+            return F64::ZERO.copysign(self);
+        }
+
+        // all of the mantissa bits are whole number bits
+        if exponent >= MANTISSA_BITS as i32 {
+            return self;
+        }
+
+        let exponent_clamped = i32::max(exponent, 0) as u32;
+
+        // find the part of the fraction that would be left over
+        let fractional_part = x_bits.overflowing_shl(exponent_clamped).0 & MANTISSA_MASK;
+
+        // if there isn't a fraction we can just return the whole thing.
+        if fractional_part == 0_u64 {
+            return self;
+        }
+
+        let fractional_mask = fractional_part.overflowing_shr(exponent_clamped).0;
+
+        Self::from_bits(x_bits & !fractional_mask)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::F64;
+
+    #[test]
+    fn sanity_check() {
+        assert_eq!(F64(-1.1).trunc(), F64(-1.0));
+        assert_eq!(F64(-0.1).trunc(), F64(-0.0));
+        assert_eq!(F64(0.0).trunc(), F64(0.0));
+        assert_eq!(F64(1.0).trunc(), F64(1.0));
+        assert_eq!(F64(1.1).trunc(), F64(1.0));
+        assert_eq!(F64(2.9).trunc(), F64(2.0));
+
+        assert_eq!(F64(-100_000_000.134_253_45).trunc(), F64(-100_000_000.0));
+        assert_eq!(F64(100_000_000.134_253_45).trunc(), F64(100_000_000.0));
+    }
+}
@@ -0,0 +1,263 @@
+//! 16-bit floating point storage types.
+//!
+//! [`F16`] (IEEE 754 binary16) and [`BF16`] (bfloat16) are storage formats:
+//! conversions to and from [`F32`][`crate::F32`] are bit-exact (correctly
+//! rounded to the nearest value, ties to even), while arithmetic is performed
+//! by converting both operands to `f32` and rounding the result back to 16
+//! bits.
+
+/// Implement conversions, arithmetic and formatting for a 16-bit float type
+/// in terms of its `from_f32`/`to_f32` conversions.
+macro_rules! impl_half {
+    ($half:ident) => {
+        impl $half {
+            /// Returns `true` if this value is `NaN`.
+            #[inline]
+            pub fn is_nan(self) -> bool {
+                self.0 & !Self::SIGN_MASK > Self::EXPONENT_MASK
+            }
+
+            /// Returns `true` if this value is positive infinity or negative
+            /// infinity, and `false` otherwise.
+            #[inline]
+            pub fn is_infinite(self) -> bool {
+                self.0 & !Self::SIGN_MASK == Self::EXPONENT_MASK
+            }
+
+            /// Returns `true` if this number is neither infinite nor `NaN`.
+            #[inline]
+            pub fn is_finite(self) -> bool {
+                self.0 & Self::EXPONENT_MASK != Self::EXPONENT_MASK
+            }
+
+            /// Returns `true` if `self` has a positive sign, including `+0.0`,
+            /// `NaN`s with positive sign bit and positive infinity.
+            #[inline]
+            pub fn is_sign_positive(self) -> bool {
+                self.0 & Self::SIGN_MASK == 0
+            }
+
+            /// Returns `true` if `self` has a negative sign, including `-0.0`,
+            /// `NaN`s with negative sign bit and negative infinity.
+            #[inline]
+            pub fn is_sign_negative(self) -> bool {
+                !self.is_sign_positive()
+            }
+
+            /// Raw transmutation to `u16`.
+            #[inline]
+            pub const fn to_bits(self) -> u16 {
+                self.0
+            }
+
+            /// Raw transmutation from `u16`.
+            #[inline]
+            pub const fn from_bits(v: u16) -> Self {
+                Self(v)
+            }
+        }
+
+        impl Add for $half {
+            type Output = $half;
+
+            #[inline]
+            fn add(self, rhs: $half) -> $half {
+                $half::from_f32(self.to_f32() + rhs.to_f32())
+            }
+        }
+
+        impl AddAssign for $half {
+            #[inline]
+            fn add_assign(&mut self, rhs: $half) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl Debug for $half {
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(fmt, "{}({:?})", stringify!($half), self.to_f32())
+            }
+        }
+
+        impl Display for $half {
+            #[inline]
+            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(fmt, "{}", self.to_f32())
+            }
+        }
+
+        impl Div for $half {
+            type Output = $half;
+
+            #[inline]
+            fn div(self, rhs: $half) -> $half {
+                $half::from_f32(self.to_f32() / rhs.to_f32())
+            }
+        }
+
+        impl DivAssign for $half {
+            #[inline]
+            fn div_assign(&mut self, rhs: $half) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl From<f32> for $half {
+            #[inline]
+            fn from(n: f32) -> $half {
+                $half::from_f32(n)
+            }
+        }
+
+        impl From<F32> for $half {
+            #[inline]
+            fn from(n: F32) -> $half {
+                $half::from_f32(n.0)
+            }
+        }
+
+        impl From<$half> for f32 {
+            #[inline]
+            fn from(n: $half) -> f32 {
+                n.to_f32()
+            }
+        }
+
+        impl From<$half> for F32 {
+            #[inline]
+            fn from(n: $half) -> F32 {
+                F32(n.to_f32())
+            }
+        }
+
+        impl From<i8> for $half {
+            #[inline]
+            fn from(n: i8) -> $half {
+                $half::from_f32(n.into())
+            }
+        }
+
+        impl From<u8> for $half {
+            #[inline]
+            fn from(n: u8) -> $half {
+                $half::from_f32(n.into())
+            }
+        }
+
+        impl LowerExp for $half {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{:e}", self.to_f32())
+            }
+        }
+
+        impl Mul for $half {
+            type Output = $half;
+
+            #[inline]
+            fn mul(self, rhs: $half) -> $half {
+                $half::from_f32(self.to_f32() * rhs.to_f32())
+            }
+        }
+
+        impl MulAssign for $half {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $half) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl Neg for $half {
+            type Output = $half;
+
+            #[inline]
+            fn neg(self) -> $half {
+                $half(self.0 ^ Self::SIGN_MASK)
+            }
+        }
+
+        impl PartialEq for $half {
+            fn eq(&self, other: &$half) -> bool {
+                self.to_f32().eq(&other.to_f32())
+            }
+        }
+
+        impl PartialOrd for $half {
+            fn partial_cmp(&self, other: &$half) -> Option<Ordering> {
+                self.to_f32().partial_cmp(&other.to_f32())
+            }
+        }
+
+        impl Product for $half {
+            #[inline]
+            fn product<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = $half>,
+            {
+                $half::from_f32(f32::product(iter.map(f32::from)))
+            }
+        }
+
+        impl Rem for $half {
+            type Output = $half;
+
+            #[inline]
+            fn rem(self, rhs: $half) -> $half {
+                $half::from_f32(self.to_f32() % rhs.to_f32())
+            }
+        }
+
+        impl RemAssign for $half {
+            #[inline]
+            fn rem_assign(&mut self, rhs: $half) {
+                *self = *self % rhs;
+            }
+        }
+
+        impl Sub for $half {
+            type Output = $half;
+
+            #[inline]
+            fn sub(self, rhs: $half) -> $half {
+                $half::from_f32(self.to_f32() - rhs.to_f32())
+            }
+        }
+
+        impl SubAssign for $half {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $half) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl Sum for $half {
+            #[inline]
+            fn sum<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = $half>,
+            {
+                $half::from_f32(f32::sum(iter.map(f32::from)))
+            }
+        }
+
+        impl UpperExp for $half {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{:E}", self.to_f32())
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+        impl defmt::Format for $half {
+            fn format(&self, fmt: defmt::Formatter<'_>) {
+                defmt::write!(fmt, "{}", self.to_f32())
+            }
+        }
+    };
+}
+
+mod bf16;
+mod f16;
+
+pub use self::{bf16::BF16, f16::F16};
@@ -0,0 +1,143 @@
+//! bfloat16 ("brain floating point") storage type.
+
+use crate::F32;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, LowerExp, UpperExp},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+
+/// 16-bit bfloat16 floating point storage type.
+///
+/// Has 1 sign bit, 8 exponent bits and 7 mantissa bits: the upper half of an
+/// `f32`, with the same range but only 2-3 significant decimal digits.
+#[derive(Copy, Clone, Default)]
+pub struct BF16(u16);
+
+impl BF16 {
+    /// The value `0.0`.
+    pub const ZERO: Self = Self(0x0000);
+
+    /// The value `1.0`.
+    pub const ONE: Self = Self(0x3f80);
+
+    /// [Machine epsilon] value for `BF16`.
+    ///
+    /// [Machine epsilon]: https://en.wikipedia.org/wiki/Machine_epsilon
+    pub const EPSILON: Self = Self(0x3c00);
+
+    /// Smallest finite `BF16` value.
+    pub const MIN: Self = Self(0xff7f);
+
+    /// Smallest positive normal `BF16` value.
+    pub const MIN_POSITIVE: Self = Self(0x0080);
+
+    /// Largest finite `BF16` value.
+    pub const MAX: Self = Self(0x7f7f);
+
+    /// Not a Number (NaN).
+    pub const NAN: Self = Self(0x7fc0);
+
+    /// Infinity (∞).
+    pub const INFINITY: Self = Self(0x7f80);
+
+    /// Negative infinity (−∞).
+    pub const NEG_INFINITY: Self = Self(0xff80);
+
+    /// Sign mask.
+    const SIGN_MASK: u16 = 0x8000;
+
+    /// Exponent mask.
+    const EXPONENT_MASK: u16 = 0x7f80;
+
+    /// Convert an `f32` to the nearest `BF16`, rounding ties to even.
+    pub fn from_f32(n: f32) -> Self {
+        let bits = n.to_bits();
+
+        // Truncating a NaN could produce infinity, so keep NaNs quiet
+        if n.is_nan() {
+            return Self((bits >> 16) as u16 | 0x0040);
+        }
+
+        // A carry out of the mantissa correctly increments the exponent,
+        // overflowing to infinity when rounding up `MAX`.
+        let rounding_bias = 0x7fff + ((bits >> 16) & 1);
+        Self((bits.wrapping_add(rounding_bias) >> 16) as u16)
+    }
+
+    /// Convert this value to an `f32`. This conversion is exact.
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        f32::from_bits(u32::from(self.0) << 16)
+    }
+}
+
+impl_half!(BF16);
+
+#[cfg(test)]
+mod tests {
+    use super::BF16;
+
+    /// Conversion test vectors - `(input, output bits)`
+    const TEST_VECTORS: &[(f32, u16)] = &[
+        (0.0, 0x0000),
+        (-0.0, 0x8000),
+        (1.0, 0x3f80),
+        (-2.0, 0xc000),
+        (0.333_333_34, 0x3eab),
+        (1.003_906_3, 0x3f80),
+        (1.011_718_8, 0x3f82),
+        (1.003_906_4, 0x3f81),
+        (3.389_531_4e38, 0x7f7f),
+        (f32::MAX, 0x7f80),
+        (1.0e-40, 0x0001),
+        (f32::INFINITY, 0x7f80),
+        (f32::NEG_INFINITY, 0xff80),
+    ];
+
+    #[test]
+    fn from_f32() {
+        for &(x, expected) in TEST_VECTORS {
+            let actual = BF16::from_f32(x).to_bits();
+            assert_eq!(
+                actual, expected,
+                "{:#06x} vs {:#06x} for {}",
+                actual, expected, x
+            );
+        }
+
+        assert!(BF16::from_f32(f32::NAN).is_nan());
+        assert!(BF16::from_f32(f32::from_bits(0x7f80_0001)).is_nan());
+    }
+
+    #[test]
+    fn round_trip() {
+        for bits in 0..=u16::MAX {
+            let x = BF16::from_bits(bits);
+            let y = BF16::from_f32(x.to_f32());
+
+            if x.is_nan() {
+                assert!(y.is_nan());
+                assert!(x.to_f32().is_nan());
+            } else {
+                assert_eq!(x.to_bits(), y.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = BF16::from_f32(1.5);
+        let b = BF16::from_f32(0.25);
+
+        assert_eq!((a + b).to_f32(), 1.75);
+        assert_eq!((a - b).to_f32(), 1.25);
+        assert_eq!((a * b).to_f32(), 0.375);
+        assert_eq!((a / b).to_f32(), 6.0);
+        assert_eq!((-a).to_f32(), -1.5);
+        assert_eq!(BF16::MAX + BF16::MAX, BF16::INFINITY);
+        assert_eq!(BF16::ZERO, -BF16::ZERO);
+        assert!(BF16::NAN != BF16::NAN);
+    }
+}
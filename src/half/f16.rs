@@ -0,0 +1,212 @@
+//! IEEE 754 half-precision (binary16) floating point storage type.
+
+use crate::F32;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, LowerExp, UpperExp},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+
+/// 16-bit IEEE 754 half-precision floating point storage type.
+///
+/// Has 1 sign bit, 5 exponent bits and 10 mantissa bits, for a range of
+/// `±65504` and roughly 3 significant decimal digits.
+#[derive(Copy, Clone, Default)]
+pub struct F16(u16);
+
+impl F16 {
+    /// The value `0.0`.
+    pub const ZERO: Self = Self(0x0000);
+
+    /// The value `1.0`.
+    pub const ONE: Self = Self(0x3c00);
+
+    /// [Machine epsilon] value for `F16`.
+    ///
+    /// [Machine epsilon]: https://en.wikipedia.org/wiki/Machine_epsilon
+    pub const EPSILON: Self = Self(0x1400);
+
+    /// Smallest finite `F16` value.
+    pub const MIN: Self = Self(0xfbff);
+
+    /// Smallest positive normal `F16` value.
+    pub const MIN_POSITIVE: Self = Self(0x0400);
+
+    /// Largest finite `F16` value.
+    pub const MAX: Self = Self(0x7bff);
+
+    /// Not a Number (NaN).
+    pub const NAN: Self = Self(0x7e00);
+
+    /// Infinity (∞).
+    pub const INFINITY: Self = Self(0x7c00);
+
+    /// Negative infinity (−∞).
+    pub const NEG_INFINITY: Self = Self(0xfc00);
+
+    /// Sign mask.
+    const SIGN_MASK: u16 = 0x8000;
+
+    /// Exponent mask.
+    const EXPONENT_MASK: u16 = 0x7c00;
+
+    /// Convert an `f32` to the nearest `F16`, rounding ties to even.
+    ///
+    /// Values too large for `F16` become infinity, and values too small
+    /// become (signed) zero or a subnormal.
+    pub fn from_f32(n: f32) -> Self {
+        let bits = n.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let exponent = ((bits >> 23) & 0xff) as i32;
+        let mantissa = bits & 0x007f_ffff;
+
+        // NaN or infinity, keeping NaNs quiet
+        if exponent == 0xff {
+            return if mantissa == 0 {
+                Self(sign | 0x7c00)
+            } else {
+                Self(sign | 0x7e00 | (mantissa >> 13) as u16)
+            };
+        }
+
+        let half_exponent = exponent - 127 + 15;
+
+        if half_exponent >= 0x1f {
+            return Self(sign | 0x7c00);
+        }
+
+        if half_exponent <= 0 {
+            // Below half of the smallest subnormal: rounds to zero
+            if half_exponent < -10 {
+                return Self(sign);
+            }
+
+            // Subnormal: shift the mantissa including its implicit leading bit
+            let mantissa = mantissa | 0x0080_0000;
+            let shift = (14 - half_exponent) as u32;
+            return Self(sign | round_shr(mantissa, shift) as u16);
+        }
+
+        // A carry out of the mantissa correctly increments the exponent,
+        // overflowing to infinity when rounding up `MAX`.
+        let magnitude = ((half_exponent as u32) << 23 | mantissa) >> 13;
+        let round_bit = 1 << 12;
+
+        if mantissa & round_bit != 0 && mantissa & (3 * round_bit - 1) != 0 {
+            Self(sign | (magnitude + 1) as u16)
+        } else {
+            Self(sign | magnitude as u16)
+        }
+    }
+
+    /// Convert this value to an `f32`. This conversion is exact.
+    pub fn to_f32(self) -> f32 {
+        let sign = u32::from(self.0 & Self::SIGN_MASK) << 16;
+        let exponent = u32::from((self.0 & Self::EXPONENT_MASK) >> 10);
+        let mantissa = u32::from(self.0 & 0x03ff);
+
+        let bits = match exponent {
+            0 if mantissa == 0 => sign,
+            0 => {
+                // Subnormal: normalize so the leading bit becomes implicit
+                let shift = mantissa.leading_zeros() - 21;
+                let mantissa = (mantissa << shift) & 0x03ff;
+                sign | ((113 - shift) << 23) | (mantissa << 13)
+            }
+            0x1f => sign | 0x7f80_0000 | (mantissa << 13),
+            _ => sign | ((exponent + 112) << 23) | (mantissa << 13),
+        };
+
+        f32::from_bits(bits)
+    }
+}
+
+/// Shift `n` right by `shift` bits, rounding to nearest with ties to even.
+fn round_shr(n: u32, shift: u32) -> u32 {
+    let round_bit = 1 << (shift - 1);
+    let shifted = n >> shift;
+
+    if n & round_bit != 0 && n & (3 * round_bit - 1) != 0 {
+        shifted + 1
+    } else {
+        shifted
+    }
+}
+
+impl_half!(F16);
+
+#[cfg(test)]
+mod tests {
+    use super::F16;
+
+    /// Conversion test vectors - `(input, output bits)`
+    const TEST_VECTORS: &[(f32, u16)] = &[
+        (0.0, 0x0000),
+        (-0.0, 0x8000),
+        (1.0, 0x3c00),
+        (-2.0, 0xc000),
+        (0.5, 0x3800),
+        (0.333_333_34, 0x3555),
+        (65504.0, 0x7bff),
+        (65519.0, 0x7bff),
+        (65520.0, 0x7c00),
+        (1.0e6, 0x7c00),
+        (-1.0e6, 0xfc00),
+        (6.103_515_6e-5, 0x0400),
+        (6.097_555e-5, 0x03ff),
+        (5.960_464_5e-8, 0x0001),
+        (2.980_232_2e-8, 0x0000),
+        (2.980_233e-8, 0x0001),
+        (8.940_697e-8, 0x0002),
+        (1.000_488_3, 0x3c00),
+        (1.001_464_8, 0x3c02),
+        (1.000_488_4, 0x3c01),
+        (f32::INFINITY, 0x7c00),
+        (f32::NEG_INFINITY, 0xfc00),
+    ];
+
+    #[test]
+    fn from_f32() {
+        for &(x, expected) in TEST_VECTORS {
+            let actual = F16::from_f32(x).to_bits();
+            assert_eq!(
+                actual, expected,
+                "{:#06x} vs {:#06x} for {}",
+                actual, expected, x
+            );
+        }
+
+        assert!(F16::from_f32(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn round_trip() {
+        for bits in 0..=u16::MAX {
+            let x = F16::from_bits(bits);
+            let y = F16::from_f32(x.to_f32());
+
+            if x.is_nan() {
+                assert!(y.is_nan());
+                assert!(x.to_f32().is_nan());
+            } else {
+                assert_eq!(x.to_bits(), y.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = F16::from_f32(1.5);
+        let b = F16::from_f32(0.25);
+
+        assert_eq!((a + b).to_f32(), 1.75);
+        assert_eq!((a - b).to_f32(), 1.25);
+        assert_eq!((a * b).to_f32(), 0.375);
+        assert_eq!((a / b).to_f32(), 6.0);
+        assert_eq!((-a).to_f32(), -1.5);
+        assert_eq!(F16::MAX + F16::MAX, F16::INFINITY);
+        assert_eq!(F16::ZERO, -F16::ZERO);
+        assert!(F16::NAN != F16::NAN);
+    }
+}
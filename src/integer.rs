@@ -0,0 +1,207 @@
+//! Integer math which doesn't use floating point at all, for targets without
+//! an FPU where `f32` operations are emulated in software.
+//!
+//! ```
+//! use micromath::integer;
+//!
+//! assert_eq!(integer::isqrt(99), 9);
+//! assert_eq!(integer::ilog10(12_345), Some(4));
+//! assert_eq!(integer::magnitude(3000, -4000), 5000);
+//!
+//! // 45 degrees is an eighth of a turn
+//! let angle = integer::atan2(1000, 1000);
+//! assert!(angle.abs_diff(1 << 29) <= 8);
+//! ```
+
+use crate::cordic;
+
+/// Integer square root, rounded down.
+///
+/// Method described at: <https://en.wikipedia.org/wiki/Methods_of_computing_square_roots#Binary_numeral_system_(base_2)>
+pub const fn isqrt(n: u64) -> u32 {
+    let mut remainder = n;
+    let mut result = 0;
+    let mut bit = 1 << 62;
+
+    while bit > remainder {
+        bit >>= 2;
+    }
+
+    while bit != 0 {
+        if remainder >= result + bit {
+            remainder -= result + bit;
+            result = (result >> 1) + bit;
+        } else {
+            result >>= 1;
+        }
+
+        bit >>= 2;
+    }
+
+    result as u32
+}
+
+/// Integer square root of a 128-bit integer, rounded down.
+///
+/// Same method as [`isqrt`], for sums of squares which overflow a `u64`.
+pub const fn isqrt_u128(n: u128) -> u64 {
+    let mut remainder = n;
+    let mut result = 0;
+    let mut bit = 1 << 126;
+
+    while bit > remainder {
+        bit >>= 2;
+    }
+
+    while bit != 0 {
+        if remainder >= result + bit {
+            remainder -= result + bit;
+            result = (result >> 1) + bit;
+        } else {
+            result >>= 1;
+        }
+
+        bit >>= 2;
+    }
+
+    result as u64
+}
+
+/// Base 2 logarithm, rounded down.
+///
+/// Returns `None` if `n` is zero.
+pub const fn ilog2(n: u64) -> Option<u32> {
+    n.checked_ilog2()
+}
+
+/// Base 10 logarithm, rounded down.
+///
+/// Returns `None` if `n` is zero.
+pub const fn ilog10(n: u64) -> Option<u32> {
+    n.checked_ilog10()
+}
+
+/// Computes the angle of the vector `(x, y)` as a binary angle, i.e. the
+/// full range of `u32` covers a full turn counter-clockwise from the positive
+/// `x` axis, so `1 << 30` is 90 degrees.
+///
+/// Uses the [CORDIC](crate::cordic) algorithm, with a maximum error of a few
+/// units. Returns `0` for `(0, 0)`.
+pub fn atan2(y: i32, x: i32) -> u32 {
+    cordic::atan2(y, x) as u32
+}
+
+/// Computes the squared magnitude `x² + y²`, which can't overflow.
+pub const fn magnitude_sq(x: i32, y: i32) -> u64 {
+    let x = x.unsigned_abs() as u64;
+    let y = y.unsigned_abs() as u64;
+    x * x + y * y
+}
+
+/// Computes the magnitude `sqrt(x² + y²)`, rounded down.
+pub const fn magnitude(x: i32, y: i32) -> u32 {
+    isqrt(magnitude_sq(x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isqrt_exact() {
+        for n in 0..10_000u64 {
+            let root = u64::from(isqrt(n));
+            assert!(
+                root * root <= n && (root + 1) * (root + 1) > n,
+                "isqrt({})",
+                n
+            );
+        }
+
+        assert_eq!(isqrt(u64::MAX), u32::MAX);
+        assert_eq!(isqrt(u64::from(u32::MAX) * u64::from(u32::MAX)), u32::MAX);
+        assert_eq!(
+            isqrt(u64::from(u32::MAX) * u64::from(u32::MAX) - 1),
+            u32::MAX - 1
+        );
+    }
+
+    #[test]
+    fn isqrt_u128_exact() {
+        for n in 0..10_000u64 {
+            assert_eq!(isqrt_u128(n.into()), isqrt(n).into(), "isqrt_u128({})", n);
+        }
+
+        let max = u128::from(u64::MAX);
+        assert_eq!(isqrt_u128(u128::MAX), u64::MAX);
+        assert_eq!(isqrt_u128(max * max), u64::MAX);
+        assert_eq!(isqrt_u128(max * max - 1), u64::MAX - 1);
+        assert_eq!(
+            isqrt_u128(3 * u128::from(u32::MAX) * u128::from(u32::MAX)),
+            7_439_101_571
+        );
+    }
+
+    #[test]
+    fn ilog() {
+        assert_eq!(ilog2(0), None);
+        assert_eq!(ilog10(0), None);
+        assert_eq!(ilog2(1), Some(0));
+        assert_eq!(ilog2(u64::MAX), Some(63));
+        assert_eq!(ilog10(u64::MAX), Some(19));
+
+        let mut power = 1u64;
+
+        for exponent in 1..20 {
+            power *= 10;
+            assert_eq!(
+                ilog10(power - 1),
+                Some(exponent - 1),
+                "ilog10({})",
+                power - 1
+            );
+            assert_eq!(ilog10(power), Some(exponent), "ilog10({})", power);
+            assert_eq!(
+                ilog2(1 << exponent),
+                Some(exponent),
+                "ilog2({})",
+                1u64 << exponent
+            );
+        }
+    }
+
+    #[test]
+    fn atan2_quadrants() {
+        /// Maximum error in binary angle units
+        const MAX_ERROR: i64 = 8;
+
+        let cases = [
+            ((0, 1000), 0),
+            ((1000, 1000), 1 << 29),
+            ((1000, 0), 1 << 30),
+            ((1000, -1000), 3 << 29),
+            ((0, -1000), 1 << 31),
+            ((-1000, -1000), 5 << 29),
+            ((-1000, 0), 3 << 30),
+            ((-1000, 1000), 7 << 29),
+            ((i32::MAX, i32::MIN), 3 << 29),
+        ];
+
+        for &((y, x), expected) in &cases {
+            let actual = atan2(y, x);
+            let delta = (i64::from(actual.wrapping_sub(expected) as i32)).abs();
+            assert!(delta <= MAX_ERROR, "atan2({}, {}) = {}", y, x, actual);
+        }
+
+        assert_eq!(atan2(0, 0), 0);
+    }
+
+    #[test]
+    fn magnitude_exact() {
+        assert_eq!(magnitude_sq(3, -4), 25);
+        assert_eq!(magnitude(3, -4), 5);
+        assert_eq!(magnitude(1, 1), 1);
+        assert_eq!(magnitude_sq(i32::MIN, i32::MIN), 1 << 63);
+        assert_eq!(magnitude(i32::MIN, i32::MIN), 3_037_000_499);
+    }
+}
@@ -36,6 +36,55 @@
 //! use micromath::F32Ext;
 //! ```
 //!
+//! ### Precision tiers
+//!
+//! Some approximations can be selected per call site from a set of
+//! [`precision`] tiers ([`Fast`][`precision::Fast`],
+//! [`Balanced`][`precision::Balanced`] and [`Precise`][`precision::Precise`])
+//! using the `*_with` methods. These are available for `sqrt`, `invsqrt`,
+//! `cbrt`, `sin`, `cos` and `exp`, e.g. [`F32::sqrt_with`]; all other methods
+//! use a single fixed approximation.
+//!
+//! ### `F64` and `F64Ext`
+//!
+//! The same approximations are also available for `f64` via the [`F64`]
+//! newtype wrapper and the [`F64Ext`] extension trait. They use
+//! double-precision kernels, so results are generally within a couple of ULPs
+//! of the correctly rounded value rather than `f32` levels of accuracy.
+//!
+//! ```
+//! use micromath::F64Ext;
+//!
+//! let n = 2.0f64.sqrt();
+//! assert!((n - 1.414_213_562_373_095).abs() < 1e-15);
+//! ```
+//!
+//! ### `F16` and `BF16` storage types
+//!
+//! The [`F16`] (IEEE 754 half precision) and [`BF16`] (bfloat16) types store
+//! values in 16 bits, with exact conversions to and from [`F32`] and
+//! arithmetic which is performed in `f32` precision.
+//!
+//! ```
+//! use micromath::{F16, F32};
+//!
+//! let x = F16::from(F32(0.1));
+//! assert_eq!(F32::from(x).0, 0.099975586);
+//! ```
+//!
+//! ## Fixed-point types
+//!
+//! For targets without an FPU, the [`Q15`], [`Q31`] and [`I16F16`]
+//! fixed-point types provide saturating arithmetic and integer-only
+//! implementations of `sqrt`, `sin`, `cos`, `atan2`, `exp` and `ln`.
+//!
+//! ```
+//! use micromath::I16F16;
+//!
+//! let x = I16F16::from(2i16).sqrt();
+//! assert!((x.to_f32() - 1.414_213_5).abs() < 1e-4);
+//! ```
+//!
 //! ## Vector types
 //!
 //! See the [`vector`] module for more information on vector types.
@@ -81,20 +130,48 @@
     unused_qualifications
 )]
 
+pub mod accuracy;
+pub mod activation;
+pub mod cordic;
+
+#[cfg(feature = "ct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ct")))]
+pub mod ct;
+pub mod integer;
+pub mod poly;
+pub mod precision;
+
 #[cfg(feature = "statistics")]
 #[cfg_attr(docsrs, doc(cfg(feature = "statistics")))]
 pub mod statistics;
 
+#[cfg(feature = "trig-table")]
+#[cfg_attr(docsrs, doc(cfg(feature = "trig-table")))]
+pub mod trig_table;
+
 #[cfg(feature = "vector")]
 #[cfg_attr(docsrs, doc(cfg(feature = "vector")))]
 pub mod vector;
 
+mod approx;
 mod f32ext;
+mod f64ext;
+mod fixed;
 mod float;
+mod float64;
+mod half;
 #[cfg(feature = "quaternion")]
 mod quaternion;
 
-pub use crate::{f32ext::F32Ext, float::F32};
+pub use crate::{
+    approx::ApproxEq,
+    f32ext::F32Ext,
+    f64ext::F64Ext,
+    fixed::{I16F16, Q15, Q31},
+    float::{TotalOrd, F32},
+    float64::F64,
+    half::{BF16, F16},
+};
 
 #[cfg(feature = "quaternion")]
 pub use crate::quaternion::Quaternion;
@@ -0,0 +1,198 @@
+//! Polynomial and Chebyshev series evaluation.
+//!
+//! These are the building blocks used by many of the [`F32`] approximations,
+//! and can be used to evaluate custom approximations such as sensor
+//! linearization curves. Coefficients are given in order of increasing
+//! degree, i.e. `[c0, c1, c2]` is `c0 + c1 * x + c2 * x^2`.
+//!
+//! All functions are `const fn`, so they can also be used to compute
+//! constants at compile time.
+//!
+//! ```
+//! use micromath::{poly, F32};
+//!
+//! /// Thermistor curve fitted over `[0, 1]`
+//! const CURVE: [f32; 4] = [-40.0, 180.5, -42.25, 8.0];
+//!
+//! let celsius = poly::horner(F32(0.5), &CURVE);
+//! assert_eq!(celsius, poly::estrin(F32(0.5), &CURVE));
+//! assert_eq!(celsius.0, 40.6875);
+//! ```
+
+use crate::{F32, F64};
+
+/// Evaluates the polynomial with the given `coefficients` at `x` using
+/// Horner's method, which performs one multiply-add per coefficient.
+pub const fn horner(x: F32, coefficients: &[f32]) -> F32 {
+    let mut result = 0.0;
+    let mut i = coefficients.len();
+
+    while i > 0 {
+        i -= 1;
+        result = result * x.0 + coefficients[i];
+    }
+
+    F32(result)
+}
+
+/// Evaluates the polynomial with the given `coefficients` at `x` using
+/// Horner's method, like [`horner`] but in double precision.
+pub const fn horner_f64(x: F64, coefficients: &[f64]) -> F64 {
+    let mut result = 0.0;
+    let mut i = coefficients.len();
+
+    while i > 0 {
+        i -= 1;
+        result = result * x.0 + coefficients[i];
+    }
+
+    F64(result)
+}
+
+/// Evaluates the polynomial with the given `coefficients` at `x` using
+/// Estrin's scheme.
+///
+/// This performs the same number of multiply-adds as [`horner`] plus a
+/// squaring per level, but splits the polynomial into independent halves,
+/// which is faster on CPUs able to overlap floating point operations. The
+/// result may differ from [`horner`] by a few ULPs.
+pub const fn estrin<const N: usize>(x: F32, coefficients: &[f32; N]) -> F32 {
+    let mut terms = *coefficients;
+    let mut len = N;
+    let mut power = x.0;
+
+    // Combine pairs of terms `a + b * x^(2^level)` until only one is left
+    while len > 1 {
+        let mut i = 0;
+
+        while i < len / 2 {
+            terms[i] = terms[2 * i] + terms[2 * i + 1] * power;
+            i += 1;
+        }
+
+        if len % 2 == 1 {
+            terms[len / 2] = terms[len - 1];
+        }
+
+        len = len.div_ceil(2);
+        power *= power;
+    }
+
+    if N == 0 {
+        F32::ZERO
+    } else {
+        F32(terms[0])
+    }
+}
+
+/// Evaluates the Chebyshev series `c0 * T0(x) + c1 * T1(x) + ...` with the
+/// given `coefficients` at `x` using Clenshaw's algorithm, where `Tn` is the
+/// Chebyshev polynomial of the first kind of degree `n`.
+///
+/// Chebyshev series are defined on `[-1, 1]`: to approximate a function on
+/// `[a, b]`, evaluate the series at `(2x - a - b) / (b - a)`.
+pub const fn chebyshev(x: F32, coefficients: &[f32]) -> F32 {
+    if coefficients.is_empty() {
+        return F32::ZERO;
+    }
+
+    let two_x = 2.0 * x.0;
+    let mut b1 = 0.0;
+    let mut b2 = 0.0;
+    let mut i = coefficients.len();
+
+    while i > 1 {
+        i -= 1;
+        let b0 = coefficients[i] + two_x * b1 - b2;
+        b2 = b1;
+        b1 = b0;
+    }
+
+    F32(coefficients[0] + x.0 * b1 - b2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chebyshev, estrin, horner, horner_f64};
+    use crate::{F32, F64};
+
+    /// Coefficients of `1 - 2x + 3x^2 - 4x^3 + 5x^4 - 6x^5 + 7x^6`
+    const COEFFICIENTS: [f32; 7] = [1.0, -2.0, 3.0, -4.0, 5.0, -6.0, 7.0];
+
+    /// Reference implementation summing each term
+    fn naive(x: f32, coefficients: &[f32]) -> f32 {
+        coefficients
+            .iter()
+            .enumerate()
+            .map(|(i, c)| c * x.powi(i as i32))
+            .sum()
+    }
+
+    #[test]
+    fn horner_estrin() {
+        for i in -20..=20 {
+            let x = i as f32 * 0.1;
+            let expected = naive(x, &COEFFICIENTS);
+
+            for actual in [horner(F32(x), &COEFFICIENTS), estrin(F32(x), &COEFFICIENTS)] {
+                let delta = (actual.0 - expected).abs();
+                assert!(
+                    delta <= 1e-5 * expected.abs().max(1.0),
+                    "delta {} too large: {} vs {}",
+                    delta,
+                    actual.0,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn short_polynomials() {
+        assert_eq!(horner(F32(2.0), &[]), F32(0.0));
+        assert_eq!(estrin(F32(2.0), &[]), F32(0.0));
+        assert_eq!(horner(F32(2.0), &[3.0]), F32(3.0));
+        assert_eq!(estrin(F32(2.0), &[3.0]), F32(3.0));
+        assert_eq!(estrin(F32(2.0), &[3.0, 1.0]), F32(5.0));
+        assert_eq!(estrin(F32(2.0), &[3.0, 1.0, 1.0]), F32(9.0));
+    }
+
+    #[test]
+    fn horner_double_precision() {
+        assert_eq!(horner_f64(F64(2.0), &[]), F64(0.0));
+        assert_eq!(horner_f64(F64(2.0), &[3.0, 1.0, 1.0]), F64(9.0));
+
+        // `1 + x + x^2` at `1 + 2^-30` needs more than `f32` precision
+        let x = 1.0 + 1.0 / (1u64 << 30) as f64;
+        assert_eq!(horner_f64(F64(x), &[1.0, 1.0, 1.0]).0, 1.0 + x + x * x);
+    }
+
+    #[test]
+    fn chebyshev_series() {
+        const COEFFICIENTS: [f32; 5] = [1.0, 2.0, -1.0, 0.5, 0.25];
+
+        for i in -10..=10 {
+            let x = i as f32 * 0.1;
+
+            // `Tn(cos(θ)) = cos(nθ)`
+            let expected: f32 = COEFFICIENTS
+                .iter()
+                .enumerate()
+                .map(|(n, c)| c * (n as f32 * x.acos()).cos())
+                .sum();
+
+            let actual = chebyshev(F32(x), &COEFFICIENTS).0;
+            let delta = (actual - expected).abs();
+            assert!(delta <= 1e-6, "delta {} too large for {}", delta, x);
+        }
+
+        assert_eq!(chebyshev(F32(0.5), &[]), F32(0.0));
+        assert_eq!(chebyshev(F32(0.5), &[3.0]), F32(3.0));
+    }
+
+    #[test]
+    fn const_eval() {
+        const VALUE: F32 = horner(F32(2.0), &COEFFICIENTS);
+        assert_eq!(VALUE.0, naive(2.0, &COEFFICIENTS));
+    }
+}
@@ -0,0 +1,142 @@
+//! Selectable precision tiers for [`F32`] approximations.
+//!
+//! Most [`F32`] methods are hard-wired to a single speed/accuracy tradeoff.
+//! The `*_with` family of methods instead accept a marker type which selects
+//! the tier used at a particular call site. Tiers are only available for the
+//! following methods:
+//!
+//! - [`F32::sqrt_with`] and [`F32::invsqrt_with`]
+//! - [`F32::cbrt_with`]
+//! - [`F32::sin_with`] and [`F32::cos_with`]
+//! - [`F32::exp_with`]
+//!
+//! The tiers are:
+//!
+//! - [`Fast`]: the cheapest approximation available, e.g. the bit-hack
+//!   square root with no refinement.
+//! - [`Balanced`]: the same approximations used by the default methods, e.g.
+//!   [`F32::cos`] and [`F32::exp`], plus one Newton-Raphson step for `sqrt`.
+//! - [`Precise`]: additional refinement steps and longer series, for code
+//!   where accuracy matters more than a handful of extra cycles.
+//!
+//! ```
+//! use micromath::{precision::{Fast, Precise}, F32};
+//!
+//! let rough = F32(2.0).sqrt_with::<Fast>();
+//! let close = F32(2.0).sqrt_with::<Precise>();
+//! assert!((rough.0 - 1.414_213_5).abs() < 0.1);
+//! assert!((close.0 - 1.414_213_5).abs() < 1e-5);
+//! ```
+//!
+//! Each tier documents the maximum error of every function it covers via the
+//! associated constants of the [`Precision`] trait.
+
+#[allow(unused_imports)] // used by rustdoc links
+use crate::F32;
+use core::fmt::Debug;
+
+/// Precision tier used to select the approximation for a `*_with` method.
+///
+/// This trait is sealed and impl'd for [`Fast`], [`Balanced`] and [`Precise`].
+pub trait Precision: Copy + Debug + Default + Send + Sync + sealed::Sealed {
+    /// Number of Newton-Raphson iterations used to refine the bit-hack
+    /// initial guess in [`F32::sqrt_with`], [`F32::invsqrt_with`] and
+    /// [`F32::cbrt_with`].
+    const NEWTON_ITERATIONS: u32;
+
+    /// Number of terms of the series used by [`F32::exp_with`].
+    const EXP_SERIES_TERMS: u32;
+
+    /// Maximum relative error of [`F32::sqrt_with`] for positive normal inputs.
+    const SQRT_MAX_ERROR: f32;
+
+    /// Maximum relative error of [`F32::invsqrt_with`] for positive normal
+    /// inputs.
+    const INVSQRT_MAX_ERROR: f32;
+
+    /// Maximum relative error of [`F32::cbrt_with`] for normal inputs.
+    const CBRT_MAX_ERROR: f32;
+
+    /// Maximum absolute error of [`F32::cos_with`] and [`F32::sin_with`] for
+    /// inputs in the `[-2π, 2π]` range.
+    const COS_MAX_ERROR: f32;
+
+    /// Maximum relative error of [`F32::exp_with`] for inputs in the
+    /// `[-80, 80]` range.
+    const EXP_MAX_ERROR: f32;
+}
+
+/// Cheapest approximations: bit hacks and low-order polynomials only.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Fast;
+
+/// Approximations used by the default [`F32`] methods, with a single
+/// refinement step for `sqrt` and `invsqrt`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Balanced;
+
+/// Most accurate approximations, at the cost of additional refinement steps.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Precise;
+
+impl Precision for Fast {
+    const NEWTON_ITERATIONS: u32 = 0;
+    const EXP_SERIES_TERMS: u32 = 3;
+    const SQRT_MAX_ERROR: f32 = 0.061;
+    const INVSQRT_MAX_ERROR: f32 = 0.035;
+    const CBRT_MAX_ERROR: f32 = 0.033;
+    const COS_MAX_ERROR: f32 = 0.057;
+    const EXP_MAX_ERROR: f32 = 0.017;
+}
+
+impl Precision for Balanced {
+    const NEWTON_ITERATIONS: u32 = 1;
+    const EXP_SERIES_TERMS: u32 = 4;
+    const SQRT_MAX_ERROR: f32 = 0.002;
+    const INVSQRT_MAX_ERROR: f32 = 0.002;
+    const CBRT_MAX_ERROR: f32 = 0.0011;
+    const COS_MAX_ERROR: f32 = 0.002;
+    const EXP_MAX_ERROR: f32 = 0.0025;
+}
+
+impl Precision for Precise {
+    const NEWTON_ITERATIONS: u32 = 2;
+    const EXP_SERIES_TERMS: u32 = 7;
+    const SQRT_MAX_ERROR: f32 = 2e-6;
+    const INVSQRT_MAX_ERROR: f32 = 6e-6;
+    const CBRT_MAX_ERROR: f32 = 2e-6;
+    const COS_MAX_ERROR: f32 = 1e-6;
+    const EXP_MAX_ERROR: f32 = 6e-6;
+}
+
+mod sealed {
+    use super::{Balanced, Fast, Precise};
+    use crate::F32;
+
+    /// Sealed trait which also carries the per-tier trigonometric kernel.
+    pub trait Sealed {
+        /// Approximate `cos(x)` in radians, where `x` is in `[-π, π]`.
+        fn cos(x: F32) -> F32;
+    }
+
+    impl Sealed for Fast {
+        #[inline]
+        fn cos(x: F32) -> F32 {
+            x.cos_parabolic()
+        }
+    }
+
+    impl Sealed for Balanced {
+        #[inline]
+        fn cos(x: F32) -> F32 {
+            x.cos_refined()
+        }
+    }
+
+    impl Sealed for Precise {
+        #[inline]
+        fn cos(x: F32) -> F32 {
+            x.cos_polynomial()
+        }
+    }
+}
@@ -19,7 +19,7 @@
 //! See the License for the specific language governing permissions and
 //! limitations under the License.
 
-use crate::F32;
+use crate::{ApproxEq, F32};
 use core::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
 
 #[cfg(feature = "vector")]
@@ -362,6 +362,20 @@ where
     }
 }
 
+impl ApproxEq for Quaternion {
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.to_array().abs_diff_eq(&other.to_array(), epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f32) -> bool {
+        self.to_array().relative_eq(&other.to_array(), max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u32) -> bool {
+        self.to_array().ulps_eq(&other.to_array(), max_ulps)
+    }
+}
+
 #[cfg(feature = "defmt")]
 #[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
 impl defmt::Format for Quaternion {
@@ -92,7 +92,7 @@ where
     type Item = N;
 
     fn next(&mut self) -> Option<N> {
-        while let Some(n) = self.input.next() {
+        for n in self.input.by_ref() {
             let distance = (f32::from(n) - self.mean).abs();
 
             // TODO(tarcieri): better method for finding outliers? (e.g. MAD, IQD)
@@ -0,0 +1,262 @@
+//! Table-driven sine and cosine using linear interpolation.
+//!
+//! With the `trig-table` feature enabled, [`F32::sin`], [`F32::cos`] and
+//! [`F32::sin_cos`] use a [`TrigTable`] of [`DEFAULT_SIZE`] entries instead
+//! of the parabolic approximation. This trades `4 * N` bytes of flash for
+//! fewer multiplies and a smaller error: linear interpolation between `N`
+//! samples per turn has a maximum error of roughly `(2π/N)² / 8`, see
+//! [`TrigTable::MAX_ERROR`].
+//!
+//! The default size is `256`, which can be changed with one of the
+//! `trig-table-64`, `trig-table-1024` or `trig-table-4096` features (the
+//! largest one wins if several are enabled).
+//!
+//! Tables of other sizes can be built at compile time with
+//! [`TrigTable::new`] and passed to [`F32::sin_with_table`],
+//! [`F32::cos_with_table`] and [`F32::sin_cos_with_table`]:
+//!
+//! ```
+//! use micromath::{trig_table::TrigTable, F32};
+//!
+//! static TABLE: TrigTable<1024> = TrigTable::new();
+//!
+//! let sin = F32(1.0).sin_with_table(&TABLE);
+//! assert!((sin.0 - 0.841_470_96).abs() < TrigTable::<1024>::MAX_ERROR);
+//! ```
+
+use crate::F32;
+use core::f32::consts::{FRAC_1_PI, TAU};
+
+/// Number of entries in the table used by [`F32::sin`], [`F32::cos`] and
+/// [`F32::sin_cos`], selected by the `trig-table-*` features.
+#[cfg(feature = "trig-table-4096")]
+pub const DEFAULT_SIZE: usize = 4096;
+
+/// Number of entries in the table used by [`F32::sin`], [`F32::cos`] and
+/// [`F32::sin_cos`], selected by the `trig-table-*` features.
+#[cfg(all(feature = "trig-table-1024", not(feature = "trig-table-4096")))]
+pub const DEFAULT_SIZE: usize = 1024;
+
+/// Number of entries in the table used by [`F32::sin`], [`F32::cos`] and
+/// [`F32::sin_cos`], selected by the `trig-table-*` features.
+#[cfg(not(any(
+    feature = "trig-table-64",
+    feature = "trig-table-1024",
+    feature = "trig-table-4096"
+)))]
+pub const DEFAULT_SIZE: usize = 256;
+
+/// Number of entries in the table used by [`F32::sin`], [`F32::cos`] and
+/// [`F32::sin_cos`], selected by the `trig-table-*` features.
+#[cfg(all(
+    feature = "trig-table-64",
+    not(any(feature = "trig-table-1024", feature = "trig-table-4096"))
+))]
+pub const DEFAULT_SIZE: usize = 64;
+
+/// Table used by [`F32::sin`], [`F32::cos`] and [`F32::sin_cos`].
+pub(crate) static DEFAULT_TABLE: TrigTable<DEFAULT_SIZE> = TrigTable::new();
+
+/// Number of fractional bits of the table entries.
+const FRAC_BITS: u32 = 30;
+
+/// `π/2` with 62 fractional bits.
+const FRAC_PI_2_Q62: i128 = 7_244_019_458_077_122_842;
+
+/// Table of `N` samples of `sin(x)` over one turn, evaluated with linear
+/// interpolation.
+///
+/// `N` must be a power of two and at least `4`.
+#[derive(Clone, Debug)]
+pub struct TrigTable<const N: usize> {
+    /// `sin(2πi/N)` with [`FRAC_BITS`] fractional bits.
+    table: [i32; N],
+}
+
+impl<const N: usize> TrigTable<N> {
+    /// Maximum error of [`Self::sin`], [`Self::cos`] and [`Self::sin_cos`]:
+    /// the interpolation error `(2π/N)² / 8` plus `5e-7` for the rounding
+    /// of the `f32` arithmetic, which dominates for tables larger than `4096`.
+    pub const MAX_ERROR: f32 = {
+        let step = TAU / N as f32;
+        step * step / 8.0 + 5e-7
+    };
+
+    /// Compute the table. Intended to be evaluated at compile time, e.g. when
+    /// initializing a `static`.
+    ///
+    /// # Panics
+    ///
+    /// If `N` is not a power of two or is less than `4`.
+    pub const fn new() -> Self {
+        assert!(
+            N.is_power_of_two() && N >= 4,
+            "table size must be a power of two"
+        );
+
+        let mut table = [0; N];
+        let mut i = 0;
+
+        while i < N {
+            table[i] = sin_q30(i, N);
+            i += 1;
+        }
+
+        Self { table }
+    }
+
+    /// Approximates `sin(x)` in radians.
+    pub fn sin(&self, x: F32) -> F32 {
+        self.sin_turns(x.reduce_angle() * (FRAC_1_PI / 2.0))
+    }
+
+    /// Approximates `cos(x)` in radians.
+    pub fn cos(&self, x: F32) -> F32 {
+        self.sin_turns(x.reduce_angle() * (FRAC_1_PI / 2.0) + 0.25)
+    }
+
+    /// Simultaneously approximates `sin(x)` and `cos(x)` in radians.
+    /// Returns `(sin(x), cos(x))`.
+    pub fn sin_cos(&self, x: F32) -> (F32, F32) {
+        let turns = x.reduce_angle() * (FRAC_1_PI / 2.0);
+        (self.sin_turns(turns), self.sin_turns(turns + 0.25))
+    }
+
+    /// Interpolate `sin(x)` where `x` is in turns, in the range `[-1, 1]`.
+    fn sin_turns(&self, turns: F32) -> F32 {
+        let position = turns * N as f32;
+        let index = position.floor();
+        let frac = (position - index).0;
+
+        // Negative indices wrap around to the end of the table
+        let index = index.0 as i32 as usize;
+        let a = self.entry(index);
+        let b = self.entry(index.wrapping_add(1));
+
+        F32(a + (b - a) * frac)
+    }
+
+    /// Get the entry at the given index modulo `N` as an `f32`.
+    #[inline]
+    fn entry(&self, index: usize) -> f32 {
+        self.table[index & (N - 1)] as f32 * (1.0 / (1 << FRAC_BITS) as f32)
+    }
+}
+
+impl<const N: usize> Default for TrigTable<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compute `sin(2πi/n)` with [`FRAC_BITS`] fractional bits using only
+/// integer arithmetic, so it can be evaluated at compile time.
+const fn sin_q30(i: usize, n: usize) -> i32 {
+    // Fold onto the first quadrant
+    let quarter = n / 4;
+    let (k, negate) = match (i / quarter) % 4 {
+        0 => (i % quarter, false),
+        1 => (quarter - i % quarter, false),
+        2 => (i % quarter, true),
+        _ => (quarter - i % quarter, true),
+    };
+
+    // Taylor series in 2.62 fixed point: `x` is at most `π/2`
+    let x = FRAC_PI_2_Q62 * k as i128 / quarter as i128;
+    let x2 = (x * x) >> 62;
+    let mut term = x;
+    let mut sum = x;
+    let mut j = 1;
+
+    while j < 14 {
+        term = -((term * x2) >> 62) / ((2 * j) * (2 * j + 1));
+        sum += term;
+        j += 1;
+    }
+
+    let result = ((sum >> (61 - FRAC_BITS)) + 1) >> 1;
+
+    if negate {
+        -result as i32
+    } else {
+        result as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TrigTable, DEFAULT_SIZE, DEFAULT_TABLE};
+    use crate::F32;
+    use core::f32::consts::TAU;
+
+    #[test]
+    fn table_entries() {
+        let table = TrigTable::<16>::new();
+
+        for (i, &entry) in table.table.iter().enumerate() {
+            let expected = (f64::from(i as u32) * core::f64::consts::TAU / 16.0).sin();
+            let delta = (f64::from(entry) / f64::from(1 << 30) - expected).abs();
+            assert!(delta <= 1e-9, "delta {} too large for entry {}", delta, i);
+        }
+    }
+
+    /// Check `table` against `std` over `[-10τ, 10τ]`.
+    fn check_table<const N: usize>(table: &TrigTable<N>) {
+        let max_error = TrigTable::<N>::MAX_ERROR;
+
+        for i in -100_000..=100_000 {
+            let x = i as f32 * (TAU / 10_000.0);
+            let (sin, cos) = table.sin_cos(F32(x));
+
+            for &(actual, expected) in &[(sin.0, x.sin()), (cos.0, x.cos())] {
+                let delta = (actual - expected).abs();
+                assert!(
+                    delta <= max_error,
+                    "delta {} too large for {} with {} entries",
+                    delta,
+                    x,
+                    N
+                );
+            }
+
+            assert_eq!(table.sin(F32(x)), sin);
+            assert_eq!(table.cos(F32(x)), cos);
+        }
+    }
+
+    #[test]
+    fn sin_cos() {
+        check_table(&DEFAULT_TABLE);
+
+        for i in -1_000..=1_000 {
+            let x = F32(i as f32 * (TAU / 100.0));
+            assert_eq!(x.sin(), DEFAULT_TABLE.sin(x));
+            assert_eq!(x.cos(), DEFAULT_TABLE.cos(x));
+            assert_eq!(x.sin_cos(), DEFAULT_TABLE.sin_cos(x));
+        }
+    }
+
+    #[test]
+    fn default_size() {
+        assert_eq!(DEFAULT_TABLE.table.len(), DEFAULT_SIZE);
+    }
+
+    #[test]
+    fn other_sizes() {
+        check_table(&TrigTable::<64>::new());
+        check_table(&TrigTable::<1024>::new());
+        check_table(&TrigTable::<4096>::new());
+    }
+
+    #[test]
+    fn with_table() {
+        static TABLE: TrigTable<1024> = TrigTable::new();
+
+        for i in -1_000..=1_000 {
+            let x = F32(i as f32 * (TAU / 100.0));
+            assert_eq!(x.sin_with_table(&TABLE), TABLE.sin(x));
+            assert_eq!(x.cos_with_table(&TABLE), TABLE.cos(x));
+            assert_eq!(x.sin_cos_with_table(&TABLE), TABLE.sin_cos(x));
+        }
+    }
+}
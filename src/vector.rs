@@ -10,7 +10,7 @@ mod vector2d;
 mod vector3d;
 
 pub use self::{
-    component::Component,
+    component::{Component, IntegerComponent},
     iter::Iter,
     vector2d::{F32x2, I16x2, I32x2, I8x2, U16x2, U32x2, U8x2, Vector2d},
     vector3d::{F32x3, I16x3, I32x3, I8x3, U16x3, U32x3, U8x3, Vector3d},
@@ -82,6 +82,26 @@ where
             .sqrt()
     }
 
+    /// Compute the squared magnitude of a vector of integers using integer
+    /// arithmetic wide enough that it can't overflow.
+    ///
+    /// See [`IntegerComponent`] for the width used for each component type.
+    fn magnitude_sq_int(self) -> C::Squared
+    where
+        C: IntegerComponent,
+    {
+        self.iter().map(C::squared).sum()
+    }
+
+    /// Compute the magnitude of a vector of integers, rounded down, without
+    /// using floating point.
+    fn magnitude_int(self) -> C::Magnitude
+    where
+        C: IntegerComponent,
+    {
+        C::isqrt(self.magnitude_sq_int())
+    }
+
     /// Returns a normalized version of the vector.
     fn normalized(mut self) -> Self
     where
@@ -117,6 +137,43 @@ mod tests {
         assert_eq!(mag, 3.0 * 3.0 + 4.0 * 4.0 + 5.0 * 5.0);
     }
 
+    #[test]
+    fn magnitude_int() {
+        let vec = I16x3 {
+            x: 2000,
+            y: -3000,
+            z: 6000,
+        };
+        assert_eq!(vec.magnitude_sq_int(), 49_000_000u64);
+        assert_eq!(vec.magnitude_int(), 7000u32);
+
+        let vec = U8x2 { x: 255, y: 255 };
+        assert_eq!(vec.magnitude_sq_int(), 130_050u64);
+        assert_eq!(vec.magnitude_int(), 360u32);
+
+        let vec = I32x3 {
+            x: i32::MIN,
+            y: i32::MIN,
+            z: i32::MIN,
+        };
+        assert_eq!(vec.magnitude_sq_int(), 3u128 << 62);
+        assert_eq!(vec.magnitude_int(), 3_719_550_786u64);
+
+        let vec = U32x2 {
+            x: u32::MAX,
+            y: u32::MAX,
+        };
+        assert_eq!(vec.magnitude_sq_int(), 2 * u128::from(u32::MAX).pow(2));
+        assert_eq!(vec.magnitude_int(), 6_074_000_998);
+
+        let vec = U32x3 {
+            x: u32::MAX,
+            y: u32::MAX,
+            z: u32::MAX,
+        };
+        assert_eq!(vec.magnitude_int(), 7_439_101_571);
+    }
+
     #[test]
     fn normalized() {
         const ERROR: f32 = 1e-6;
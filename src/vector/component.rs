@@ -1,8 +1,9 @@
 //! Components of numeric vectors.
 
-use crate::F32;
+use crate::{integer, BF16, F16, F32, I16F16, Q15, Q31};
 use core::{
     fmt::Debug,
+    iter::Sum,
     ops::{Add, Div, Mul, Sub},
 };
 
@@ -17,6 +18,9 @@ use core::{
 /// - [`i8`], [`i16`], [`i32`]
 /// - [`u8`], [`u16`], [`u32`]
 /// - [`f32`]
+///
+/// as well as the [`F32`], [`F16`] and [`BF16`] floating point types and the
+/// [`Q15`], [`Q31`] and [`I16F16`] fixed-point types.
 pub trait Component:
     Copy
     + Debug
@@ -41,3 +45,54 @@ impl Component for u16 {}
 impl Component for u32 {}
 impl Component for f32 {}
 impl Component for F32 {}
+impl Component for F16 {}
+impl Component for BF16 {}
+impl Component for Q15 {}
+impl Component for Q31 {}
+impl Component for I16F16 {}
+
+/// Integer components of numeric vectors, whose magnitude can be computed
+/// without floating point.
+///
+/// Squares are accumulated in the narrowest integer type which can't
+/// overflow for a [`Vector3d`](super::Vector3d) of the component, since wider
+/// arithmetic is expensive on the FPU-less targets this is meant for:
+///
+/// - [`i8`], [`i16`], [`u8`], [`u16`]: [`u64`], with a [`u32`] magnitude
+/// - [`i32`], [`u32`]: [`u128`], with a [`u64`] magnitude
+pub trait IntegerComponent: Component {
+    /// Type the squared magnitude is accumulated in.
+    type Squared: Copy + Debug + PartialEq + Sum;
+
+    /// Type of the magnitude, i.e. the square root of [`Self::Squared`].
+    type Magnitude: Copy + Debug + PartialEq;
+
+    /// Computes the square of the component.
+    fn squared(self) -> Self::Squared;
+
+    /// Computes the integer square root of a sum of squares, rounded down.
+    fn isqrt(squared: Self::Squared) -> Self::Magnitude;
+}
+
+macro_rules! impl_integer_component {
+    ($signed:ty, $squared:ty, $magnitude:ty, $isqrt:path => $($component:ty),+) => {
+        $(
+            impl IntegerComponent for $component {
+                type Squared = $squared;
+                type Magnitude = $magnitude;
+
+                fn squared(self) -> $squared {
+                    let n = <$squared>::from(<$signed>::from(self).unsigned_abs());
+                    n * n
+                }
+
+                fn isqrt(squared: $squared) -> $magnitude {
+                    $isqrt(squared)
+                }
+            }
+        )+
+    };
+}
+
+impl_integer_component!(i32, u64, u32, integer::isqrt => i8, i16, u8, u16);
+impl_integer_component!(i64, u128, u64, integer::isqrt_u128 => i32, u32);
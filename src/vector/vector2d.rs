@@ -1,8 +1,10 @@
 //! 2-dimensional vector
 
 use super::{Component, Vector, Vector3d};
+use crate::cordic::{self, Cordic};
+use crate::integer;
 use crate::vector::commutative::impl_commutative;
-use crate::F32;
+use crate::{ApproxEq, F32};
 use core::ops::{Div, DivAssign};
 use core::{
     iter::{FromIterator, Sum},
@@ -83,6 +85,32 @@ where
     pub fn cross(&self, rhs: Self) -> Vector3d<C> {
         Vector3d::from(*self) * Vector3d::from(rhs)
     }
+
+    /// Computes the magnitude and angle of this vector using [CORDIC],
+    /// returning `(magnitude, angle)`.
+    ///
+    /// See the [`cordic`] module for the units of the angle, e.g. for
+    /// [`I16x2`] the angle is a binary angle where `i16::MIN` is `-π`.
+    ///
+    /// [CORDIC]: https://en.wikipedia.org/wiki/CORDIC
+    pub fn to_polar(self) -> (C, C)
+    where
+        C: Cordic,
+    {
+        cordic::polar(self.x, self.y)
+    }
+
+    /// Computes the angle of this vector of integers as a binary angle
+    /// without using floating point, where the full range of `u32` covers a
+    /// full turn counter-clockwise from the positive `x` axis.
+    ///
+    /// See [`integer::atan2`].
+    pub fn angle_int(self) -> u32
+    where
+        C: Into<i32>,
+    {
+        integer::atan2(self.y.into(), self.x.into())
+    }
 }
 
 impl<C> FromIterator<C> for Vector2d<C>
@@ -369,6 +397,23 @@ impl From<U16x2> for F32x2 {
     }
 }
 
+impl<C> ApproxEq for Vector2d<C>
+where
+    C: Component + ApproxEq,
+{
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.to_array().abs_diff_eq(&other.to_array(), epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f32) -> bool {
+        self.to_array().relative_eq(&other.to_array(), max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u32) -> bool {
+        self.to_array().ulps_eq(&other.to_array(), max_ulps)
+    }
+}
+
 #[cfg(feature = "defmt")]
 #[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
 impl<C> defmt::Format for Vector2d<C>
@@ -445,4 +490,41 @@ mod tests {
         assert_eq!(vec.x, 5);
         assert_eq!(vec.y, 10);
     }
+
+    #[test]
+    fn half_precision_components() {
+        use crate::{vector::Vector, F16};
+
+        let lhs = Vector2d::<F16>::from((F16::from(1u8), F16::from(2u8)));
+        let rhs = Vector2d::<F16>::from((F16::from(3u8), F16::from(4u8)));
+        assert_eq!(lhs.dot(rhs), F16::from(11u8));
+        assert_eq!(rhs.magnitude(), 5.0);
+    }
+
+    #[test]
+    fn fixed_point_components() {
+        use crate::I16F16;
+
+        let lhs = Vector2d::<I16F16>::from((I16F16::from(1i16), I16F16::from(2i16)));
+        let rhs = Vector2d::<I16F16>::from((I16F16::from(3i16), I16F16::from(4i16)));
+        assert_eq!(lhs.dot(rhs), I16F16::from(11i16));
+        assert_eq!(
+            lhs * I16F16::from(2i16),
+            Vector2d::from((rhs.x - lhs.x, rhs.y))
+        );
+    }
+
+    #[test]
+    fn to_polar() {
+        let reading = I16x2 { x: -4000, y: 3000 };
+        assert_eq!(reading.to_polar(), (5000, 26_056));
+
+        let angle = reading.angle_int();
+        assert!(angle.abs_diff(0x65c8_0a3b) <= 8);
+        assert_eq!(reading.magnitude_int(), 5000);
+
+        let (magnitude, angle) = F32x2 { x: 1.0, y: -1.0 }.to_polar();
+        assert!((magnitude - 2.0_f32.sqrt()).abs() <= 1e-6);
+        assert!((angle + core::f32::consts::FRAC_PI_4).abs() <= 1e-6);
+    }
 }
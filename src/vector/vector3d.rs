@@ -1,7 +1,7 @@
 //! 3-dimensional vector
 
 use super::{commutative::impl_commutative, Component, Vector, Vector2d};
-use crate::F32;
+use crate::{ApproxEq, F32};
 use core::ops::{Div, DivAssign};
 use core::{
     iter::{FromIterator, Sum},
@@ -402,6 +402,23 @@ impl From<Vector3d<F32>> for F32x3 {
     }
 }
 
+impl<C> ApproxEq for Vector3d<C>
+where
+    C: Component + ApproxEq,
+{
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.to_array().abs_diff_eq(&other.to_array(), epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f32) -> bool {
+        self.to_array().relative_eq(&other.to_array(), max_relative)
+    }
+
+    fn ulps_eq(&self, other: &Self, max_ulps: u32) -> bool {
+        self.to_array().ulps_eq(&other.to_array(), max_ulps)
+    }
+}
+
 #[cfg(feature = "defmt")]
 #[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
 impl<C> defmt::Format for Vector3d<C>